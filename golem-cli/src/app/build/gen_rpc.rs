@@ -15,18 +15,24 @@
 use crate::app::build::task_result_marker::{ComponentGeneratorMarkerHash, TaskResultMarker};
 use crate::app::build::{delete_path_logged, env_var_flag, is_up_to_date};
 use crate::app::context::ApplicationContext;
+use crate::app::wit_lint::lint_wit;
 use crate::fs;
 use crate::fs::PathExtra;
 use crate::log::{log_action, log_skipping_up_to_date, LogColorize, LogIndent};
 use crate::model::app::{AppComponentName, DependencyType, DependentComponent};
+use crate::model::text::fmt::{log_warn, TextView};
 use crate::wasm_rpc_stubgen::cargo::regenerate_cargo_package_component;
 use crate::wasm_rpc_stubgen::commands;
+use crate::wasm_rpc_stubgen::commands::generate::ClientWorkspaceMember;
+use crate::wasm_rpc_stubgen::naming;
+use crate::wasm_rpc_stubgen::typescript::ClientWrapperLanguage;
 use crate::wasm_rpc_stubgen::wit_generate::{
     add_client_as_dependency_to_wit_dir, extract_exports_as_wit_dep, AddClientAsDepConfig,
     UpdateCargoToml,
 };
-use anyhow::{anyhow, Context, Error};
+use anyhow::{anyhow, bail, Context, Error};
 use itertools::Itertools;
+use std::collections::BTreeSet;
 use std::path::{Path, PathBuf};
 
 // TODO: this step is not selected_component_names aware yet, for that we have to build / filter
@@ -38,15 +44,24 @@ pub async fn gen_rpc(ctx: &mut ApplicationContext) -> anyhow::Result<()> {
     let _indent = LogIndent::new();
 
     {
+        let mut changed_base_wits = BTreeSet::new();
         for component_name in ctx.wit.component_order_cloned() {
-            create_generated_base_wit(ctx, &component_name)?;
+            if create_generated_base_wit(ctx, &component_name)? {
+                changed_base_wits.insert(component_name);
+            }
         }
 
+        lint_source_wit(ctx, &changed_base_wits)?;
+
+        let mut static_clients = Vec::new();
         for dep in &ctx.application.all_dependencies() {
             if dep.dep_type.is_wasm_rpc() {
-                build_client(ctx, dep).await?;
+                if let Some(static_client) = generate_client(ctx, dep)? {
+                    static_clients.push(static_client);
+                }
             }
         }
+        build_static_clients(ctx, static_clients).await?;
     }
 
     {
@@ -59,6 +74,7 @@ pub async fn gen_rpc(ctx: &mut ApplicationContext) -> anyhow::Result<()> {
         for component_name in component_names {
             let changed = create_generated_wit(ctx, &component_name)?;
             update_cargo_toml(ctx, changed, &component_name)?;
+            update_client_wrappers(ctx, changed, &component_name)?;
             any_changed |= changed;
         }
         if any_changed {
@@ -69,6 +85,89 @@ pub async fn gen_rpc(ctx: &mut ApplicationContext) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Lints the selected components, unless neither their base wit nor the base wit of their
+/// wasm-rpc dependencies changed since the last successful lint. In strict mode linting is never
+/// skipped, as a previous non-strict lint could have succeeded with findings.
+fn lint_source_wit(
+    ctx: &ApplicationContext,
+    changed_base_wits: &BTreeSet<AppComponentName>,
+) -> anyhow::Result<()> {
+    let mut components_to_lint = Vec::new();
+    for component_name in ctx.selected_component_names() {
+        let task_result_marker = TaskResultMarker::new(
+            &ctx.application.task_result_marker_dir(),
+            ComponentGeneratorMarkerHash {
+                component_name,
+                generator_kind: "wit_lint",
+            },
+        )?;
+
+        let changed = changed_base_wits.contains(component_name)
+            || ctx
+                .application
+                .component_dependencies(component_name)
+                .iter()
+                .any(|dep| dep.dep_type.is_wasm_rpc() && changed_base_wits.contains(&dep.name));
+
+        if !ctx.config.skip_up_to_date_checks
+            && !ctx.config.strict_wit_lint
+            && task_result_marker.is_up_to_date()
+            && !changed
+        {
+            log_skipping_up_to_date(format!(
+                "linting wit for {}",
+                component_name.as_str().log_color_highlight()
+            ));
+        } else {
+            components_to_lint.push((component_name, task_result_marker));
+        }
+    }
+
+    if components_to_lint.is_empty() {
+        return Ok(());
+    }
+
+    log_action("Linting", "component source wit directories");
+    let _indent = LogIndent::new();
+
+    let findings = match lint_wit(ctx, components_to_lint.iter().map(|(name, _)| *name)) {
+        Ok(findings) => findings,
+        Err(err) if !ctx.config.strict_wit_lint => {
+            log_warn(format!("Skipping WIT linting: {err:#}"));
+            return Ok(());
+        }
+        Err(err) => {
+            for (_, task_result_marker) in &components_to_lint {
+                task_result_marker.failure()?;
+            }
+            return Err(err);
+        }
+    };
+
+    for (component_name, task_result_marker) in &components_to_lint {
+        let has_findings = findings
+            .iter()
+            .any(|finding| finding.component == component_name.as_str());
+        if ctx.config.strict_wit_lint && has_findings {
+            task_result_marker.failure()?;
+        } else {
+            task_result_marker.success()?;
+        }
+    }
+
+    if !findings.is_empty() {
+        findings.log();
+        if ctx.config.strict_wit_lint {
+            bail!(
+                "WIT linting reported {} issue(s), see the warnings above",
+                findings.len()
+            );
+        }
+    }
+
+    Ok(())
+}
+
 fn create_generated_base_wit(
     ctx: &mut ApplicationContext,
     component_name: &AppComponentName,
@@ -279,10 +378,100 @@ fn update_cargo_toml(
     ))
 }
 
-async fn build_client(
+/// Generates TypeScript or JavaScript wrappers for the WASM RPC clients of the dependencies,
+/// for components with a package.json next to their source wit dir
+fn update_client_wrappers(
+    ctx: &mut ApplicationContext,
+    mut skip_up_to_date_checks: bool,
+    component_name: &AppComponentName,
+) -> anyhow::Result<()> {
+    let component_source_wit = PathExtra::new(
+        ctx.application
+            .component_source_wit(component_name, ctx.profile()),
+    );
+    let component_dir = component_source_wit.parent().with_context(|| {
+        anyhow!(
+            "Failed to get parent for component {}",
+            component_name.as_str().log_color_highlight()
+        )
+    })?;
+
+    if !component_dir.join("package.json").exists() {
+        return Ok(());
+    }
+    let language = if component_dir.join("tsconfig.json").exists() {
+        ClientWrapperLanguage::TypeScript
+    } else {
+        ClientWrapperLanguage::JavaScript
+    };
+    let wrappers_dir = component_dir.join(naming::typescript::GENERATED_CLIENTS_DIR);
+
+    let task_result_marker = TaskResultMarker::new(
+        &ctx.application.task_result_marker_dir(),
+        ComponentGeneratorMarkerHash {
+            component_name,
+            generator_kind: "client_wrappers",
+        },
+    )?;
+
+    skip_up_to_date_checks |= ctx.config.skip_up_to_date_checks;
+    if !skip_up_to_date_checks && task_result_marker.is_up_to_date() {
+        log_skipping_up_to_date(format!(
+            "generating client wrappers for {}",
+            component_name.as_str().log_color_highlight()
+        ));
+        return Ok(());
+    }
+
+    let dependencies = ctx
+        .application
+        .component_dependencies(component_name)
+        .iter()
+        .filter(|dep| dep.dep_type.is_wasm_rpc())
+        .cloned()
+        .collect::<Vec<_>>();
+
+    task_result_marker.result((|| {
+        delete_path_logged("generated client wrappers", &wrappers_dir)?;
+        if dependencies.is_empty() {
+            return Ok(());
+        }
+
+        log_action(
+            "Generating",
+            format!(
+                "client wrappers for {}",
+                component_name.as_str().log_color_highlight()
+            ),
+        );
+        let _indent = LogIndent::new();
+
+        for dep in &dependencies {
+            let is_ephemeral = ctx
+                .application
+                .component_properties(&dep.name, ctx.profile())
+                .is_ephemeral();
+            let stub_def = ctx.component_stub_def(&dep.name, is_ephemeral)?;
+            commands::generate::generate_client_wrappers(stub_def, language, &wrappers_dir)?;
+        }
+
+        Ok(())
+    })())
+}
+
+/// Static WASM RPC client crate, which is generated but not built yet
+struct StaticClient {
+    component_name: AppComponentName,
+    member: ClientWorkspaceMember,
+    task_result_marker: TaskResultMarker,
+}
+
+/// Generates the client for the dependency, the crates of static clients are only built
+/// after all of them are generated, see [build_static_clients]
+fn generate_client(
     ctx: &mut ApplicationContext,
     component: &DependentComponent,
-) -> anyhow::Result<bool> {
+) -> anyhow::Result<Option<StaticClient>> {
     let stub_def = ctx.component_stub_def(
         &component.name,
         ctx.application
@@ -328,95 +517,166 @@ async fn build_client(
             "generating WASM RPC client for {}",
             component.name.as_str().log_color_highlight()
         ));
-        Ok(false)
-    } else {
-        task_result_marker.result(
-            async {
-                match component.dep_type {
-                    DependencyType::StaticWasmRpc => {
-                        log_action(
-                            "Building",
-                            format!(
-                                "WASM RPC client for {}",
-                                component.name.as_str().log_color_highlight()
-                            ),
-                        );
+        return Ok(None);
+    }
 
-                        let _indent = LogIndent::new();
+    match component.dep_type {
+        DependencyType::StaticWasmRpc => {
+            log_action(
+                "Generating",
+                format!(
+                    "WASM RPC client crate for {}",
+                    component.name.as_str().log_color_highlight()
+                ),
+            );
+            let _indent = LogIndent::new();
 
-                        delete_path_logged("client temp build dir", &client_wit_root)?;
-                        delete_path_logged("client wit", &client_wit)?;
-                        delete_path_logged("client wasm", &client_wasm)?;
+            let member = (|| -> anyhow::Result<ClientWorkspaceMember> {
+                delete_path_logged("client temp build dir", &client_wit_root)?;
+                delete_path_logged("client wit", &client_wit)?;
+                delete_path_logged("client wasm", &client_wasm)?;
 
-                        log_action(
-                            "Creating",
-                            format!(
-                                "client temp build dir {}",
-                                client_wit_root.log_color_highlight()
-                            ),
-                        );
-                        fs::create_dir_all(&client_wit_root)?;
-
-                        let offline = ctx.config.offline;
-                        commands::generate::build(
-                            ctx.component_stub_def(
-                                &component.name,
-                                ctx.application
-                                    .component_properties(&component.name, ctx.profile())
-                                    .is_ephemeral(),
-                            )?,
-                            &client_wasm,
-                            &client_wit,
-                            offline,
-                        )
-                        .await?;
+                log_action(
+                    "Creating",
+                    format!(
+                        "client temp build dir {}",
+                        client_wit_root.log_color_highlight()
+                    ),
+                );
+                fs::create_dir_all(&client_wit_root)?;
+
+                let stub_def = ctx.component_stub_def(
+                    &component.name,
+                    ctx.application
+                        .component_properties(&component.name, ctx.profile())
+                        .is_ephemeral(),
+                )?;
+                commands::generate::generate_client(stub_def)?;
+                Ok(ClientWorkspaceMember::new(stub_def))
+            })();
+
+            Ok(Some(StaticClient {
+                component_name: component.name.clone(),
+                member: task_result_marker.result(member)?,
+                task_result_marker,
+            }))
+        }
+        DependencyType::DynamicWasmRpc => {
+            task_result_marker.result((|| {
+                log_action(
+                    "Generating",
+                    format!(
+                        "WASM RPC client for {}",
+                        component.name.as_str().log_color_highlight()
+                    ),
+                );
+                let _indent = LogIndent::new();
 
-                        if !env_var_flag("WASM_RPC_KEEP_CLIENT_DIR") {
-                            delete_path_logged("client temp build dir", &client_wit_root)?;
-                        }
+                delete_path_logged("client wit", &client_wit)?;
 
-                        Ok(())
-                    }
-                    DependencyType::DynamicWasmRpc => {
-                        log_action(
-                            "Generating",
-                            format!(
-                                "WASM RPC client for {}",
-                                component.name.as_str().log_color_highlight()
-                            ),
-                        );
-                        let _indent = LogIndent::new();
+                log_action(
+                    "Creating",
+                    format!(
+                        "client temp build dir {}",
+                        client_wit_root.log_color_highlight()
+                    ),
+                );
+                fs::create_dir_all(&client_wit_root)?;
+
+                let stub_def = ctx.component_stub_def(
+                    &component.name,
+                    ctx.application
+                        .component_properties(&component.name, ctx.profile())
+                        .is_ephemeral(),
+                )?;
+                commands::generate::generate_and_copy_client_wit(stub_def, &client_wit)
+            })())?;
+            Ok(None)
+        }
+        DependencyType::Wasm => {
+            // No need to generate RPC clients for this dependency type
+            task_result_marker.result(Ok(()))?;
+            Ok(None)
+        }
+    }
+}
+
+/// Builds the static clients in a shared Cargo workspace, so their common dependencies
+/// are only compiled once, with a single cargo invocation
+async fn build_static_clients(
+    ctx: &ApplicationContext,
+    static_clients: Vec<StaticClient>,
+) -> anyhow::Result<()> {
+    if static_clients.is_empty() {
+        return Ok(());
+    }
 
-                        delete_path_logged("client wit", &client_wit)?;
+    let workspace_root = ctx.application.client_workspace_dir();
+
+    // Crate names are derived from the world names, which are not necessarily unique between components,
+    // clients with the same crate name are built in separate cargo invocations
+    let mut batches: Vec<Vec<StaticClient>> = Vec::new();
+    for static_client in static_clients {
+        let batch = batches.iter_mut().find(|batch| {
+            batch.iter().all(|client| {
+                client.member.client_crate_name != static_client.member.client_crate_name
+            })
+        });
+        match batch {
+            Some(batch) => batch.push(static_client),
+            None => batches.push(vec![static_client]),
+        }
+    }
 
-                        log_action(
-                            "Creating",
-                            format!(
-                                "client temp build dir {}",
-                                client_wit_root.log_color_highlight()
-                            ),
-                        );
-                        fs::create_dir_all(&client_wit_root)?;
-
-                        let stub_def = ctx.component_stub_def(
-                            &component.name,
-                            ctx.application
-                                .component_properties(&component.name, ctx.profile())
-                                .is_ephemeral(),
-                        )?;
-                        commands::generate::generate_and_copy_client_wit(stub_def, &client_wit)
-                    }
-                    DependencyType::Wasm => {
-                        // No need to generate RPC clients for this dependency type
-                        Ok(())
-                    }
-                }
+    for batch in batches {
+        log_action(
+            "Building",
+            format!(
+                "WASM RPC clients for {} in {}",
+                batch
+                    .iter()
+                    .map(|client| client.component_name.as_str().log_color_highlight())
+                    .join(", "),
+                workspace_root.log_color_highlight()
+            ),
+        );
+        let _indent = LogIndent::new();
+
+        let members = batch
+            .iter()
+            .map(|client| client.member.clone())
+            .collect::<Vec<_>>();
+        if let Err(err) = commands::generate::build_client_workspace(
+            &workspace_root,
+            &members,
+            ctx.config.offline,
+        )
+        .await
+        {
+            for client in &batch {
+                client.task_result_marker.failure()?;
             }
-            .await,
-        )?;
+            return Err(err);
+        }
 
-        Ok(true)
+        for client in batch {
+            client.task_result_marker.result((|| {
+                client.member.copy_outputs(
+                    &workspace_root,
+                    &ctx.application.client_wasm(&client.component_name),
+                    &ctx.application.client_wit(&client.component_name),
+                )?;
+
+                if !env_var_flag("WASM_RPC_KEEP_CLIENT_DIR") {
+                    delete_path_logged("client temp build dir", &client.member.client_root)?;
+                }
+
+                Ok(())
+            })())?;
+        }
     }
+
+    Ok(())
 }
 
 fn add_client_deps(
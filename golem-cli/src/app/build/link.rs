@@ -17,10 +17,12 @@ use crate::app::build::task_result_marker::{LinkRpcMarkerHash, TaskResultMarker}
 use crate::app::context::ApplicationContext;
 use crate::fs;
 use crate::log::{log_action, log_skipping_up_to_date, LogColorize, LogIndent};
-use crate::model::app::DependencyType;
+use crate::model::app::{AppComponentName, DependencyType};
 use crate::wasm_rpc_stubgen::commands;
+use crate::wasm_rpc_stubgen::commands::composition::CompositionExplanation;
 use itertools::Itertools;
 use std::collections::BTreeSet;
+use std::path::PathBuf;
 
 pub async fn link(ctx: &ApplicationContext) -> anyhow::Result<()> {
     log_action("Linking", "dependencies");
@@ -45,14 +47,9 @@ pub async fn link(ctx: &ApplicationContext) -> anyhow::Result<()> {
             .iter()
             .filter(|dep| dep.dep_type == DependencyType::DynamicWasmRpc)
             .collect::<BTreeSet<_>>();
-        let wasms_to_compose_with = static_dependencies
-            .iter()
-            .map(|dep| ctx.application.client_wasm(&dep.name))
-            .chain(
-                library_dependencies
-                    .iter()
-                    .map(|dep| ctx.application.component_wasm(&dep.name, ctx.profile())),
-            )
+        let wasms_to_compose_with = link_plugs(ctx, component_name)
+            .into_iter()
+            .map(|(_, wasm)| wasm)
             .collect::<Vec<_>>();
         let component_wasm = ctx
             .application
@@ -171,3 +168,50 @@ pub async fn link(ctx: &ApplicationContext) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// WASMs to be composed with the component during linking, labeled by the dependency they
+/// belong to: static WASM RPC clients first, then libraries
+pub fn link_plugs(
+    ctx: &ApplicationContext,
+    component_name: &AppComponentName,
+) -> Vec<(String, PathBuf)> {
+    let dependencies = ctx.application.component_dependencies(component_name);
+    let static_dependencies = dependencies
+        .iter()
+        .filter(|dep| dep.dep_type == DependencyType::StaticWasmRpc)
+        .collect::<BTreeSet<_>>();
+    let library_dependencies = dependencies
+        .iter()
+        .filter(|dep| dep.dep_type == DependencyType::Wasm)
+        .collect::<BTreeSet<_>>();
+
+    static_dependencies
+        .iter()
+        .map(|dep| {
+            (
+                format!("{} (client)", dep.name),
+                ctx.application.client_wasm(&dep.name),
+            )
+        })
+        .chain(library_dependencies.iter().map(|dep| {
+            (
+                format!("{} (library)", dep.name),
+                ctx.application.component_wasm(&dep.name, ctx.profile()),
+            )
+        }))
+        .collect()
+}
+
+/// Explains the composition of the component with its link plugs, based on the built component
+/// WASM, so it can be used even when linking fails
+pub fn explain_link(
+    ctx: &ApplicationContext,
+    component_name: &AppComponentName,
+) -> anyhow::Result<CompositionExplanation> {
+    commands::composition::explain_composition(
+        component_name.as_str(),
+        &ctx.application
+            .component_wasm(component_name, ctx.profile()),
+        &link_plugs(ctx, component_name),
+    )
+}
@@ -269,27 +269,76 @@ impl ApplicationContext {
         is_ephemeral: bool,
     ) -> anyhow::Result<&StubDefinition> {
         if !self.component_stub_defs.contains_key(component_name) {
-            self.component_stub_defs.insert(
-                component_name.clone(),
-                StubDefinition::new(StubConfig {
-                    source_wit_root: self
-                        .application
-                        .component_generated_base_wit(component_name),
-                    client_root: self.application.client_temp_build_dir(component_name),
-                    selected_world: None,
-                    stub_crate_version: WASM_RPC_VERSION.to_string(),
-                    golem_rust_override: self.config.golem_rust_override.clone(),
-                    extract_source_exports_package: false,
-                    seal_cargo_workspace: true,
-                    component_name: component_name.clone(),
-                    is_ephemeral,
-                })
-                .context("Failed to gather information for the stub generator")?,
-            );
+            let stub_def = self.new_component_stub_def(
+                component_name,
+                is_ephemeral,
+                self.application.client_temp_build_dir(component_name),
+                WASM_RPC_VERSION.to_string(),
+                // Static clients are built as members of the client workspace
+                false,
+                true,
+            )?;
+            self.component_stub_defs
+                .insert(component_name.clone(), stub_def);
         }
         Ok(self.component_stub_defs.get(component_name).unwrap())
     }
 
+    /// Stub definition for exporting the client of a component as a standalone package,
+    /// versioned from the root package name of the component
+    pub fn component_exported_client_stub_def(
+        &self,
+        component_name: &AppComponentName,
+        client_root: &Path,
+    ) -> anyhow::Result<StubDefinition> {
+        let is_ephemeral = self
+            .application
+            .component_properties(component_name, self.profile())
+            .is_ephemeral();
+        let stub_crate_version = self
+            .wit
+            .root_package_name(component_name)?
+            .version
+            .map(|version| version.to_string())
+            .unwrap_or_else(|| "0.1.0".to_string());
+
+        self.new_component_stub_def(
+            component_name,
+            is_ephemeral,
+            client_root.to_path_buf(),
+            stub_crate_version,
+            // Exported clients are built on their own
+            true,
+            false,
+        )
+    }
+
+    fn new_component_stub_def(
+        &self,
+        component_name: &AppComponentName,
+        is_ephemeral: bool,
+        client_root: PathBuf,
+        stub_crate_version: String,
+        seal_cargo_workspace: bool,
+        client_workspace_member: bool,
+    ) -> anyhow::Result<StubDefinition> {
+        StubDefinition::new(StubConfig {
+            source_wit_root: self
+                .application
+                .component_generated_base_wit(component_name),
+            client_root,
+            selected_world: None,
+            stub_crate_version,
+            golem_rust_override: self.config.golem_rust_override.clone(),
+            extract_source_exports_package: false,
+            seal_cargo_workspace,
+            client_workspace_member,
+            component_name: component_name.clone(),
+            is_ephemeral,
+        })
+        .context("Failed to gather information for the stub generator")
+    }
+
     pub fn component_stub_interfaces(
         &mut self,
         component_name: &AppComponentName,
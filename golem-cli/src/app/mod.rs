@@ -15,3 +15,5 @@
 pub mod build;
 pub mod context;
 pub mod error;
+pub mod wit_deps;
+pub mod wit_lint;
@@ -0,0 +1,340 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::app::context::ApplicationContext;
+use crate::fs;
+use crate::log::{log_action, LogColorize};
+use crate::model::app::AppComponentName;
+use crate::wasm_rpc_stubgen::naming;
+use crate::wasm_rpc_stubgen::wit_resolve::ResolvedWitDir;
+use anyhow::{anyhow, bail, Context};
+use async_zip::tokio::read::fs::ZipFileReader;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Component, Path, PathBuf};
+use wit_parser::{PackageName, UnresolvedPackageGroup};
+
+/// A WIT package found in a deps dir
+pub struct WitDepPackage {
+    pub name: PackageName,
+    pub dir: PathBuf,
+}
+
+impl WitDepPackage {
+    /// Package name without version, used as the lock key, so different versions of the same
+    /// package are treated as copies of each other
+    pub fn key(&self) -> String {
+        package_key(&self.name)
+    }
+
+    pub fn dir_name(&self) -> anyhow::Result<String> {
+        fs::PathExtra::new(&self.dir).file_name_to_string()
+    }
+}
+
+pub fn package_key(package_name: &PackageName) -> String {
+    format!("{}:{}", package_name.namespace, package_name.name)
+}
+
+pub fn parse_package_dir(dir: &Path) -> anyhow::Result<WitDepPackage> {
+    let package_group = UnresolvedPackageGroup::parse_dir(dir).with_context(|| {
+        anyhow!(
+            "Failed to parse wit package {}",
+            dir.log_color_error_highlight()
+        )
+    })?;
+    Ok(WitDepPackage {
+        name: package_group.main.name,
+        dir: dir.to_path_buf(),
+    })
+}
+
+/// Parses all package directories of a deps dir, returns an empty list if the dir does not exist
+pub fn parse_deps_dir(deps_dir: &Path) -> anyhow::Result<Vec<WitDepPackage>> {
+    if !deps_dir.is_dir() {
+        return Ok(vec![]);
+    }
+
+    let mut dirs = deps_dir
+        .read_dir()
+        .and_then(|read_dir| read_dir.collect::<std::io::Result<Vec<_>>>())
+        .with_context(|| {
+            anyhow!(
+                "Failed to read wit dependencies from {}",
+                deps_dir.log_color_error_highlight()
+            )
+        })?
+        .into_iter()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .collect::<Vec<_>>();
+    dirs.sort();
+
+    dirs.iter().map(|dir| parse_package_dir(dir)).collect()
+}
+
+/// Hashes the files of a package dir, nested dirs (e.g. deps) are not part of the package
+pub fn package_dir_hash(dir: &Path) -> anyhow::Result<String> {
+    let mut hasher = blake3::Hasher::new();
+    for file in package_files(dir)? {
+        hasher.update(fs::PathExtra::new(&file).file_name_to_string()?.as_bytes());
+        hasher.update(&[0]);
+        hasher.update(&fs::read(&file)?);
+        hasher.update(&[0]);
+    }
+    Ok(hasher.finalize().to_hex().to_string())
+}
+
+/// Replaces the target package dir with the package files of the source dir
+pub fn copy_package_dir(source: &Path, target: &Path) -> anyhow::Result<()> {
+    log_action(
+        "Copying",
+        format!(
+            "wit package from {} to {}",
+            source.log_color_highlight(),
+            target.log_color_highlight()
+        ),
+    );
+    fs::remove(target)?;
+    fs::create_dir_all(target)?;
+    for file in package_files(source)? {
+        fs::copy(
+            &file,
+            target.join(fs::PathExtra::new(&file).file_name_to_string()?),
+        )?;
+    }
+    Ok(())
+}
+
+fn package_files(dir: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    let mut files = dir
+        .read_dir()
+        .and_then(|read_dir| read_dir.collect::<std::io::Result<Vec<_>>>())
+        .with_context(|| anyhow!("Failed to read wit package {}", dir.log_color_highlight()))?
+        .into_iter()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+        .collect::<Vec<_>>();
+    files.sort();
+    Ok(files)
+}
+
+/// Returns the package dir of a local source, which is either a package directory or a zip
+/// archive containing one, either at its root or in a single top level directory. Archives are
+/// extracted into the provided temp dir.
+pub async fn prepare_package_source(source: &Path, temp_dir: &Path) -> anyhow::Result<PathBuf> {
+    if source.is_dir() {
+        return Ok(source.to_path_buf());
+    }
+    if !source.is_file() {
+        bail!(
+            "WIT package source {} does not exist",
+            source.log_color_error_highlight()
+        );
+    }
+    if source.extension().and_then(|ext| ext.to_str()) != Some("zip") {
+        bail!(
+            "Unsupported WIT package source {}, expected a directory or a zip archive",
+            source.log_color_error_highlight()
+        );
+    }
+
+    extract_zip(source, temp_dir).await?;
+
+    let has_wit_files = package_files(temp_dir)?
+        .iter()
+        .any(|file| file.extension().and_then(|ext| ext.to_str()) == Some("wit"));
+    if has_wit_files {
+        return Ok(temp_dir.to_path_buf());
+    }
+
+    let dirs = temp_dir
+        .read_dir()?
+        .collect::<std::io::Result<Vec<_>>>()?
+        .into_iter()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .collect::<Vec<_>>();
+    match dirs.as_slice() {
+        [dir] => Ok(dir.clone()),
+        _ => bail!(
+            "Archive {} does not contain a WIT package at its root or in a single top level directory",
+            source.log_color_error_highlight()
+        ),
+    }
+}
+
+async fn extract_zip(archive: &Path, target_dir: &Path) -> anyhow::Result<()> {
+    log_action(
+        "Extracting",
+        format!("archive {}", archive.log_color_highlight()),
+    );
+    let context = || anyhow!("Failed to extract {}", archive.log_color_error_highlight());
+
+    let reader = ZipFileReader::new(archive).await.with_context(context)?;
+    for index in 0..reader.file().entries().len() {
+        let entry = &reader.file().entries()[index];
+        if entry.dir().with_context(context)? {
+            continue;
+        }
+        let file_name = entry.filename().as_str().with_context(context)?.to_string();
+        let relative_path = Path::new(&file_name);
+        if !relative_path
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)))
+        {
+            bail!(
+                "Archive {} contains an invalid entry: {}",
+                archive.log_color_error_highlight(),
+                file_name.log_color_highlight()
+            );
+        }
+
+        let mut content = Vec::new();
+        reader
+            .reader_with_entry(index)
+            .await
+            .with_context(context)?
+            .read_to_end_checked(&mut content)
+            .await
+            .with_context(context)?;
+        fs::write(target_dir.join(relative_path), content)?;
+    }
+    Ok(())
+}
+
+/// Packages of a source: the main package, and the packages of its deps dir, which are
+/// returned with their path relative to the main package dir
+pub fn source_packages(
+    package_dir: &Path,
+) -> anyhow::Result<(WitDepPackage, Vec<(String, WitDepPackage)>)> {
+    let main = parse_package_dir(package_dir)?;
+    let deps = parse_deps_dir(&package_dir.join(naming::wit::DEPS_DIR))?
+        .into_iter()
+        .map(|package| {
+            let source_dir = format!("{}/{}", naming::wit::DEPS_DIR, package.dir_name()?);
+            Ok((source_dir, package))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    Ok((main, deps))
+}
+
+/// Package copies in the source wit deps dirs of the components, excluding the shared deps dir
+pub fn component_package_copies(
+    app_ctx: &ApplicationContext,
+    shared_deps_dir: &Path,
+) -> anyhow::Result<Vec<(AppComponentName, WitDepPackage)>> {
+    let shared_deps_dir = shared_deps_dir.canonicalize().ok();
+    let mut visited_deps_dirs = BTreeSet::new();
+    let mut copies = Vec::new();
+    for component_name in app_ctx.application.component_names() {
+        let deps_dir = app_ctx
+            .application
+            .component_source_wit(component_name, app_ctx.profile())
+            .join(naming::wit::DEPS_DIR);
+        let Ok(deps_dir) = deps_dir.canonicalize() else {
+            continue;
+        };
+        if Some(&deps_dir) == shared_deps_dir.as_ref()
+            || !visited_deps_dirs.insert(deps_dir.clone())
+        {
+            continue;
+        }
+        for package in parse_deps_dir(&deps_dir)? {
+            copies.push((component_name.clone(), package));
+        }
+    }
+    Ok(copies)
+}
+
+/// Components using interfaces of packages, by package key. Uses the generated wit dir of
+/// the component if available, otherwise tries to resolve the source wit dir.
+pub fn component_package_usages(
+    app_ctx: &ApplicationContext,
+) -> anyhow::Result<BTreeMap<String, BTreeSet<AppComponentName>>> {
+    let mut usages = BTreeMap::<String, BTreeSet<AppComponentName>>::new();
+    for component_name in app_ctx.application.component_names() {
+        let source_wit_dir;
+        let wit_dir = match app_ctx.wit.component(component_name)?.generated_wit_dir() {
+            Some(wit_dir) => wit_dir,
+            None => {
+                let Ok(wit_dir) = ResolvedWitDir::new(
+                    &app_ctx
+                        .application
+                        .component_source_wit(component_name, app_ctx.profile()),
+                ) else {
+                    continue;
+                };
+                source_wit_dir = wit_dir;
+                &source_wit_dir
+            }
+        };
+        for (_, package_name, _) in wit_dir.used_interfaces()? {
+            usages
+                .entry(package_key(&package_name))
+                .or_default()
+                .insert(component_name.clone());
+        }
+    }
+    Ok(usages)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::app::wit_deps::{package_dir_hash, parse_deps_dir, source_packages};
+    use crate::fs;
+    use assert2::assert;
+    use test_r::test;
+
+    #[test]
+    fn hash_depends_only_on_package_files() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let a = temp_dir.path().join("a");
+        let b = temp_dir.path().join("b");
+        for dir in [&a, &b] {
+            fs::write_str(dir.join("api.wit"), "package test:api;\ninterface api {}\n").unwrap();
+        }
+        fs::write_str(b.join("deps/io/io.wit"), "package wasi:io;\n").unwrap();
+
+        assert!(package_dir_hash(&a).unwrap() == package_dir_hash(&b).unwrap());
+
+        fs::write_str(b.join("api.wit"), "package test:api;\ninterface api2 {}\n").unwrap();
+        assert!(package_dir_hash(&a).unwrap() != package_dir_hash(&b).unwrap());
+    }
+
+    #[test]
+    fn source_packages_include_deps() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let package_dir = temp_dir.path().join("http");
+        fs::write_str(
+            package_dir.join("types.wit"),
+            "package wasi:http@0.2.0;\ninterface types { use wasi:io/streams@0.2.0.{input-stream}; }\n",
+        )
+        .unwrap();
+        fs::write_str(
+            package_dir.join("deps/io/streams.wit"),
+            "package wasi:io@0.2.0;\ninterface streams { resource input-stream; }\n",
+        )
+        .unwrap();
+
+        let (main, deps) = source_packages(&package_dir).unwrap();
+        assert!(main.key() == "wasi:http");
+        assert!(deps.len() == 1);
+        assert!(deps[0].0 == "deps/io");
+        assert!(deps[0].1.name.to_string() == "wasi:io@0.2.0");
+
+        assert!(parse_deps_dir(&temp_dir.path().join("missing"))
+            .unwrap()
+            .is_empty());
+    }
+}
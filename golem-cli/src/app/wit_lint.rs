@@ -0,0 +1,791 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::app::context::ApplicationContext;
+use crate::fs;
+use crate::log::LogColorize;
+use crate::model::app::AppComponentName;
+use crate::model::wave::type_wave_compatible;
+use crate::model::wit_lint::{WitLintFinding, WitLintKind, WitSourceLocation};
+use crate::wasm_rpc_stubgen::naming;
+use crate::wasm_rpc_stubgen::wit_resolve::ResolvedWitDir;
+use anyhow::{anyhow, Context};
+use golem_wasm_ast::analysis::analysed_type::{
+    bool, case, chr, f32, f64, field, flags, handle, list, option, r#enum, record, result,
+    result_err, result_ok, s16, s32, s64, s8, str, tuple, u16, u32, u64, u8, unit_case, variant,
+};
+use golem_wasm_ast::analysis::{
+    AnalysedResourceId, AnalysedResourceMode, AnalysedType, TypeResult,
+};
+use itertools::Itertools;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::path::{Path, PathBuf};
+use wit_parser::{
+    Function, FunctionKind, Handle, InterfaceId, PackageId, Resolve, Type, TypeDefKind, TypeId,
+    WorldItem, WorldKey,
+};
+
+/// Lints the source wit directories of the given components.
+///
+/// The source wit directory is used directly if it can be resolved on its own, otherwise the
+/// generated base wit directory is used (which also contains the common and component package
+/// deps), while locations are still reported in the source wit directory.
+///
+/// The exports of the wasm-rpc dependencies of each component are also checked together, as their
+/// clients are all imported by the same component.
+pub fn lint_wit<'a>(
+    ctx: &ApplicationContext,
+    component_names: impl IntoIterator<Item = &'a AppComponentName>,
+) -> anyhow::Result<Vec<WitLintFinding>> {
+    let mut wit_dirs = BTreeMap::<AppComponentName, ResolvedWitDir>::new();
+    let mut findings = Vec::new();
+    for component_name in component_names {
+        let source_wit_dir = ctx
+            .application
+            .component_source_wit(component_name, ctx.profile());
+        let wit_dir = cached_lint_wit_dir(ctx, &mut wit_dirs, component_name)?;
+        findings.extend(lint_wit_dir(
+            component_name.as_str(),
+            wit_dir,
+            &source_wit_dir,
+        )?);
+
+        let rpc_dependency_names = ctx
+            .application
+            .component_dependencies(component_name)
+            .iter()
+            .filter(|dep| dep.dep_type.is_wasm_rpc())
+            .map(|dep| dep.name.clone())
+            .collect::<Vec<_>>();
+        for dependency_name in &rpc_dependency_names {
+            cached_lint_wit_dir(ctx, &mut wit_dirs, dependency_name)?;
+        }
+        findings.extend(lint_rpc_dependencies(
+            component_name.as_str(),
+            rpc_dependency_names.iter().map(|name| &wit_dirs[name]),
+        )?);
+    }
+    Ok(findings)
+}
+
+fn cached_lint_wit_dir<'a>(
+    ctx: &ApplicationContext,
+    wit_dirs: &'a mut BTreeMap<AppComponentName, ResolvedWitDir>,
+    component_name: &AppComponentName,
+) -> anyhow::Result<&'a ResolvedWitDir> {
+    if !wit_dirs.contains_key(component_name) {
+        let wit_dir = resolve_lint_wit_dir(ctx, component_name)?;
+        wit_dirs.insert(component_name.clone(), wit_dir);
+    }
+    Ok(&wit_dirs[component_name])
+}
+
+fn resolve_lint_wit_dir(
+    ctx: &ApplicationContext,
+    component_name: &AppComponentName,
+) -> anyhow::Result<ResolvedWitDir> {
+    let source_wit_dir = ctx
+        .application
+        .component_source_wit(component_name, ctx.profile());
+    let generated_base_wit_dir = ctx.application.component_generated_base_wit(component_name);
+
+    match ResolvedWitDir::new(&source_wit_dir) {
+        Ok(wit_dir) => Ok(wit_dir),
+        Err(source_error) if generated_base_wit_dir.exists() => {
+            ResolvedWitDir::new(&generated_base_wit_dir).with_context(|| {
+                anyhow!(
+                    "Failed to resolve wit dirs for linting component {}: {}",
+                    component_name.as_str().log_color_error_highlight(),
+                    source_error
+                )
+            })
+        }
+        Err(source_error) => Err(source_error.context(anyhow!(
+            "Failed to resolve source wit dir for linting component {}, \
+            if it depends on common wit deps, build the application first",
+            component_name.as_str().log_color_error_highlight()
+        ))),
+    }
+}
+
+/// Checks the interface names that end up in the same component through the generated clients
+/// (see [naming::wit]): the exported interfaces and the client interfaces of all the wasm-rpc
+/// dependencies.
+pub fn lint_rpc_dependencies<'a>(
+    component_name: &str,
+    dependency_wit_dirs: impl IntoIterator<Item = &'a ResolvedWitDir>,
+) -> anyhow::Result<Vec<WitLintFinding>> {
+    let mut interface_packages = BTreeMap::<String, BTreeSet<String>>::new();
+    for wit_dir in dependency_wit_dirs {
+        let resolve = &wit_dir.resolve;
+        let main_package = wit_dir.main_package()?;
+        for world_id in main_package.worlds.values() {
+            let world = &resolve.worlds[*world_id];
+
+            interface_packages
+                .entry(naming::wit::client_interface_name(world))
+                .or_default()
+                .insert(naming::wit::client_parser_package_name(&main_package.name).to_string());
+
+            for (key, item) in &world.exports {
+                let WorldItem::Interface { id, .. } = item else {
+                    continue;
+                };
+                let interface = &resolve.interfaces[*id];
+                if let (WorldKey::Interface(_), Some(name), Some(package_id)) =
+                    (key, &interface.name, interface.package)
+                {
+                    interface_packages
+                        .entry(name.clone())
+                        .or_default()
+                        .insert(resolve.packages[package_id].name.to_string());
+                }
+            }
+        }
+    }
+
+    Ok(interface_packages
+        .into_iter()
+        .filter(|(_, package_names)| package_names.len() > 1)
+        .map(|(interface_name, package_names)| WitLintFinding {
+            component: component_name.to_string(),
+            kind: WitLintKind::DuplicateInterfaceName,
+            message: format!(
+                "Interface name {} is used by multiple packages of the RPC dependencies, which collide in the generated clients: {}",
+                interface_name.log_color_highlight(),
+                package_names
+                    .iter()
+                    .map(|name| name.log_color_highlight())
+                    .join(", ")
+            ),
+            location: None,
+        })
+        .collect())
+}
+
+pub fn lint_wit_dir(
+    component_name: &str,
+    wit_dir: &ResolvedWitDir,
+    source_wit_dir: &Path,
+) -> anyhow::Result<Vec<WitLintFinding>> {
+    let mut linter = WitLinter {
+        component_name,
+        wit_dir,
+        source_wit_dir,
+        findings: Vec::new(),
+    };
+
+    linter.check_package_version()?;
+    linter.check_unused_imports()?;
+    linter.check_exports()?;
+
+    Ok(linter.findings)
+}
+
+struct WitLinter<'a> {
+    component_name: &'a str,
+    wit_dir: &'a ResolvedWitDir,
+    source_wit_dir: &'a Path,
+    findings: Vec<WitLintFinding>,
+}
+
+impl WitLinter<'_> {
+    fn add_finding(
+        &mut self,
+        kind: WitLintKind,
+        message: String,
+        location: Option<WitSourceLocation>,
+    ) {
+        self.findings.push(WitLintFinding {
+            component: self.component_name.to_string(),
+            kind,
+            message,
+            location,
+        });
+    }
+
+    fn check_package_version(&mut self) -> anyhow::Result<()> {
+        let package_name = self.wit_dir.main_package()?.name.clone();
+        if package_name.version.is_none() {
+            let location = self.locate(self.wit_dir.package_id, None, |line| {
+                line.starts_with("package ")
+            })?;
+            self.add_finding(
+                WitLintKind::UnversionedPackage,
+                format!(
+                    "Package {} has no version, so the generated client package and the linked interfaces will not be versioned either",
+                    package_name.to_string().log_color_highlight()
+                ),
+                location,
+            );
+        }
+        Ok(())
+    }
+
+    fn check_unused_imports(&mut self) -> anyhow::Result<()> {
+        let wit_dir = self.wit_dir;
+        let resolve = &wit_dir.resolve;
+
+        let used_types = self.used_types();
+        let main_package = wit_dir.main_package()?;
+
+        let interface_types = main_package.interfaces.values().flat_map(|interface_id| {
+            let interface = &resolve.interfaces[*interface_id];
+            interface
+                .types
+                .values()
+                .map(move |type_id| (interface.name.clone(), *type_id))
+        });
+        let world_types = main_package.worlds.values().flat_map(|world_id| {
+            let world = &resolve.worlds[*world_id];
+            world.imports.values().filter_map(move |item| match item {
+                WorldItem::Type(type_id) => Some((Some(world.name.clone()), *type_id)),
+                _ => None,
+            })
+        });
+
+        let unused_imports = interface_types
+            .chain(world_types)
+            .filter(|(_, type_id)| !used_types.contains(type_id))
+            .filter_map(|(item_name, type_id)| {
+                let type_def = &resolve.types[type_id];
+                match &type_def.kind {
+                    TypeDefKind::Type(Type::Id(used_type_id))
+                        if resolve.types[*used_type_id].owner != type_def.owner =>
+                    {
+                        Some((item_name, type_def.name.clone()?))
+                    }
+                    _ => None,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        for (item_name, type_name) in unused_imports {
+            let mut in_use = false;
+            let location = self.locate(self.wit_dir.package_id, item_name.as_deref(), |line| {
+                if line.starts_with("use ") {
+                    in_use = true;
+                }
+                let found = in_use && contains_word(line, &type_name);
+                if line.contains(';') {
+                    in_use = false;
+                }
+                found
+            })?;
+            self.add_finding(
+                WitLintKind::UnusedImport,
+                format!(
+                    "Type {} is imported with {} in {}, but it is not used",
+                    type_name.log_color_highlight(),
+                    "use".log_color_highlight(),
+                    item_name.unwrap_or_default().log_color_highlight()
+                ),
+                location,
+            );
+        }
+
+        Ok(())
+    }
+
+    fn check_exports(&mut self) -> anyhow::Result<()> {
+        let wit_dir = self.wit_dir;
+        let resolve = &wit_dir.resolve;
+
+        let mut visited_interfaces = HashSet::<InterfaceId>::new();
+
+        let main_package = wit_dir.main_package()?;
+        for world_id in main_package.worlds.values() {
+            let world = &resolve.worlds[*world_id];
+            for item in world.exports.values() {
+                match item {
+                    WorldItem::Interface { id, .. } => {
+                        let interface = &resolve.interfaces[*id];
+
+                        if !visited_interfaces.insert(*id) {
+                            continue;
+                        }
+
+                        let (package_id, item_name) = match &interface.name {
+                            Some(name) => (
+                                interface.package.unwrap_or(self.wit_dir.package_id),
+                                name.clone(),
+                            ),
+                            None => (self.wit_dir.package_id, world.name.clone()),
+                        };
+
+                        for (type_name, type_id) in &interface.types {
+                            if !matches!(resolve.types[*type_id].kind, TypeDefKind::Resource) {
+                                continue;
+                            }
+                            let has_constructor = interface.functions.values().any(|function| {
+                                function.kind == FunctionKind::Constructor(*type_id)
+                            });
+                            if !has_constructor {
+                                let location =
+                                    self.locate(package_id, Some(&item_name), |line| {
+                                        line.starts_with("resource ")
+                                            && contains_word(line, type_name)
+                                    })?;
+                                self.add_finding(
+                                    WitLintKind::ResourceWithoutConstructor,
+                                    format!(
+                                        "Exported resource {} in {} has no constructor, so it cannot be created through RPC",
+                                        type_name.log_color_highlight(),
+                                        item_name.log_color_highlight()
+                                    ),
+                                    location,
+                                );
+                            }
+                        }
+
+                        for function in interface.functions.values() {
+                            self.check_function_wave_compatible(package_id, &item_name, function)?;
+                        }
+                    }
+                    WorldItem::Function(function) => {
+                        let world_name = world.name.clone();
+                        self.check_function_wave_compatible(
+                            self.wit_dir.package_id,
+                            &world_name,
+                            function,
+                        )?;
+                    }
+                    WorldItem::Type(_) => {}
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_function_wave_compatible(
+        &mut self,
+        package_id: PackageId,
+        item_name: &str,
+        function: &Function,
+    ) -> anyhow::Result<()> {
+        let resolve = &self.wit_dir.resolve;
+
+        // The implicit self parameter of methods and the result of constructors are
+        // handled by the invocation itself, so they are not checked
+        let params = match function.kind {
+            FunctionKind::Method(_) | FunctionKind::AsyncMethod(_) => &function.params[1..],
+            _ => &function.params[..],
+        };
+        let result = match function.kind {
+            FunctionKind::Constructor(_) => None,
+            _ => function.result.as_ref(),
+        };
+
+        let incompatible = params
+            .iter()
+            .filter(|(_, typ)| !wit_type_wave_compatible(resolve, typ))
+            .map(|(name, _)| format!("parameter {}", name.log_color_highlight()))
+            .chain(
+                result
+                    .filter(|typ| !wit_type_wave_compatible(resolve, typ))
+                    .map(|_| "result".to_string()),
+            )
+            .collect::<Vec<_>>();
+
+        if !incompatible.is_empty() {
+            let function_name = function.item_name().to_string();
+            let location = self.locate(package_id, Some(item_name), |line| {
+                contains_word(line, &function_name)
+                    && (line.contains("func") || line.starts_with("constructor"))
+            })?;
+            self.add_finding(
+                WitLintKind::WaveIncompatibleType,
+                format!(
+                    "Function {} in {} uses types which cannot be represented in WAVE: {}",
+                    function.name.log_color_highlight(),
+                    item_name.log_color_highlight(),
+                    incompatible.join(", ")
+                ),
+                location,
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Type ids which are referenced by any type definition or function
+    fn used_types(&self) -> HashSet<TypeId> {
+        let wit_dir = self.wit_dir;
+        let resolve = &wit_dir.resolve;
+
+        fn add_type(used_types: &mut HashSet<TypeId>, typ: &Type) {
+            if let Type::Id(type_id) = typ {
+                used_types.insert(*type_id);
+            }
+        }
+
+        fn add_function(used_types: &mut HashSet<TypeId>, function: &Function) {
+            for (_, typ) in &function.params {
+                add_type(used_types, typ);
+            }
+            if let Some(typ) = &function.result {
+                add_type(used_types, typ);
+            }
+        }
+
+        let mut used_types = HashSet::new();
+
+        for (_, type_def) in resolve.types.iter() {
+            match &type_def.kind {
+                TypeDefKind::Record(record) => {
+                    for field in &record.fields {
+                        add_type(&mut used_types, &field.ty);
+                    }
+                }
+                TypeDefKind::Handle(Handle::Own(type_id) | Handle::Borrow(type_id)) => {
+                    used_types.insert(*type_id);
+                }
+                TypeDefKind::Tuple(tuple) => {
+                    for typ in &tuple.types {
+                        add_type(&mut used_types, typ);
+                    }
+                }
+                TypeDefKind::Variant(variant) => {
+                    for case in &variant.cases {
+                        if let Some(typ) = &case.ty {
+                            add_type(&mut used_types, typ);
+                        }
+                    }
+                }
+                TypeDefKind::Result(result) => {
+                    for typ in result.ok.iter().chain(result.err.iter()) {
+                        add_type(&mut used_types, typ);
+                    }
+                }
+                TypeDefKind::Option(typ) | TypeDefKind::List(typ) | TypeDefKind::Type(typ) => {
+                    add_type(&mut used_types, typ);
+                }
+                TypeDefKind::Future(Some(typ)) | TypeDefKind::Stream(Some(typ)) => {
+                    add_type(&mut used_types, typ);
+                }
+                TypeDefKind::Resource
+                | TypeDefKind::Flags(_)
+                | TypeDefKind::Enum(_)
+                | TypeDefKind::Future(None)
+                | TypeDefKind::Stream(None)
+                | TypeDefKind::Unknown => {}
+            }
+        }
+
+        for (_, interface) in resolve.interfaces.iter() {
+            for function in interface.functions.values() {
+                add_function(&mut used_types, function);
+            }
+        }
+
+        for (_, world) in resolve.worlds.iter() {
+            for item in world.imports.values().chain(world.exports.values()) {
+                if let WorldItem::Function(function) = item {
+                    add_function(&mut used_types, function);
+                }
+            }
+        }
+
+        used_types
+    }
+
+    /// Finds the first line in the package sources matching the predicate, optionally only
+    /// searching in the interface or world with the given name. Lines are passed trimmed.
+    fn locate(
+        &self,
+        package_id: PackageId,
+        item_name: Option<&str>,
+        mut matches: impl FnMut(&str) -> bool,
+    ) -> anyhow::Result<Option<WitSourceLocation>> {
+        let Some(package_sources) = self.wit_dir.package_sources.get(&package_id) else {
+            return Ok(None);
+        };
+
+        for file in &package_sources.files {
+            let content = fs::read_to_string(file)?;
+            let mut in_item = item_name.is_none();
+            for (idx, line) in content.lines().enumerate() {
+                let line = line.trim();
+                if let (Some(item_name), Some(header_name)) = (item_name, item_header_name(line)) {
+                    in_item = header_name == item_name;
+                }
+                if in_item && matches(line) {
+                    return Ok(Some(WitSourceLocation {
+                        file: self.source_path(file),
+                        line: idx + 1,
+                    }));
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Maps paths of the resolved wit dir back to the source wit dir, in case the generated
+    /// base wit dir was used for resolving
+    fn source_path(&self, path: &Path) -> PathBuf {
+        match path.strip_prefix(&self.wit_dir.path) {
+            Ok(relative_path) if self.source_wit_dir.join(relative_path).exists() => {
+                self.source_wit_dir.join(relative_path)
+            }
+            _ => path.to_path_buf(),
+        }
+    }
+}
+
+/// Returns the name of the interface or world defined on the line, if any
+fn item_header_name(line: &str) -> Option<&str> {
+    let rest = line
+        .strip_prefix("interface ")
+        .or_else(|| line.strip_prefix("world "))?;
+    let name = rest
+        .split(|c: char| c.is_whitespace() || c == '{')
+        .next()
+        .unwrap_or_default();
+    (!name.is_empty()).then_some(name.trim_start_matches('%'))
+}
+
+fn contains_word(line: &str, word: &str) -> bool {
+    let is_ident_char = |c: char| c.is_ascii_alphanumeric() || c == '-' || c == '_';
+    line.match_indices(word).any(|(idx, _)| {
+        let before = line[..idx].chars().next_back();
+        let after = line[idx + word.len()..].chars().next();
+        !before.is_some_and(is_ident_char) && !after.is_some_and(is_ident_char)
+    })
+}
+
+fn wit_type_wave_compatible(resolve: &Resolve, typ: &Type) -> bool {
+    analysed_type(resolve, typ).is_some_and(|typ| type_wave_compatible(&typ))
+}
+
+/// Converts a resolved wit type to an analysed type, returns None for types which have no
+/// analysed type representation (e.g. futures and streams)
+fn analysed_type(resolve: &Resolve, typ: &Type) -> Option<AnalysedType> {
+    Some(match typ {
+        Type::Bool => bool(),
+        Type::U8 => u8(),
+        Type::U16 => u16(),
+        Type::U32 => u32(),
+        Type::U64 => u64(),
+        Type::S8 => s8(),
+        Type::S16 => s16(),
+        Type::S32 => s32(),
+        Type::S64 => s64(),
+        Type::F32 => f32(),
+        Type::F64 => f64(),
+        Type::Char => chr(),
+        Type::String => str(),
+        Type::ErrorContext => return None,
+        Type::Id(type_id) => match &resolve.types.get(*type_id)?.kind {
+            TypeDefKind::Record(record_def) => record(
+                record_def
+                    .fields
+                    .iter()
+                    .map(|f| Some(field(&f.name, analysed_type(resolve, &f.ty)?)))
+                    .collect::<Option<Vec<_>>>()?,
+            ),
+            TypeDefKind::Resource => handle(
+                AnalysedResourceId(type_id.index() as u64),
+                AnalysedResourceMode::Owned,
+            ),
+            TypeDefKind::Handle(Handle::Own(resource_id)) => handle(
+                AnalysedResourceId(resource_id.index() as u64),
+                AnalysedResourceMode::Owned,
+            ),
+            TypeDefKind::Handle(Handle::Borrow(resource_id)) => handle(
+                AnalysedResourceId(resource_id.index() as u64),
+                AnalysedResourceMode::Borrowed,
+            ),
+            TypeDefKind::Flags(flags_def) => flags(
+                &flags_def
+                    .flags
+                    .iter()
+                    .map(|f| f.name.as_str())
+                    .collect::<Vec<_>>(),
+            ),
+            TypeDefKind::Tuple(tuple_def) => tuple(
+                tuple_def
+                    .types
+                    .iter()
+                    .map(|typ| analysed_type(resolve, typ))
+                    .collect::<Option<Vec<_>>>()?,
+            ),
+            TypeDefKind::Variant(variant_def) => variant(
+                variant_def
+                    .cases
+                    .iter()
+                    .map(|c| match &c.ty {
+                        Some(typ) => Some(case(&c.name, analysed_type(resolve, typ)?)),
+                        None => Some(unit_case(&c.name)),
+                    })
+                    .collect::<Option<Vec<_>>>()?,
+            ),
+            TypeDefKind::Enum(enum_def) => r#enum(
+                &enum_def
+                    .cases
+                    .iter()
+                    .map(|c| c.name.as_str())
+                    .collect::<Vec<_>>(),
+            ),
+            TypeDefKind::Option(inner) => option(analysed_type(resolve, inner)?),
+            TypeDefKind::Result(result_def) => match (&result_def.ok, &result_def.err) {
+                (Some(ok), Some(err)) => {
+                    result(analysed_type(resolve, ok)?, analysed_type(resolve, err)?)
+                }
+                (Some(ok), None) => result_ok(analysed_type(resolve, ok)?),
+                (None, Some(err)) => result_err(analysed_type(resolve, err)?),
+                (None, None) => AnalysedType::Result(TypeResult {
+                    ok: None,
+                    err: None,
+                }),
+            },
+            TypeDefKind::List(inner) => list(analysed_type(resolve, inner)?),
+            TypeDefKind::Type(typ) => analysed_type(resolve, typ)?,
+            TypeDefKind::Future(_) | TypeDefKind::Stream(_) | TypeDefKind::Unknown => return None,
+        },
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::app::wit_lint::{
+        contains_word, item_header_name, lint_rpc_dependencies, lint_wit_dir,
+    };
+    use crate::model::wit_lint::WitLintKind;
+    use crate::wasm_rpc_stubgen::wit_resolve::ResolvedWitDir;
+    use assert2::assert;
+    use test_r::test;
+
+    #[test]
+    fn lint_wit_dir_findings() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let wit_dir = temp_dir.path();
+        std::fs::write(
+            wit_dir.join("component.wit"),
+            r#"package app:component;
+
+interface types {
+  record item {
+    name: string,
+  }
+
+  resource counter;
+}
+
+interface api {
+  use types.{item, counter};
+
+  resource session {
+    get-name: func() -> string;
+  }
+
+  add: func(name: string) -> u64;
+  track: func(c: borrow<counter>);
+}
+
+world component {
+  export api;
+}
+"#,
+        )
+        .unwrap();
+
+        let resolved_wit_dir = ResolvedWitDir::new(wit_dir).unwrap();
+        let findings = lint_wit_dir("app:component", &resolved_wit_dir, wit_dir).unwrap();
+
+        let findings = findings
+            .iter()
+            .map(|finding| {
+                (
+                    finding.kind,
+                    finding.location.as_ref().map(|location| location.line),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert!(
+            findings
+                == vec![
+                    (WitLintKind::UnversionedPackage, Some(1)),
+                    (WitLintKind::UnusedImport, Some(12)),
+                    (WitLintKind::ResourceWithoutConstructor, Some(14)),
+                    (WitLintKind::WaveIncompatibleType, Some(19)),
+                ]
+        );
+    }
+
+    #[test]
+    fn lint_rpc_dependencies_findings() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let dependency_wit_dir = |name: &str, world: &str| {
+            let wit_dir = temp_dir.path().join(name);
+            std::fs::create_dir_all(&wit_dir).unwrap();
+            std::fs::write(
+                wit_dir.join("component.wit"),
+                format!(
+                    r#"package app:{name}@1.0.0;
+
+interface api {{
+  get: func() -> u64;
+}}
+
+world {world} {{
+  export api;
+}}
+"#
+                ),
+            )
+            .unwrap();
+            ResolvedWitDir::new(&wit_dir).unwrap()
+        };
+
+        let first = dependency_wit_dir("first", "first");
+        let second = dependency_wit_dir("second", "second");
+        let third = dependency_wit_dir("third", "second");
+
+        let findings = lint_rpc_dependencies("app:caller", [&first]).unwrap();
+        assert!(findings.is_empty());
+
+        let findings = lint_rpc_dependencies("app:caller", [&first, &second, &third]).unwrap();
+        let findings = findings
+            .iter()
+            .map(|finding| {
+                (
+                    finding.component.as_str(),
+                    finding.kind,
+                    finding.location.is_none(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        // "api" is exported by all three, "second-client" is the client interface of two
+        assert!(
+            findings
+                == vec![
+                    ("app:caller", WitLintKind::DuplicateInterfaceName, true),
+                    ("app:caller", WitLintKind::DuplicateInterfaceName, true),
+                ]
+        );
+    }
+
+    #[test]
+    fn helpers() {
+        assert!(item_header_name("interface api {") == Some("api"));
+        assert!(item_header_name("world component{") == Some("component"));
+        assert!(item_header_name("use types.{item};") == None);
+        assert!(contains_word("use types.{item, counter};", "item"));
+        assert!(!contains_word("use types.{item-id};", "item"));
+    }
+}
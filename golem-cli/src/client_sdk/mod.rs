@@ -0,0 +1,437 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Typed HTTP client SDK generation for the exported functions of a component.
+//!
+//! The generated clients call the worker invocation REST API (`invoke` and `invoke-and-await`),
+//! and convert between native types of the target language and the JSON representation of
+//! WIT values. Records, variants, enums and flags are anonymous in the component metadata,
+//! so their names are derived from where they are first used.
+
+pub mod python;
+pub mod rust;
+pub mod typescript;
+
+use crate::model::component::format_function_name;
+use golem_wasm_ast::analysis::{AnalysedExport, AnalysedFunction, AnalysedInstance, AnalysedType};
+use heck::ToUpperCamelCase;
+use std::collections::BTreeSet;
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+use uuid::Uuid;
+
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+#[clap(rename_all = "kebab_case")]
+pub enum ClientLanguage {
+    #[value(name = "ts", alias = "typescript")]
+    TypeScript,
+    Rust,
+    Python,
+}
+
+impl Display for ClientLanguage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientLanguage::TypeScript => write!(f, "TypeScript"),
+            ClientLanguage::Rust => write!(f, "Rust"),
+            ClientLanguage::Python => write!(f, "Python"),
+        }
+    }
+}
+
+/// Generated source file, relative to the output directory
+pub struct ClientSdkFile {
+    pub path: PathBuf,
+    pub content: String,
+}
+
+pub struct ClientSdk {
+    pub component_name: String,
+    pub component_id: Uuid,
+    pub interfaces: Vec<ClientInterface>,
+    pub types: Vec<NamedType>,
+    /// Functions which cannot be called through the generated clients, e.g. resource functions
+    pub skipped_functions: Vec<String>,
+}
+
+/// Exported instance, or the top level exported functions when `name` is empty
+pub struct ClientInterface {
+    pub name: Option<String>,
+    pub client_name: String,
+    pub functions: Vec<ClientFunction>,
+}
+
+pub struct ClientFunction {
+    pub name: String,
+    /// Fully qualified function name, as expected by the invocation API
+    pub qualified_name: String,
+    pub parameters: Vec<(String, AnalysedType)>,
+    pub results: Vec<AnalysedType>,
+}
+
+/// Record, variant, enum or flags type with a derived name
+pub struct NamedType {
+    pub name: String,
+    pub typ: AnalysedType,
+    /// Names of the variant cases for languages representing them as separate types
+    pub case_names: Vec<String>,
+}
+
+/// Names used by the runtime parts of the generated clients
+const RESERVED_NAMES: &[&str] = &[
+    "ClientOptions",
+    "Err",
+    "Error",
+    "InvocationError",
+    "Invoker",
+    "Ok",
+    "Option",
+    "Result",
+    "Self",
+    "String",
+    "Value",
+    "Vec",
+    "WitValue",
+];
+
+impl ClientSdk {
+    pub fn new(component_name: &str, component_id: Uuid, exports: &[AnalysedExport]) -> Self {
+        let mut sdk = Self {
+            component_name: component_name.to_string(),
+            component_id,
+            interfaces: vec![],
+            types: vec![],
+            skipped_functions: vec![],
+        };
+        let mut names = RESERVED_NAMES
+            .iter()
+            .map(|name| name.to_string())
+            .collect::<BTreeSet<_>>();
+
+        let global_functions = exports
+            .iter()
+            .filter_map(|export| match export {
+                AnalysedExport::Function(function) => Some(function.clone()),
+                AnalysedExport::Instance(_) => None,
+            })
+            .collect::<Vec<_>>();
+        if !global_functions.is_empty() {
+            sdk.add_interface(&mut names, None, &global_functions);
+        }
+        for export in exports {
+            if let AnalysedExport::Instance(AnalysedInstance { name, functions }) = export {
+                sdk.add_interface(&mut names, Some(name), functions);
+            }
+        }
+
+        sdk
+    }
+
+    /// Name of the record, variant, enum or flags type
+    pub fn type_name(&self, typ: &AnalysedType) -> Option<&str> {
+        self.types
+            .iter()
+            .find(|named_type| &named_type.typ == typ)
+            .map(|named_type| named_type.name.as_str())
+    }
+
+    fn add_interface(
+        &mut self,
+        names: &mut BTreeSet<String>,
+        name: Option<&str>,
+        functions: &[AnalysedFunction],
+    ) {
+        let client_name = unique_name(
+            names,
+            &format!(
+                "{}Client",
+                name.map(interface_local_name)
+                    .unwrap_or_default()
+                    .to_upper_camel_case()
+            ),
+        );
+
+        let mut interface = ClientInterface {
+            name: name.map(|name| name.to_string()),
+            client_name,
+            functions: vec![],
+        };
+        for function in functions {
+            let qualified_name = format_function_name(name, &function.name);
+            if is_resource_function(function) {
+                self.skipped_functions.push(qualified_name);
+                continue;
+            }
+
+            let hint = function.name.to_upper_camel_case();
+            for parameter in &function.parameters {
+                self.add_types(
+                    names,
+                    &parameter.typ,
+                    &format!("{}{}", hint, parameter.name.to_upper_camel_case()),
+                );
+            }
+            for result in &function.results {
+                self.add_types(names, &result.typ, &format!("{}Result", hint));
+            }
+
+            interface.functions.push(ClientFunction {
+                name: function.name.clone(),
+                qualified_name,
+                parameters: function
+                    .parameters
+                    .iter()
+                    .map(|parameter| (parameter.name.clone(), parameter.typ.clone()))
+                    .collect(),
+                results: function
+                    .results
+                    .iter()
+                    .map(|result| result.typ.clone())
+                    .collect(),
+            });
+        }
+
+        self.interfaces.push(interface);
+    }
+
+    fn add_types(&mut self, names: &mut BTreeSet<String>, typ: &AnalysedType, hint: &str) {
+        let is_named = matches!(
+            typ,
+            AnalysedType::Record(_)
+                | AnalysedType::Variant(_)
+                | AnalysedType::Enum(_)
+                | AnalysedType::Flags(_)
+        );
+        let name = if is_named {
+            match self.type_name(typ) {
+                Some(_) => return,
+                None => {
+                    let name = unique_name(names, hint);
+                    let case_names = match typ {
+                        AnalysedType::Variant(variant) => variant
+                            .cases
+                            .iter()
+                            .map(|case| {
+                                unique_name(
+                                    names,
+                                    &format!("{}{}", name, case.name.to_upper_camel_case()),
+                                )
+                            })
+                            .collect(),
+                        _ => vec![],
+                    };
+                    self.types.push(NamedType {
+                        name: name.clone(),
+                        typ: typ.clone(),
+                        case_names,
+                    });
+                    name
+                }
+            }
+        } else {
+            hint.to_string()
+        };
+
+        match typ {
+            AnalysedType::Record(record) => {
+                for field in &record.fields {
+                    self.add_types(
+                        names,
+                        &field.typ,
+                        &format!("{}{}", name, field.name.to_upper_camel_case()),
+                    );
+                }
+            }
+            AnalysedType::Variant(variant) => {
+                for case in &variant.cases {
+                    if let Some(typ) = &case.typ {
+                        self.add_types(
+                            names,
+                            typ,
+                            &format!("{}{}Value", name, case.name.to_upper_camel_case()),
+                        );
+                    }
+                }
+            }
+            AnalysedType::Option(option) => self.add_types(names, &option.inner, &name),
+            AnalysedType::List(list) => {
+                self.add_types(names, &list.inner, &format!("{}Item", name))
+            }
+            AnalysedType::Tuple(tuple) => {
+                for (idx, item) in tuple.items.iter().enumerate() {
+                    self.add_types(names, item, &format!("{}Item{}", name, idx));
+                }
+            }
+            AnalysedType::Result(result) => {
+                if let Some(ok) = &result.ok {
+                    self.add_types(names, ok, &format!("{}Ok", name));
+                }
+                if let Some(err) = &result.err {
+                    self.add_types(names, err, &format!("{}Error", name));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Renders the client SDK for the requested language
+pub fn generate_client_sdk(sdk: &ClientSdk, language: ClientLanguage) -> Vec<ClientSdkFile> {
+    match language {
+        ClientLanguage::TypeScript => typescript::generate(sdk),
+        ClientLanguage::Rust => rust::generate(sdk),
+        ClientLanguage::Python => python::generate(sdk),
+    }
+}
+
+fn interface_local_name(name: &str) -> &str {
+    let name = name.split_once('@').map(|(name, _)| name).unwrap_or(name);
+    name.rsplit_once('/').map(|(_, name)| name).unwrap_or(name)
+}
+
+fn unique_name(names: &mut BTreeSet<String>, name: &str) -> String {
+    let mut unique = name.to_string();
+    let mut idx = 2;
+    while names.contains(&unique) {
+        unique = format!("{}{}", name, idx);
+        idx += 1;
+    }
+    names.insert(unique.clone());
+    unique
+}
+
+/// Resource constructors and methods need a resource handle, which cannot be passed through
+/// the generated clients
+fn is_resource_function(function: &AnalysedFunction) -> bool {
+    function.name.starts_with('[')
+        || function
+            .parameters
+            .iter()
+            .any(|parameter| contains_handle(&parameter.typ))
+        || function
+            .results
+            .iter()
+            .any(|result| contains_handle(&result.typ))
+}
+
+fn contains_handle(typ: &AnalysedType) -> bool {
+    match typ {
+        AnalysedType::Handle(_) => true,
+        AnalysedType::Record(record) => record
+            .fields
+            .iter()
+            .any(|field| contains_handle(&field.typ)),
+        AnalysedType::Variant(variant) => variant
+            .cases
+            .iter()
+            .any(|case| case.typ.iter().any(contains_handle)),
+        AnalysedType::Option(option) => contains_handle(&option.inner),
+        AnalysedType::List(list) => contains_handle(&list.inner),
+        AnalysedType::Tuple(tuple) => tuple.items.iter().any(contains_handle),
+        AnalysedType::Result(result) => {
+            result.ok.iter().any(|typ| contains_handle(typ))
+                || result.err.iter().any(|typ| contains_handle(typ))
+        }
+        _ => false,
+    }
+}
+
+/// Serialized analysed type, sent as the type annotation of the parameters
+fn type_json(typ: &AnalysedType) -> String {
+    serde_json::to_string(typ).expect("Failed to serialize analysed type")
+}
+
+#[cfg(test)]
+mod test {
+    use crate::client_sdk::ClientSdk;
+    use assert2::assert;
+    use golem_wasm_ast::analysis::analysed_type::{
+        case, field, handle, list, option, r#enum, record, str, u32, unit_case, variant,
+    };
+    use golem_wasm_ast::analysis::{
+        AnalysedExport, AnalysedFunction, AnalysedFunctionParameter, AnalysedFunctionResult,
+        AnalysedInstance, AnalysedResourceId, AnalysedResourceMode, AnalysedType,
+    };
+    use test_r::test;
+    use uuid::Uuid;
+
+    pub(crate) fn example_item() -> AnalysedType {
+        record(vec![
+            field("name", str()),
+            field("count", u32()),
+            field("status", r#enum(&["open", "closed"])),
+        ])
+    }
+
+    /// Interface with a record (containing an enum), a variant and a resource, shared by the
+    /// tests of the generators
+    pub(crate) fn example_exports() -> Vec<AnalysedExport> {
+        let item = example_item();
+        vec![AnalysedExport::Instance(AnalysedInstance {
+            name: "golem:it/api".to_string(),
+            functions: vec![
+                AnalysedFunction {
+                    name: "add-item".to_string(),
+                    parameters: vec![AnalysedFunctionParameter {
+                        name: "item".to_string(),
+                        typ: item.clone(),
+                    }],
+                    results: vec![AnalysedFunctionResult {
+                        name: None,
+                        typ: variant(vec![case("added", u32()), unit_case("rejected")]),
+                    }],
+                },
+                AnalysedFunction {
+                    name: "get-items".to_string(),
+                    parameters: vec![],
+                    results: vec![AnalysedFunctionResult {
+                        name: None,
+                        typ: list(option(item)),
+                    }],
+                },
+                AnalysedFunction {
+                    name: "[constructor]counter".to_string(),
+                    parameters: vec![],
+                    results: vec![AnalysedFunctionResult {
+                        name: None,
+                        typ: handle(AnalysedResourceId(0), AnalysedResourceMode::Owned),
+                    }],
+                },
+            ],
+        })]
+    }
+
+    #[test]
+    fn named_types_are_derived_from_first_usage() {
+        let sdk = ClientSdk::new("test", Uuid::new_v4(), &example_exports());
+
+        let names = sdk
+            .types
+            .iter()
+            .map(|named_type| named_type.name.as_str())
+            .collect::<Vec<_>>();
+        assert!(names == vec!["AddItemItem", "AddItemItemStatus", "AddItemResult"]);
+        assert!(sdk.types[2].case_names == vec!["AddItemResultAdded", "AddItemResultRejected"]);
+    }
+
+    #[test]
+    fn resource_functions_are_skipped() {
+        let sdk = ClientSdk::new("test", Uuid::new_v4(), &example_exports());
+
+        assert!(sdk.interfaces.len() == 1);
+        assert!(sdk.interfaces[0].client_name == "ApiClient");
+        assert!(sdk.interfaces[0].functions.len() == 2);
+        assert!(sdk.skipped_functions == vec!["golem:it/api.{[constructor]counter}"]);
+    }
+}
@@ -0,0 +1,715 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::client_sdk::{type_json, ClientFunction, ClientSdk, ClientSdkFile, NamedType};
+use golem_wasm_ast::analysis::AnalysedType;
+use heck::{ToShoutySnakeCase, ToSnakeCase};
+use indoc::indoc;
+use std::path::PathBuf;
+
+const IMPORTS: &str = indoc! {r#"
+    from __future__ import annotations
+
+    import json
+    import urllib.error
+    import urllib.parse
+    import urllib.request
+    from dataclasses import dataclass
+    from enum import Enum
+    from typing import Any, Callable, Generic, List, Optional, Tuple, TypeVar, Union
+"#};
+
+const RUNTIME: &str = indoc! {r#"
+    T = TypeVar("T")
+    E = TypeVar("E")
+
+
+    @dataclass
+    class Ok(Generic[T]):
+        value: T
+
+
+    @dataclass
+    class Err(Generic[E]):
+        value: E
+
+
+    Result = Union[Ok[T], Err[E]]
+
+
+    class InvocationError(Exception):
+        def __init__(self, message: str, status: int, body: str):
+            super().__init__(message)
+            self.status = status
+            self.body = body
+
+
+    def _identity(value: Any) -> Any:
+        return value
+
+
+    def _unit(value: Any) -> None:
+        return None
+
+
+    def _list_encoder(item: Callable) -> Callable:
+        return lambda value: [item(v) for v in value]
+
+
+    def _list_decoder(item: Callable) -> Callable:
+        return lambda value: [item(v) for v in value]
+
+
+    def _tuple_encoder(items: List[Callable]) -> Callable:
+        return lambda value: [item(v) for item, v in zip(items, value)]
+
+
+    def _tuple_decoder(items: List[Callable]) -> Callable:
+        return lambda value: tuple(item(v) for item, v in zip(items, value))
+
+
+    def _option_encoder(inner: Callable) -> Callable:
+        return lambda value: None if value is None else inner(value)
+
+
+    def _option_decoder(inner: Callable) -> Callable:
+        return lambda value: None if value is None else inner(value)
+
+
+    def _result_encoder(ok: Callable, err: Callable) -> Callable:
+        return lambda value: {"ok": ok(value.value)} if isinstance(value, Ok) else {"err": err(value.value)}
+
+
+    def _result_decoder(ok: Callable, err: Callable) -> Callable:
+        return lambda value: Ok(ok(value["ok"])) if "ok" in value else Err(err(value["err"]))
+
+
+    def _variant_case(value: Any) -> Tuple[str, Any]:
+        if isinstance(value, str):
+            return value, None
+        [(tag, val)] = value.items()
+        return tag, val
+
+
+    class Invoker:
+        """Connection to a single worker through the worker invocation API"""
+
+        def __init__(
+            self,
+            base_url: str,
+            worker_name: str,
+            component_id: str = COMPONENT_ID,
+            token: Optional[str] = None,
+            timeout: Optional[float] = None,
+        ):
+            self.base_url = base_url.rstrip("/")
+            self.worker_name = worker_name
+            self.component_id = component_id
+            self.token = token
+            self.timeout = timeout
+
+        def _request(self, endpoint: str, function_name: str, params: List[Any]) -> Any:
+            url = "{}/v1/components/{}/workers/{}/{}?{}".format(
+                self.base_url,
+                urllib.parse.quote(self.component_id, safe=""),
+                urllib.parse.quote(self.worker_name, safe=""),
+                endpoint,
+                urllib.parse.urlencode({"function": function_name}),
+            )
+            headers = {"Content-Type": "application/json"}
+            if self.token is not None:
+                headers["Authorization"] = "Bearer " + self.token
+            request = urllib.request.Request(
+                url, data=json.dumps({"params": params}).encode("utf-8"), headers=headers, method="POST"
+            )
+            try:
+                with urllib.request.urlopen(request, timeout=self.timeout) as response:
+                    body = response.read().decode("utf-8")
+            except urllib.error.HTTPError as err:
+                body = err.read().decode("utf-8")
+                raise InvocationError(
+                    "Invocation of {} failed with status {}: {}".format(function_name, err.code, body), err.code, body
+                ) from err
+            return json.loads(body) if body else None
+
+        def _invoke_and_await(self, function_name: str, params: List[Any]) -> List[Any]:
+            return self._request("invoke-and-await", function_name, params)["result"]["value"]
+
+        def _invoke(self, function_name: str, params: List[Any]) -> None:
+            self._request("invoke", function_name, params)
+"#};
+
+const KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield", "self",
+];
+
+pub fn generate(sdk: &ClientSdk) -> Vec<ClientSdkFile> {
+    let mut out = String::new();
+    out.push_str(&format!(
+        "# Generated by golem-cli for component {} ({}), do not edit manually\n\n",
+        sdk.component_name, sdk.component_id
+    ));
+    out.push_str(IMPORTS);
+    out.push_str(&format!("\nCOMPONENT_ID = \"{}\"\n\n", sdk.component_id));
+    out.push_str(RUNTIME);
+
+    for named_type in &sdk.types {
+        out.push_str("\n\n");
+        out.push_str(&named_type_def(sdk, named_type));
+    }
+
+    for interface in &sdk.interfaces {
+        out.push_str("\n\n");
+        out.push_str(&format!("class {}(Invoker):\n", interface.client_name));
+        match &interface.name {
+            Some(name) => out.push_str(&format!(
+                "    \"\"\"Client for the exported interface `{}`\"\"\"\n",
+                name
+            )),
+            None => out.push_str("    \"\"\"Client for the exported top level functions\"\"\"\n"),
+        }
+        for function in &interface.functions {
+            out.push('\n');
+            out.push_str(&function_defs(sdk, function));
+        }
+    }
+
+    vec![ClientSdkFile {
+        path: PathBuf::from("client.py"),
+        content: out,
+    }]
+}
+
+fn named_type_def(sdk: &ClientSdk, named_type: &NamedType) -> String {
+    let name = &named_type.name;
+    let snake_name = name.to_snake_case();
+    let mut out = String::new();
+    match &named_type.typ {
+        AnalysedType::Record(record) => {
+            out.push_str(&format!("@dataclass\nclass {}:\n", name));
+            if record.fields.is_empty() {
+                out.push_str("    pass\n");
+            }
+            for field in &record.fields {
+                out.push_str(&format!(
+                    "    {}: {}\n",
+                    ident(&field.name),
+                    py_type(sdk, &field.typ)
+                ));
+            }
+
+            out.push_str(&format!(
+                "\n\ndef _encode_{}(value: {}) -> Any:\n    return {{\n",
+                snake_name, name
+            ));
+            for field in &record.fields {
+                out.push_str(&format!(
+                    "        \"{}\": {}(value.{}),\n",
+                    field.name,
+                    encoder(sdk, &field.typ),
+                    ident(&field.name)
+                ));
+            }
+            out.push_str("    }\n");
+
+            out.push_str(&format!(
+                "\n\ndef _decode_{}(value: Any) -> {}:\n    return {}(\n",
+                snake_name, name, name
+            ));
+            for field in &record.fields {
+                out.push_str(&format!(
+                    "        {}={}(value.get(\"{}\")),\n",
+                    ident(&field.name),
+                    decoder(sdk, &field.typ),
+                    field.name
+                ));
+            }
+            out.push_str("    )\n");
+        }
+        AnalysedType::Variant(variant) => {
+            for (case, case_name) in variant.cases.iter().zip(&named_type.case_names) {
+                out.push_str(&format!("@dataclass\nclass {}:\n", case_name));
+                match &case.typ {
+                    Some(typ) => out.push_str(&format!("    value: {}\n\n\n", py_type(sdk, typ))),
+                    None => out.push_str("    pass\n\n\n"),
+                }
+            }
+            out.push_str(&format!(
+                "{} = Union[{}]\n",
+                name,
+                named_type.case_names.join(", ")
+            ));
+
+            out.push_str(&format!(
+                "\n\ndef _encode_{}(value: {}) -> Any:\n",
+                snake_name, name
+            ));
+            for (case, case_name) in variant.cases.iter().zip(&named_type.case_names) {
+                out.push_str(&format!("    if isinstance(value, {}):\n", case_name));
+                match &case.typ {
+                    Some(typ) => out.push_str(&format!(
+                        "        return {{\"{}\": {}(value.value)}}\n",
+                        case.name,
+                        encoder(sdk, typ)
+                    )),
+                    None => out.push_str(&format!("        return {{\"{}\": None}}\n", case.name)),
+                }
+            }
+            out.push_str(&format!(
+                "    raise ValueError(\"Unknown case of {}: {{}}\".format(value))\n",
+                name
+            ));
+
+            out.push_str(&format!(
+                "\n\ndef _decode_{}(value: Any) -> {}:\n    tag, val = _variant_case(value)\n",
+                snake_name, name
+            ));
+            for (case, case_name) in variant.cases.iter().zip(&named_type.case_names) {
+                out.push_str(&format!("    if tag == \"{}\":\n", case.name));
+                match &case.typ {
+                    Some(typ) => out.push_str(&format!(
+                        "        return {}({}(val))\n",
+                        case_name,
+                        decoder(sdk, typ)
+                    )),
+                    None => out.push_str(&format!("        return {}()\n", case_name)),
+                }
+            }
+            out.push_str(&format!(
+                "    raise ValueError(\"Unknown case of {}: {{}}\".format(tag))\n",
+                name
+            ));
+        }
+        AnalysedType::Enum(enum_type) => {
+            out.push_str(&format!("class {}(Enum):\n", name));
+            if enum_type.cases.is_empty() {
+                out.push_str("    pass\n");
+            }
+            for case in &enum_type.cases {
+                out.push_str(&format!("    {} = \"{}\"\n", case_ident(case), case));
+            }
+            out.push_str(&format!(
+                "\n\ndef _encode_{}(value: {}) -> Any:\n    return value.value\n",
+                snake_name, name
+            ));
+            out.push_str(&format!(
+                "\n\ndef _decode_{}(value: Any) -> {}:\n    return {}(value)\n",
+                snake_name, name, name
+            ));
+        }
+        AnalysedType::Flags(flags) => {
+            out.push_str(&format!("@dataclass\nclass {}:\n", name));
+            if flags.names.is_empty() {
+                out.push_str("    pass\n");
+            }
+            for flag in &flags.names {
+                out.push_str(&format!("    {}: bool = False\n", ident(flag)));
+            }
+
+            out.push_str(&format!(
+                "\n\ndef _encode_{}(value: {}) -> Any:\n    flags = []\n",
+                snake_name, name
+            ));
+            for flag in &flags.names {
+                out.push_str(&format!(
+                    "    if value.{}:\n        flags.append(\"{}\")\n",
+                    ident(flag),
+                    flag
+                ));
+            }
+            out.push_str("    return flags\n");
+
+            out.push_str(&format!(
+                "\n\ndef _decode_{}(value: Any) -> {}:\n    return {}(\n",
+                snake_name, name, name
+            ));
+            for flag in &flags.names {
+                out.push_str(&format!("        {}=\"{}\" in value,\n", ident(flag), flag));
+            }
+            out.push_str("    )\n");
+        }
+        _ => {}
+    }
+    out
+}
+
+fn function_defs(sdk: &ClientSdk, function: &ClientFunction) -> String {
+    let method_name = ident(&function.name);
+    let params = function
+        .parameters
+        .iter()
+        .map(|(name, typ)| format!(", {}: {}", ident(name), py_type(sdk, typ)))
+        .collect::<String>();
+    let encoded_params = function
+        .parameters
+        .iter()
+        .map(|(name, typ)| {
+            format!(
+                "{{\"typ\": json.loads({}), \"value\": {}({})}}",
+                py_string(&type_json(typ)),
+                encoder(sdk, typ),
+                ident(name)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let (result_type, result_expr) = match function.results.as_slice() {
+        [] => ("None".to_string(), None),
+        [typ] => (
+            py_type(sdk, typ),
+            Some(format!("{}(result[0])", decoder(sdk, typ))),
+        ),
+        results => (
+            format!(
+                "Tuple[{}]",
+                results
+                    .iter()
+                    .map(|typ| py_type(sdk, typ))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Some(format!(
+                "_tuple_decoder([{}])(result)",
+                results
+                    .iter()
+                    .map(|typ| decoder(sdk, typ))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )),
+        ),
+    };
+
+    let mut out = String::new();
+    out.push_str(&format!(
+        "    def {}(self{}) -> {}:\n",
+        method_name, params, result_type
+    ));
+    out.push_str(&format!(
+        "        \"\"\"Invokes `{}` and awaits its result\"\"\"\n",
+        function.qualified_name
+    ));
+    match result_expr {
+        Some(result_expr) => {
+            out.push_str(&format!(
+                "        result = self._invoke_and_await(\"{}\", [{}])\n",
+                function.qualified_name, encoded_params
+            ));
+            out.push_str(&format!("        return {}\n", result_expr));
+        }
+        None => out.push_str(&format!(
+            "        self._invoke_and_await(\"{}\", [{}])\n",
+            function.qualified_name, encoded_params
+        )),
+    }
+
+    out.push_str(&format!(
+        "\n    def enqueue_{}(self{}) -> None:\n",
+        function.name.to_snake_case(),
+        params
+    ));
+    out.push_str(&format!(
+        "        \"\"\"Enqueues an invocation of `{}` without awaiting its result\"\"\"\n",
+        function.qualified_name
+    ));
+    out.push_str(&format!(
+        "        self._invoke(\"{}\", [{}])\n",
+        function.qualified_name, encoded_params
+    ));
+    out
+}
+
+fn py_type(sdk: &ClientSdk, typ: &AnalysedType) -> String {
+    if let Some(name) = sdk.type_name(typ) {
+        return name.to_string();
+    }
+    let unit_or = |typ: &Option<Box<AnalysedType>>| match typ {
+        Some(typ) => py_type(sdk, typ),
+        None => "None".to_string(),
+    };
+    match typ {
+        AnalysedType::Bool(_) => "bool".to_string(),
+        AnalysedType::S8(_)
+        | AnalysedType::U8(_)
+        | AnalysedType::S16(_)
+        | AnalysedType::U16(_)
+        | AnalysedType::S32(_)
+        | AnalysedType::U32(_)
+        | AnalysedType::S64(_)
+        | AnalysedType::U64(_) => "int".to_string(),
+        AnalysedType::F32(_) | AnalysedType::F64(_) => "float".to_string(),
+        AnalysedType::Chr(_) | AnalysedType::Str(_) | AnalysedType::Handle(_) => "str".to_string(),
+        AnalysedType::List(list) => format!("List[{}]", py_type(sdk, &list.inner)),
+        AnalysedType::Tuple(tuple) => format!(
+            "Tuple[{}]",
+            tuple
+                .items
+                .iter()
+                .map(|item| py_type(sdk, item))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        AnalysedType::Option(option) => format!("Optional[{}]", py_type(sdk, &option.inner)),
+        AnalysedType::Result(result) => {
+            format!("Result[{}, {}]", unit_or(&result.ok), unit_or(&result.err))
+        }
+        AnalysedType::Record(_)
+        | AnalysedType::Variant(_)
+        | AnalysedType::Enum(_)
+        | AnalysedType::Flags(_) => "Any".to_string(),
+    }
+}
+
+fn encoder(sdk: &ClientSdk, typ: &AnalysedType) -> String {
+    if let Some(name) = sdk.type_name(typ) {
+        return format!("_encode_{}", name.to_snake_case());
+    }
+    let unit_or = |typ: &Option<Box<AnalysedType>>| match typ {
+        Some(typ) => encoder(sdk, typ),
+        None => "_unit".to_string(),
+    };
+    match typ {
+        AnalysedType::List(list) => format!("_list_encoder({})", encoder(sdk, &list.inner)),
+        AnalysedType::Tuple(tuple) => format!(
+            "_tuple_encoder([{}])",
+            tuple
+                .items
+                .iter()
+                .map(|item| encoder(sdk, item))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        AnalysedType::Option(option) => {
+            format!("_option_encoder({})", encoder(sdk, &option.inner))
+        }
+        AnalysedType::Result(result) => format!(
+            "_result_encoder({}, {})",
+            unit_or(&result.ok),
+            unit_or(&result.err)
+        ),
+        _ => "_identity".to_string(),
+    }
+}
+
+fn decoder(sdk: &ClientSdk, typ: &AnalysedType) -> String {
+    if let Some(name) = sdk.type_name(typ) {
+        return format!("_decode_{}", name.to_snake_case());
+    }
+    let unit_or = |typ: &Option<Box<AnalysedType>>| match typ {
+        Some(typ) => decoder(sdk, typ),
+        None => "_unit".to_string(),
+    };
+    match typ {
+        AnalysedType::List(list) => format!("_list_decoder({})", decoder(sdk, &list.inner)),
+        AnalysedType::Tuple(tuple) => format!(
+            "_tuple_decoder([{}])",
+            tuple
+                .items
+                .iter()
+                .map(|item| decoder(sdk, item))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        AnalysedType::Option(option) => {
+            format!("_option_decoder({})", decoder(sdk, &option.inner))
+        }
+        AnalysedType::Result(result) => format!(
+            "_result_decoder({}, {})",
+            unit_or(&result.ok),
+            unit_or(&result.err)
+        ),
+        _ => "_identity".to_string(),
+    }
+}
+
+fn py_string(value: &str) -> String {
+    serde_json::to_string(value).expect("Failed to serialize string")
+}
+
+fn ident(name: &str) -> String {
+    let ident = name.to_snake_case();
+    if KEYWORDS.contains(&ident.as_str()) {
+        format!("{}_", ident)
+    } else {
+        ident
+    }
+}
+
+fn case_ident(name: &str) -> String {
+    let ident = name.to_shouty_snake_case();
+    if KEYWORDS.contains(&ident.as_str()) {
+        format!("{}_", ident)
+    } else {
+        ident
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::client_sdk::python::{generate, py_string, IMPORTS, RUNTIME};
+    use crate::client_sdk::test::{example_exports, example_item};
+    use crate::client_sdk::{type_json, ClientSdk};
+    use assert2::assert;
+    use golem_wasm_ast::analysis::analysed_type::{case, list, str, u8, unit_case, variant};
+    use golem_wasm_ast::analysis::{
+        AnalysedExport, AnalysedFunction, AnalysedFunctionParameter, AnalysedFunctionResult,
+    };
+    use indoc::indoc;
+    use test_r::test;
+    use uuid::Uuid;
+
+    #[test]
+    fn generates_typed_client() {
+        let exports = vec![AnalysedExport::Function(AnalysedFunction {
+            name: "lookup".to_string(),
+            parameters: vec![AnalysedFunctionParameter {
+                name: "from".to_string(),
+                typ: list(str()),
+            }],
+            results: vec![AnalysedFunctionResult {
+                name: None,
+                typ: variant(vec![case("found", u8()), unit_case("missing")]),
+            }],
+        })];
+        let sdk = ClientSdk::new("test", Uuid::nil(), &exports);
+
+        let files = generate(&sdk);
+        let content = &files[0].content;
+
+        assert!(content.starts_with("# Generated by golem-cli"));
+        assert!(content.contains("from __future__ import annotations\n"));
+        assert!(content.contains("@dataclass\nclass LookupResultFound:\n    value: int\n"));
+        assert!(content.contains("LookupResult = Union[LookupResultFound, LookupResultMissing]\n"));
+        assert!(content.contains("class Client(Invoker):"));
+        assert!(content.contains("    def lookup(self, from_: List[str]) -> LookupResult:"));
+        assert!(content.contains("        return _decode_lookup_result(result[0])"));
+        assert!(content.contains("    def enqueue_lookup(self, from_: List[str]) -> None:"));
+    }
+
+    #[test]
+    fn generates_record_variant_resource_interface() {
+        let sdk = ClientSdk::new("test", Uuid::nil(), &example_exports());
+
+        let files = generate(&sdk);
+        assert!(files.len() == 1);
+        assert!(files[0].path.to_string_lossy() == "client.py");
+
+        let (header, generated) = files[0].content.split_once(RUNTIME).unwrap();
+        assert!(
+            header
+                == format!(
+                    "# Generated by golem-cli for component test (00000000-0000-0000-0000-000000000000), do not edit manually\n\n\
+                    {}\nCOMPONENT_ID = \"00000000-0000-0000-0000-000000000000\"\n\n",
+                    IMPORTS
+                )
+        );
+
+        let expected = indoc! {r#"
+            @dataclass
+            class AddItemItem:
+                name: str
+                count: int
+                status: AddItemItemStatus
+
+
+            def _encode_add_item_item(value: AddItemItem) -> Any:
+                return {
+                    "name": _identity(value.name),
+                    "count": _identity(value.count),
+                    "status": _encode_add_item_item_status(value.status),
+                }
+
+
+            def _decode_add_item_item(value: Any) -> AddItemItem:
+                return AddItemItem(
+                    name=_identity(value.get("name")),
+                    count=_identity(value.get("count")),
+                    status=_decode_add_item_item_status(value.get("status")),
+                )
+
+
+            class AddItemItemStatus(Enum):
+                OPEN = "open"
+                CLOSED = "closed"
+
+
+            def _encode_add_item_item_status(value: AddItemItemStatus) -> Any:
+                return value.value
+
+
+            def _decode_add_item_item_status(value: Any) -> AddItemItemStatus:
+                return AddItemItemStatus(value)
+
+
+            @dataclass
+            class AddItemResultAdded:
+                value: int
+
+
+            @dataclass
+            class AddItemResultRejected:
+                pass
+
+
+            AddItemResult = Union[AddItemResultAdded, AddItemResultRejected]
+
+
+            def _encode_add_item_result(value: AddItemResult) -> Any:
+                if isinstance(value, AddItemResultAdded):
+                    return {"added": _identity(value.value)}
+                if isinstance(value, AddItemResultRejected):
+                    return {"rejected": None}
+                raise ValueError("Unknown case of AddItemResult: {}".format(value))
+
+
+            def _decode_add_item_result(value: Any) -> AddItemResult:
+                tag, val = _variant_case(value)
+                if tag == "added":
+                    return AddItemResultAdded(_identity(val))
+                if tag == "rejected":
+                    return AddItemResultRejected()
+                raise ValueError("Unknown case of AddItemResult: {}".format(tag))
+
+
+            class ApiClient(Invoker):
+                """Client for the exported interface `golem:it/api`"""
+
+                def add_item(self, item: AddItemItem) -> AddItemResult:
+                    """Invokes `golem:it/api.{add-item}` and awaits its result"""
+                    result = self._invoke_and_await("golem:it/api.{add-item}", [{"typ": json.loads(ITEM_TYPE), "value": _encode_add_item_item(item)}])
+                    return _decode_add_item_result(result[0])
+
+                def enqueue_add_item(self, item: AddItemItem) -> None:
+                    """Enqueues an invocation of `golem:it/api.{add-item}` without awaiting its result"""
+                    self._invoke("golem:it/api.{add-item}", [{"typ": json.loads(ITEM_TYPE), "value": _encode_add_item_item(item)}])
+
+                def get_items(self) -> List[Optional[AddItemItem]]:
+                    """Invokes `golem:it/api.{get-items}` and awaits its result"""
+                    result = self._invoke_and_await("golem:it/api.{get-items}", [])
+                    return _list_decoder(_option_decoder(_decode_add_item_item))(result[0])
+
+                def enqueue_get_items(self) -> None:
+                    """Enqueues an invocation of `golem:it/api.{get-items}` without awaiting its result"""
+                    self._invoke("golem:it/api.{get-items}", [])
+        "#}
+        .replace("ITEM_TYPE", &py_string(&type_json(&example_item())));
+
+        // Resource functions are skipped, all named types are emitted before the clients
+        assert!(generated == format!("\n\n{}", expected));
+        assert!(sdk.skipped_functions == vec!["golem:it/api.{[constructor]counter}"]);
+    }
+}
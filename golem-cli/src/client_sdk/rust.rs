@@ -0,0 +1,942 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::client_sdk::{type_json, ClientFunction, ClientSdk, ClientSdkFile, NamedType};
+use golem_wasm_ast::analysis::AnalysedType;
+use heck::{ToKebabCase, ToSnakeCase, ToUpperCamelCase};
+use indoc::indoc;
+use std::path::PathBuf;
+
+const RUNTIME: &str = indoc! {r##"
+    use serde_json::{Map, Value};
+    use std::fmt::{Display, Formatter};
+
+    #[derive(Debug)]
+    pub enum Error {
+        Http(reqwest::Error),
+        Invocation { status: u16, body: String },
+        Decode(String),
+    }
+
+    impl Display for Error {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            match self {
+                Error::Http(err) => write!(f, "HTTP error: {err}"),
+                Error::Invocation { status, body } => {
+                    write!(f, "Invocation failed with status {status}: {body}")
+                }
+                Error::Decode(err) => write!(f, "Failed to decode value: {err}"),
+            }
+        }
+    }
+
+    impl std::error::Error for Error {}
+
+    impl From<reqwest::Error> for Error {
+        fn from(err: reqwest::Error) -> Self {
+            Error::Http(err)
+        }
+    }
+
+    fn decode_error(expected: &str, value: &Value) -> Error {
+        Error::Decode(format!("expected {expected}, got {value}"))
+    }
+
+    /// Conversion between native types and the JSON representation of WIT values
+    pub trait WitValue: Sized {
+        fn to_json(&self) -> Value;
+        fn from_json(value: &Value) -> Result<Self, Error>;
+    }
+
+    impl WitValue for () {
+        fn to_json(&self) -> Value {
+            Value::Null
+        }
+
+        fn from_json(_value: &Value) -> Result<Self, Error> {
+            Ok(())
+        }
+    }
+
+    impl WitValue for bool {
+        fn to_json(&self) -> Value {
+            Value::Bool(*self)
+        }
+
+        fn from_json(value: &Value) -> Result<Self, Error> {
+            value.as_bool().ok_or_else(|| decode_error("bool", value))
+        }
+    }
+
+    macro_rules! signed_wit_value {
+        ($($t:ty),*) => {$(
+            impl WitValue for $t {
+                fn to_json(&self) -> Value {
+                    Value::from(*self)
+                }
+
+                fn from_json(value: &Value) -> Result<Self, Error> {
+                    value
+                        .as_i64()
+                        .and_then(|value| <$t>::try_from(value).ok())
+                        .ok_or_else(|| decode_error(stringify!($t), value))
+                }
+            }
+        )*};
+    }
+
+    macro_rules! unsigned_wit_value {
+        ($($t:ty),*) => {$(
+            impl WitValue for $t {
+                fn to_json(&self) -> Value {
+                    Value::from(*self)
+                }
+
+                fn from_json(value: &Value) -> Result<Self, Error> {
+                    value
+                        .as_u64()
+                        .and_then(|value| <$t>::try_from(value).ok())
+                        .ok_or_else(|| decode_error(stringify!($t), value))
+                }
+            }
+        )*};
+    }
+
+    signed_wit_value!(i8, i16, i32, i64);
+    unsigned_wit_value!(u8, u16, u32, u64);
+
+    impl WitValue for f32 {
+        fn to_json(&self) -> Value {
+            Value::from(*self)
+        }
+
+        fn from_json(value: &Value) -> Result<Self, Error> {
+            value
+                .as_f64()
+                .map(|value| value as f32)
+                .ok_or_else(|| decode_error("f32", value))
+        }
+    }
+
+    impl WitValue for f64 {
+        fn to_json(&self) -> Value {
+            Value::from(*self)
+        }
+
+        fn from_json(value: &Value) -> Result<Self, Error> {
+            value.as_f64().ok_or_else(|| decode_error("f64", value))
+        }
+    }
+
+    impl WitValue for char {
+        fn to_json(&self) -> Value {
+            Value::String(self.to_string())
+        }
+
+        fn from_json(value: &Value) -> Result<Self, Error> {
+            let mut chars = value.as_str().map(|value| value.chars());
+            match chars.as_mut().map(|chars| (chars.next(), chars.next())) {
+                Some((Some(char), None)) => Ok(char),
+                _ => Err(decode_error("char", value)),
+            }
+        }
+    }
+
+    impl WitValue for String {
+        fn to_json(&self) -> Value {
+            Value::String(self.clone())
+        }
+
+        fn from_json(value: &Value) -> Result<Self, Error> {
+            value
+                .as_str()
+                .map(|value| value.to_string())
+                .ok_or_else(|| decode_error("string", value))
+        }
+    }
+
+    impl<T: WitValue> WitValue for Vec<T> {
+        fn to_json(&self) -> Value {
+            Value::Array(self.iter().map(|item| item.to_json()).collect())
+        }
+
+        fn from_json(value: &Value) -> Result<Self, Error> {
+            value
+                .as_array()
+                .ok_or_else(|| decode_error("list", value))?
+                .iter()
+                .map(T::from_json)
+                .collect()
+        }
+    }
+
+    impl<T: WitValue> WitValue for Option<T> {
+        fn to_json(&self) -> Value {
+            match self {
+                Some(value) => value.to_json(),
+                None => Value::Null,
+            }
+        }
+
+        fn from_json(value: &Value) -> Result<Self, Error> {
+            match value {
+                Value::Null => Ok(None),
+                value => T::from_json(value).map(Some),
+            }
+        }
+    }
+
+    impl<T: WitValue, E: WitValue> WitValue for Result<T, E> {
+        fn to_json(&self) -> Value {
+            let mut map = Map::new();
+            match self {
+                Ok(value) => map.insert("ok".to_string(), value.to_json()),
+                Err(value) => map.insert("err".to_string(), value.to_json()),
+            };
+            Value::Object(map)
+        }
+
+        fn from_json(value: &Value) -> Result<Self, Error> {
+            let (case, value) = variant_case(value)?;
+            match case {
+                "ok" => T::from_json(value).map(Ok),
+                "err" => E::from_json(value).map(Err),
+                _ => Err(decode_error("result", value)),
+            }
+        }
+    }
+
+    macro_rules! tuple_wit_value {
+        ($(($($t:ident $idx:tt),*)),*) => {$(
+            impl<$($t: WitValue),*> WitValue for ($($t,)*) {
+                fn to_json(&self) -> Value {
+                    Value::Array(vec![$(self.$idx.to_json()),*])
+                }
+
+                fn from_json(value: &Value) -> Result<Self, Error> {
+                    let items = value.as_array().ok_or_else(|| decode_error("tuple", value))?;
+                    Ok(($($t::from_json(items.get($idx).unwrap_or(&Value::Null))?,)*))
+                }
+            }
+        )*};
+    }
+
+    tuple_wit_value!(
+        (A 0),
+        (A 0, B 1),
+        (A 0, B 1, C 2),
+        (A 0, B 1, C 2, D 3),
+        (A 0, B 1, C 2, D 3, E 4),
+        (A 0, B 1, C 2, D 3, E 4, F 5),
+        (A 0, B 1, C 2, D 3, E 4, F 5, G 6),
+        (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7)
+    );
+
+    /// Case name and payload of a variant, accepting both `{"case": value}` and `"case"`
+    fn variant_case(value: &Value) -> Result<(&str, &Value), Error> {
+        match value {
+            Value::String(case) => Ok((case.as_str(), &Value::Null)),
+            Value::Object(map) if map.len() == 1 => {
+                let (case, value) = map.iter().next().unwrap();
+                Ok((case.as_str(), value))
+            }
+            value => Err(decode_error("variant", value)),
+        }
+    }
+
+    fn field<T: WitValue>(value: &Value, name: &str) -> Result<T, Error> {
+        let map = value.as_object().ok_or_else(|| decode_error("record", value))?;
+        T::from_json(map.get(name).unwrap_or(&Value::Null))
+    }
+
+    fn param<T: WitValue>(typ: &str, value: &T) -> Value {
+        let mut map = Map::new();
+        map.insert(
+            "typ".to_string(),
+            serde_json::from_str(typ).expect("Invalid type annotation"),
+        );
+        map.insert("value".to_string(), value.to_json());
+        Value::Object(map)
+    }
+
+    /// Connection to a single worker through the worker invocation API
+    #[derive(Clone)]
+    pub struct Invoker {
+        client: reqwest::Client,
+        base_url: String,
+        component_id: String,
+        worker_name: String,
+        token: Option<String>,
+    }
+
+    impl Invoker {
+        /// Creates an invoker for a worker of the component the client was generated for
+        pub fn new(base_url: impl Into<String>, worker_name: impl Into<String>) -> Self {
+            Self {
+                client: reqwest::Client::new(),
+                base_url: base_url.into().trim_end_matches('/').to_string(),
+                component_id: COMPONENT_ID.to_string(),
+                worker_name: worker_name.into(),
+                token: None,
+            }
+        }
+
+        pub fn with_component_id(mut self, component_id: impl Into<String>) -> Self {
+            self.component_id = component_id.into();
+            self
+        }
+
+        pub fn with_token(mut self, token: impl Into<String>) -> Self {
+            self.token = Some(token.into());
+            self
+        }
+
+        pub fn with_client(mut self, client: reqwest::Client) -> Self {
+            self.client = client;
+            self
+        }
+
+        async fn request(
+            &self,
+            endpoint: &str,
+            function_name: &str,
+            params: Vec<Value>,
+        ) -> Result<Value, Error> {
+            let url = format!(
+                "{}/v1/components/{}/workers/{}/{}",
+                self.base_url, self.component_id, self.worker_name, endpoint
+            );
+            let mut body = Map::new();
+            body.insert("params".to_string(), Value::Array(params));
+
+            let mut request = self
+                .client
+                .post(url)
+                .query(&[("function", function_name)])
+                .json(&body);
+            if let Some(token) = &self.token {
+                request = request.bearer_auth(token);
+            }
+
+            let response = request.send().await?;
+            let status = response.status();
+            let body = response.text().await?;
+            if !status.is_success() {
+                return Err(Error::Invocation {
+                    status: status.as_u16(),
+                    body,
+                });
+            }
+            if body.is_empty() {
+                Ok(Value::Null)
+            } else {
+                serde_json::from_str(&body).map_err(|err| Error::Decode(err.to_string()))
+            }
+        }
+
+        async fn invoke_and_await(
+            &self,
+            function_name: &str,
+            params: Vec<Value>,
+        ) -> Result<Value, Error> {
+            let response = self
+                .request("invoke-and-await", function_name, params)
+                .await?;
+            Ok(response["result"]["value"].clone())
+        }
+
+        async fn invoke(&self, function_name: &str, params: Vec<Value>) -> Result<(), Error> {
+            self.request("invoke", function_name, params).await?;
+            Ok(())
+        }
+    }
+"##};
+
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "box", "break", "const", "continue", "crate", "do", "dyn", "else",
+    "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in", "let", "loop", "macro",
+    "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return", "self", "Self",
+    "static", "struct", "super", "trait", "true", "try", "type", "typeof", "unsafe", "unsized",
+    "use", "virtual", "where", "while", "yield",
+];
+
+pub fn generate(sdk: &ClientSdk) -> Vec<ClientSdkFile> {
+    let crate_name = format!("{}-client", sdk.component_name.to_kebab_case());
+
+    let cargo_toml = format!(
+        indoc! {r#"
+            [package]
+            name = "{}"
+            version = "0.0.1"
+            edition = "2021"
+
+            [dependencies]
+            reqwest = {{ version = "0.12", default-features = false, features = ["json", "rustls-tls"] }}
+            serde_json = "1"
+        "#},
+        crate_name
+    );
+
+    let mut out = String::new();
+    out.push_str(&format!(
+        "//! Generated by golem-cli for component {} ({}), do not edit manually\n\n",
+        sdk.component_name, sdk.component_id
+    ));
+    out.push_str("#![allow(clippy::all, dead_code)]\n\n");
+    out.push_str(&format!(
+        "pub const COMPONENT_ID: &str = \"{}\";\n\n",
+        sdk.component_id
+    ));
+    out.push_str(RUNTIME);
+
+    for named_type in &sdk.types {
+        out.push('\n');
+        out.push_str(&named_type_def(sdk, named_type));
+    }
+
+    for interface in &sdk.interfaces {
+        out.push('\n');
+        if let Some(name) = &interface.name {
+            out.push_str(&format!(
+                "/// Client for the exported interface `{}`\n",
+                name
+            ));
+        } else {
+            out.push_str("/// Client for the exported top level functions\n");
+        }
+        out.push_str(&format!(
+            "#[derive(Clone)]\npub struct {} {{\n    invoker: Invoker,\n}}\n\n",
+            interface.client_name
+        ));
+        out.push_str(&format!(
+            "impl {} {{\n    pub fn new(invoker: Invoker) -> Self {{\n        Self {{ invoker }}\n    }}\n",
+            interface.client_name
+        ));
+        for function in &interface.functions {
+            out.push('\n');
+            out.push_str(&function_defs(sdk, function));
+        }
+        out.push_str("}\n");
+    }
+
+    vec![
+        ClientSdkFile {
+            path: PathBuf::from("Cargo.toml"),
+            content: cargo_toml,
+        },
+        ClientSdkFile {
+            path: PathBuf::from("src").join("lib.rs"),
+            content: out,
+        },
+    ]
+}
+
+fn named_type_def(sdk: &ClientSdk, named_type: &NamedType) -> String {
+    let name = &named_type.name;
+    let mut out = String::new();
+    match &named_type.typ {
+        AnalysedType::Record(record) => {
+            out.push_str(&format!(
+                "#[derive(Debug, Clone, PartialEq)]\npub struct {} {{\n",
+                name
+            ));
+            for field in &record.fields {
+                out.push_str(&format!(
+                    "    pub {}: {},\n",
+                    ident(&field.name),
+                    rust_type(sdk, &field.typ)
+                ));
+            }
+            out.push_str("}\n\n");
+
+            out.push_str(&format!(
+                "impl WitValue for {} {{\n    fn to_json(&self) -> Value {{\n        let mut map = Map::new();\n",
+                name
+            ));
+            for field in &record.fields {
+                out.push_str(&format!(
+                    "        map.insert(\"{}\".to_string(), self.{}.to_json());\n",
+                    field.name,
+                    ident(&field.name)
+                ));
+            }
+            out.push_str("        Value::Object(map)\n    }\n\n");
+            out.push_str(
+                "    fn from_json(value: &Value) -> Result<Self, Error> {\n        Ok(Self {\n",
+            );
+            for field in &record.fields {
+                out.push_str(&format!(
+                    "            {}: field(value, \"{}\")?,\n",
+                    ident(&field.name),
+                    field.name
+                ));
+            }
+            out.push_str("        })\n    }\n}\n");
+        }
+        AnalysedType::Variant(variant) => {
+            out.push_str(&format!(
+                "#[derive(Debug, Clone, PartialEq)]\npub enum {} {{\n",
+                name
+            ));
+            for case in &variant.cases {
+                match &case.typ {
+                    Some(typ) => out.push_str(&format!(
+                        "    {}({}),\n",
+                        case_ident(&case.name),
+                        rust_type(sdk, typ)
+                    )),
+                    None => out.push_str(&format!("    {},\n", case_ident(&case.name))),
+                }
+            }
+            out.push_str("}\n\n");
+
+            out.push_str(&format!(
+                "impl WitValue for {} {{\n    fn to_json(&self) -> Value {{\n        let mut map = Map::new();\n        match self {{\n",
+                name
+            ));
+            for case in &variant.cases {
+                match &case.typ {
+                    Some(_) => out.push_str(&format!(
+                        "            Self::{}(value) => map.insert(\"{}\".to_string(), value.to_json()),\n",
+                        case_ident(&case.name),
+                        case.name
+                    )),
+                    None => out.push_str(&format!(
+                        "            Self::{} => map.insert(\"{}\".to_string(), Value::Null),\n",
+                        case_ident(&case.name),
+                        case.name
+                    )),
+                }
+            }
+            out.push_str("        };\n        Value::Object(map)\n    }\n\n");
+            out.push_str(
+                "    fn from_json(value: &Value) -> Result<Self, Error> {\n        let (case, value) = variant_case(value)?;\n        match case {\n",
+            );
+            for case in &variant.cases {
+                match &case.typ {
+                    Some(_) => out.push_str(&format!(
+                        "            \"{}\" => WitValue::from_json(value).map(Self::{}),\n",
+                        case.name,
+                        case_ident(&case.name)
+                    )),
+                    None => out.push_str(&format!(
+                        "            \"{}\" => Ok(Self::{}),\n",
+                        case.name,
+                        case_ident(&case.name)
+                    )),
+                }
+            }
+            out.push_str(&format!(
+                "            _ => Err(decode_error(\"{}\", value)),\n        }}\n    }}\n}}\n",
+                name
+            ));
+        }
+        AnalysedType::Enum(enum_type) => {
+            out.push_str(&format!(
+                "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]\npub enum {} {{\n",
+                name
+            ));
+            for case in &enum_type.cases {
+                out.push_str(&format!("    {},\n", case_ident(case)));
+            }
+            out.push_str("}\n\n");
+
+            out.push_str(&format!(
+                "impl WitValue for {} {{\n    fn to_json(&self) -> Value {{\n        match self {{\n",
+                name
+            ));
+            for case in &enum_type.cases {
+                out.push_str(&format!(
+                    "            Self::{} => Value::String(\"{}\".to_string()),\n",
+                    case_ident(case),
+                    case
+                ));
+            }
+            out.push_str("        }\n    }\n\n");
+            out.push_str(
+                "    fn from_json(value: &Value) -> Result<Self, Error> {\n        match value.as_str() {\n",
+            );
+            for case in &enum_type.cases {
+                out.push_str(&format!(
+                    "            Some(\"{}\") => Ok(Self::{}),\n",
+                    case,
+                    case_ident(case)
+                ));
+            }
+            out.push_str(&format!(
+                "            _ => Err(decode_error(\"{}\", value)),\n        }}\n    }}\n}}\n",
+                name
+            ));
+        }
+        AnalysedType::Flags(flags) => {
+            out.push_str(&format!(
+                "#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]\npub struct {} {{\n",
+                name
+            ));
+            for flag in &flags.names {
+                out.push_str(&format!("    pub {}: bool,\n", ident(flag)));
+            }
+            out.push_str("}\n\n");
+
+            out.push_str(&format!(
+                "impl WitValue for {} {{\n    fn to_json(&self) -> Value {{\n        let mut flags = Vec::new();\n",
+                name
+            ));
+            for flag in &flags.names {
+                out.push_str(&format!(
+                    "        if self.{} {{\n            flags.push(Value::String(\"{}\".to_string()));\n        }}\n",
+                    ident(flag),
+                    flag
+                ));
+            }
+            out.push_str("        Value::Array(flags)\n    }\n\n");
+            out.push_str(
+                "    fn from_json(value: &Value) -> Result<Self, Error> {\n        let flags = Vec::<String>::from_json(value)?;\n        Ok(Self {\n",
+            );
+            for flag in &flags.names {
+                out.push_str(&format!(
+                    "            {}: flags.iter().any(|flag| flag == \"{}\"),\n",
+                    ident(flag),
+                    flag
+                ));
+            }
+            out.push_str("        })\n    }\n}\n");
+        }
+        _ => {}
+    }
+    out
+}
+
+fn function_defs(sdk: &ClientSdk, function: &ClientFunction) -> String {
+    let method_name = ident(&function.name);
+    let params = function
+        .parameters
+        .iter()
+        .map(|(name, typ)| format!(", {}: &{}", ident(name), rust_type(sdk, typ)))
+        .collect::<String>();
+    let encoded_params = function
+        .parameters
+        .iter()
+        .map(|(name, typ)| format!("param(r##\"{}\"##, {})", type_json(typ), ident(name)))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let result_type = match function.results.as_slice() {
+        [] => "()".to_string(),
+        [typ] => rust_type(sdk, typ),
+        results => format!(
+            "({},)",
+            results
+                .iter()
+                .map(|typ| rust_type(sdk, typ))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    };
+    let result_expr = match function.results.len() {
+        0 => "Ok(())",
+        1 => "WitValue::from_json(&result[0])",
+        _ => "WitValue::from_json(&result)",
+    };
+
+    let mut out = String::new();
+    out.push_str(&format!(
+        "    /// Invokes `{}` and awaits its result\n",
+        function.qualified_name
+    ));
+    out.push_str(&format!(
+        "    pub async fn {}(&self{}) -> Result<{}, Error> {{\n",
+        method_name, params, result_type
+    ));
+    out.push_str(&format!(
+        "        let {}result = self\n            .invoker\n            .invoke_and_await(\"{}\", vec![{}])\n            .await?;\n",
+        if function.results.is_empty() { "_" } else { "" },
+        function.qualified_name,
+        encoded_params
+    ));
+    out.push_str(&format!("        {}\n    }}\n\n", result_expr));
+
+    out.push_str(&format!(
+        "    /// Enqueues an invocation of `{}` without awaiting its result\n",
+        function.qualified_name
+    ));
+    out.push_str(&format!(
+        "    pub async fn enqueue_{}(&self{}) -> Result<(), Error> {{\n",
+        function.name.to_snake_case(),
+        params
+    ));
+    out.push_str(&format!(
+        "        self.invoker\n            .invoke(\"{}\", vec![{}])\n            .await\n    }}\n",
+        function.qualified_name, encoded_params
+    ));
+    out
+}
+
+fn rust_type(sdk: &ClientSdk, typ: &AnalysedType) -> String {
+    if let Some(name) = sdk.type_name(typ) {
+        return name.to_string();
+    }
+    let unit_or = |typ: &Option<Box<AnalysedType>>| match typ {
+        Some(typ) => rust_type(sdk, typ),
+        None => "()".to_string(),
+    };
+    match typ {
+        AnalysedType::Bool(_) => "bool".to_string(),
+        AnalysedType::S8(_) => "i8".to_string(),
+        AnalysedType::U8(_) => "u8".to_string(),
+        AnalysedType::S16(_) => "i16".to_string(),
+        AnalysedType::U16(_) => "u16".to_string(),
+        AnalysedType::S32(_) => "i32".to_string(),
+        AnalysedType::U32(_) => "u32".to_string(),
+        AnalysedType::S64(_) => "i64".to_string(),
+        AnalysedType::U64(_) => "u64".to_string(),
+        AnalysedType::F32(_) => "f32".to_string(),
+        AnalysedType::F64(_) => "f64".to_string(),
+        AnalysedType::Chr(_) => "char".to_string(),
+        AnalysedType::Str(_) | AnalysedType::Handle(_) => "String".to_string(),
+        AnalysedType::List(list) => format!("Vec<{}>", rust_type(sdk, &list.inner)),
+        AnalysedType::Tuple(tuple) => format!(
+            "({},)",
+            tuple
+                .items
+                .iter()
+                .map(|item| rust_type(sdk, item))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        AnalysedType::Option(option) => format!("Option<{}>", rust_type(sdk, &option.inner)),
+        AnalysedType::Result(result) => {
+            format!("Result<{}, {}>", unit_or(&result.ok), unit_or(&result.err))
+        }
+        AnalysedType::Record(_)
+        | AnalysedType::Variant(_)
+        | AnalysedType::Enum(_)
+        | AnalysedType::Flags(_) => "()".to_string(),
+    }
+}
+
+fn ident(name: &str) -> String {
+    let ident = name.to_snake_case();
+    if KEYWORDS.contains(&ident.as_str()) {
+        format!("{}_", ident)
+    } else {
+        ident
+    }
+}
+
+fn case_ident(name: &str) -> String {
+    let ident = name.to_upper_camel_case();
+    if KEYWORDS.contains(&ident.as_str()) {
+        format!("{}_", ident)
+    } else {
+        ident
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::client_sdk::rust::{generate, RUNTIME};
+    use crate::client_sdk::test::{example_exports, example_item};
+    use crate::client_sdk::{type_json, ClientSdk};
+    use assert2::assert;
+    use golem_wasm_ast::analysis::analysed_type::{
+        case, r#enum, result_err, s64, unit_case, variant,
+    };
+    use golem_wasm_ast::analysis::{
+        AnalysedExport, AnalysedFunction, AnalysedFunctionParameter, AnalysedFunctionResult,
+        AnalysedInstance,
+    };
+    use indoc::indoc;
+    use test_r::test;
+    use uuid::Uuid;
+
+    #[test]
+    fn generates_typed_client_crate() {
+        let exports = vec![AnalysedExport::Instance(AnalysedInstance {
+            name: "golem:it/counter@1.0.0".to_string(),
+            functions: vec![AnalysedFunction {
+                name: "update".to_string(),
+                parameters: vec![AnalysedFunctionParameter {
+                    name: "change".to_string(),
+                    typ: variant(vec![case("add", s64()), unit_case("reset")]),
+                }],
+                results: vec![AnalysedFunctionResult {
+                    name: None,
+                    typ: result_err(r#enum(&["overflow", "type"])),
+                }],
+            }],
+        })];
+        let sdk = ClientSdk::new("example:counter", Uuid::nil(), &exports);
+
+        let files = generate(&sdk);
+        assert!(files[0].path.to_string_lossy() == "Cargo.toml");
+        assert!(files[0]
+            .content
+            .contains("name = \"example-counter-client\""));
+
+        let content = &files[1].content;
+        assert!(content.contains("pub enum UpdateChange {\n    Add(i64),\n    Reset,\n}"));
+        assert!(content.contains("pub enum UpdateResultError {\n    Overflow,\n    Type,\n}"));
+        assert!(content.contains("pub struct CounterClient {"));
+        assert!(content.contains(
+            "pub async fn update(&self, change: &UpdateChange) -> Result<Result<(), UpdateResultError>, Error> {"
+        ));
+        assert!(content.contains(
+            "pub async fn enqueue_update(&self, change: &UpdateChange) -> Result<(), Error> {"
+        ));
+        assert!(content.contains(".invoke_and_await(\"golem:it/counter@1.0.0.{update}\""));
+    }
+
+    #[test]
+    fn generates_record_variant_resource_interface() {
+        let sdk = ClientSdk::new("test", Uuid::nil(), &example_exports());
+
+        let files = generate(&sdk);
+        assert!(files.len() == 2);
+        assert!(files[1].path == std::path::Path::new("src").join("lib.rs"));
+
+        let (header, generated) = files[1].content.split_once(RUNTIME).unwrap();
+        assert!(
+            header
+                == "//! Generated by golem-cli for component test (00000000-0000-0000-0000-000000000000), do not edit manually\n\n\
+                    #![allow(clippy::all, dead_code)]\n\n\
+                    pub const COMPONENT_ID: &str = \"00000000-0000-0000-0000-000000000000\";\n\n"
+        );
+
+        let expected = indoc! {r###"
+            #[derive(Debug, Clone, PartialEq)]
+            pub struct AddItemItem {
+                pub name: String,
+                pub count: u32,
+                pub status: AddItemItemStatus,
+            }
+
+            impl WitValue for AddItemItem {
+                fn to_json(&self) -> Value {
+                    let mut map = Map::new();
+                    map.insert("name".to_string(), self.name.to_json());
+                    map.insert("count".to_string(), self.count.to_json());
+                    map.insert("status".to_string(), self.status.to_json());
+                    Value::Object(map)
+                }
+
+                fn from_json(value: &Value) -> Result<Self, Error> {
+                    Ok(Self {
+                        name: field(value, "name")?,
+                        count: field(value, "count")?,
+                        status: field(value, "status")?,
+                    })
+                }
+            }
+
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+            pub enum AddItemItemStatus {
+                Open,
+                Closed,
+            }
+
+            impl WitValue for AddItemItemStatus {
+                fn to_json(&self) -> Value {
+                    match self {
+                        Self::Open => Value::String("open".to_string()),
+                        Self::Closed => Value::String("closed".to_string()),
+                    }
+                }
+
+                fn from_json(value: &Value) -> Result<Self, Error> {
+                    match value.as_str() {
+                        Some("open") => Ok(Self::Open),
+                        Some("closed") => Ok(Self::Closed),
+                        _ => Err(decode_error("AddItemItemStatus", value)),
+                    }
+                }
+            }
+
+            #[derive(Debug, Clone, PartialEq)]
+            pub enum AddItemResult {
+                Added(u32),
+                Rejected,
+            }
+
+            impl WitValue for AddItemResult {
+                fn to_json(&self) -> Value {
+                    let mut map = Map::new();
+                    match self {
+                        Self::Added(value) => map.insert("added".to_string(), value.to_json()),
+                        Self::Rejected => map.insert("rejected".to_string(), Value::Null),
+                    };
+                    Value::Object(map)
+                }
+
+                fn from_json(value: &Value) -> Result<Self, Error> {
+                    let (case, value) = variant_case(value)?;
+                    match case {
+                        "added" => WitValue::from_json(value).map(Self::Added),
+                        "rejected" => Ok(Self::Rejected),
+                        _ => Err(decode_error("AddItemResult", value)),
+                    }
+                }
+            }
+
+            /// Client for the exported interface `golem:it/api`
+            #[derive(Clone)]
+            pub struct ApiClient {
+                invoker: Invoker,
+            }
+
+            impl ApiClient {
+                pub fn new(invoker: Invoker) -> Self {
+                    Self { invoker }
+                }
+
+                /// Invokes `golem:it/api.{add-item}` and awaits its result
+                pub async fn add_item(&self, item: &AddItemItem) -> Result<AddItemResult, Error> {
+                    let result = self
+                        .invoker
+                        .invoke_and_await("golem:it/api.{add-item}", vec![param(r##"ITEM_TYPE"##, item)])
+                        .await?;
+                    WitValue::from_json(&result[0])
+                }
+
+                /// Enqueues an invocation of `golem:it/api.{add-item}` without awaiting its result
+                pub async fn enqueue_add_item(&self, item: &AddItemItem) -> Result<(), Error> {
+                    self.invoker
+                        .invoke("golem:it/api.{add-item}", vec![param(r##"ITEM_TYPE"##, item)])
+                        .await
+                }
+
+                /// Invokes `golem:it/api.{get-items}` and awaits its result
+                pub async fn get_items(&self) -> Result<Vec<Option<AddItemItem>>, Error> {
+                    let result = self
+                        .invoker
+                        .invoke_and_await("golem:it/api.{get-items}", vec![])
+                        .await?;
+                    WitValue::from_json(&result[0])
+                }
+
+                /// Enqueues an invocation of `golem:it/api.{get-items}` without awaiting its result
+                pub async fn enqueue_get_items(&self) -> Result<(), Error> {
+                    self.invoker
+                        .invoke("golem:it/api.{get-items}", vec![])
+                        .await
+                }
+            }
+        "###}
+        .replace("ITEM_TYPE", &type_json(&example_item()));
+
+        // Resource functions are skipped, all named types are emitted before the clients
+        assert!(generated == format!("\n{}", expected));
+        assert!(sdk.skipped_functions == vec!["golem:it/api.{[constructor]counter}"]);
+    }
+}
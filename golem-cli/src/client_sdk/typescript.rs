@@ -0,0 +1,683 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::client_sdk::{type_json, ClientFunction, ClientSdk, ClientSdkFile, NamedType};
+use crate::wasm_rpc_stubgen::naming::typescript::RESERVED_WORDS;
+use golem_wasm_ast::analysis::AnalysedType;
+use heck::{ToLowerCamelCase, ToUpperCamelCase};
+use indoc::indoc;
+use std::path::PathBuf;
+
+const RUNTIME: &str = indoc! {r#"
+    export type Result<T, E> = { tag: "ok"; val: T } | { tag: "err"; val: E };
+
+    export interface ClientOptions {
+      /** Base URL of the worker service, e.g. http://localhost:9881 */
+      baseUrl: string;
+      /** Name of the target worker */
+      workerName: string;
+      /** Defaults to the component the client was generated for */
+      componentId?: string;
+      /** Bearer token for authenticating the requests */
+      token?: string;
+      /** Custom fetch implementation */
+      fetch?: typeof fetch;
+    }
+
+    export class InvocationError extends Error {
+      constructor(
+        message: string,
+        readonly status: number,
+        readonly body: string,
+      ) {
+        super(message);
+      }
+    }
+
+    type Encoder = (value: any) => unknown;
+    type Decoder = (value: any) => any;
+
+    const identity = (value: any) => value;
+    const encodeUnit = (_value: any) => null;
+    const decodeUnit = (_value: any) => undefined;
+    const listEncoder = (item: Encoder) => (value: any[]) => value.map(item);
+    const listDecoder = (item: Decoder) => (value: any[]) => value.map(item);
+    const tupleEncoder = (items: Encoder[]) => (value: any[]) => items.map((item, idx) => item(value[idx]));
+    const tupleDecoder = (items: Decoder[]) => (value: any[]) => items.map((item, idx) => item(value[idx]));
+    const optionEncoder = (inner: Encoder) => (value: any) => (value === undefined ? null : inner(value));
+    const optionDecoder = (inner: Decoder) => (value: any) =>
+      value === null || value === undefined ? undefined : inner(value);
+    const resultEncoder = (ok: Encoder, err: Encoder) => (value: Result<any, any>) =>
+      value.tag === "ok" ? { ok: ok(value.val) } : { err: err(value.val) };
+    const resultDecoder =
+      (ok: Decoder, err: Decoder) =>
+      (value: any): Result<any, any> =>
+        "ok" in value ? { tag: "ok", val: ok(value.ok) } : { tag: "err", val: err(value.err) };
+
+    function variantCase(value: any): [string, any] {
+      if (typeof value === "string") {
+        return [value, null];
+      }
+      const [[tag, val]] = Object.entries(value);
+      return [tag, val];
+    }
+
+    export class Invoker {
+      constructor(protected readonly options: ClientOptions) {}
+
+      private async request(
+        endpoint: "invoke" | "invoke-and-await",
+        functionName: string,
+        params: unknown[],
+      ): Promise<any> {
+        const baseUrl = this.options.baseUrl.replace(/\/+$/, "");
+        const componentId = encodeURIComponent(this.options.componentId ?? COMPONENT_ID);
+        const workerName = encodeURIComponent(this.options.workerName);
+        const url = `${baseUrl}/v1/components/${componentId}/workers/${workerName}/${endpoint}?function=${encodeURIComponent(functionName)}`;
+        const headers: Record<string, string> = { "Content-Type": "application/json" };
+        if (this.options.token !== undefined) {
+          headers["Authorization"] = `Bearer ${this.options.token}`;
+        }
+        const response = await (this.options.fetch ?? fetch)(url, {
+          method: "POST",
+          headers,
+          body: JSON.stringify({ params }),
+        });
+        const body = await response.text();
+        if (!response.ok) {
+          throw new InvocationError(
+            `Invocation of ${functionName} failed with status ${response.status}: ${body}`,
+            response.status,
+            body,
+          );
+        }
+        return body.length > 0 ? JSON.parse(body) : undefined;
+      }
+
+      protected async invokeAndAwait(functionName: string, params: unknown[]): Promise<any[]> {
+        const response = await this.request("invoke-and-await", functionName, params);
+        return response.result.value;
+      }
+
+      protected async invoke(functionName: string, params: unknown[]): Promise<void> {
+        await this.request("invoke", functionName, params);
+      }
+    }
+"#};
+
+pub fn generate(sdk: &ClientSdk) -> Vec<ClientSdkFile> {
+    let mut out = String::new();
+    out.push_str(&format!(
+        "// Generated by golem-cli for component {} ({}), do not edit manually\n\n",
+        sdk.component_name, sdk.component_id
+    ));
+    out.push_str(&format!(
+        "export const COMPONENT_ID = \"{}\";\n\n",
+        sdk.component_id
+    ));
+    out.push_str(RUNTIME);
+
+    for named_type in &sdk.types {
+        out.push('\n');
+        out.push_str(&named_type_def(sdk, named_type));
+    }
+
+    for interface in &sdk.interfaces {
+        out.push('\n');
+        if let Some(name) = &interface.name {
+            out.push_str(&format!(
+                "/** Client for the exported interface `{}` */\n",
+                name
+            ));
+        } else {
+            out.push_str("/** Client for the exported top level functions */\n");
+        }
+        out.push_str(&format!(
+            "export class {} extends Invoker {{\n",
+            interface.client_name
+        ));
+        for (idx, function) in interface.functions.iter().enumerate() {
+            if idx > 0 {
+                out.push('\n');
+            }
+            out.push_str(&function_defs(sdk, function));
+        }
+        out.push_str("}\n");
+    }
+
+    vec![ClientSdkFile {
+        path: PathBuf::from("client.ts"),
+        content: out,
+    }]
+}
+
+fn named_type_def(sdk: &ClientSdk, named_type: &NamedType) -> String {
+    let name = &named_type.name;
+    let mut out = String::new();
+    match &named_type.typ {
+        AnalysedType::Record(record) => {
+            out.push_str(&format!("export interface {} {{\n", name));
+            for field in &record.fields {
+                out.push_str(&format!(
+                    "  {}: {};\n",
+                    ident(&field.name),
+                    ts_type(sdk, &field.typ)
+                ));
+            }
+            out.push_str("}\n\n");
+
+            out.push_str(&format!(
+                "function encode{}(value: {}): unknown {{\n  return {{\n",
+                name, name
+            ));
+            for field in &record.fields {
+                out.push_str(&format!(
+                    "    \"{}\": {}(value.{}),\n",
+                    field.name,
+                    encoder(sdk, &field.typ),
+                    ident(&field.name)
+                ));
+            }
+            out.push_str("  };\n}\n\n");
+
+            out.push_str(&format!(
+                "function decode{}(value: any): {} {{\n  return {{\n",
+                name, name
+            ));
+            for field in &record.fields {
+                out.push_str(&format!(
+                    "    {}: {}(value[\"{}\"]),\n",
+                    ident(&field.name),
+                    decoder(sdk, &field.typ),
+                    field.name
+                ));
+            }
+            out.push_str("  };\n}\n");
+        }
+        AnalysedType::Variant(variant) => {
+            out.push_str(&format!("export type {} =", name));
+            if variant.cases.is_empty() {
+                out.push_str(" never");
+            }
+            for case in &variant.cases {
+                match &case.typ {
+                    Some(typ) => out.push_str(&format!(
+                        "\n  | {{ tag: \"{}\"; val: {} }}",
+                        case.name,
+                        ts_type(sdk, typ)
+                    )),
+                    None => out.push_str(&format!("\n  | {{ tag: \"{}\" }}", case.name)),
+                }
+            }
+            out.push_str(";\n\n");
+
+            out.push_str(&format!(
+                "function encode{}(value: {}): unknown {{\n  switch (value.tag) {{\n",
+                name, name
+            ));
+            for case in &variant.cases {
+                match &case.typ {
+                    Some(typ) => out.push_str(&format!(
+                        "    case \"{}\":\n      return {{ \"{}\": {}(value.val) }};\n",
+                        case.name,
+                        case.name,
+                        encoder(sdk, typ)
+                    )),
+                    None => out.push_str(&format!(
+                        "    case \"{}\":\n      return {{ \"{}\": null }};\n",
+                        case.name, case.name
+                    )),
+                }
+            }
+            out.push_str("  }\n}\n\n");
+
+            out.push_str(&format!(
+                "function decode{}(value: any): {} {{\n  const [tag, val] = variantCase(value);\n  switch (tag) {{\n",
+                name, name
+            ));
+            for case in &variant.cases {
+                match &case.typ {
+                    Some(typ) => out.push_str(&format!(
+                        "    case \"{}\":\n      return {{ tag: \"{}\", val: {}(val) }};\n",
+                        case.name,
+                        case.name,
+                        decoder(sdk, typ)
+                    )),
+                    None => out.push_str(&format!(
+                        "    case \"{}\":\n      return {{ tag: \"{}\" }};\n",
+                        case.name, case.name
+                    )),
+                }
+            }
+            out.push_str(&format!(
+                "    default:\n      throw new Error(`Unknown case of {}: ${{tag}}`);\n  }}\n}}\n",
+                name
+            ));
+        }
+        AnalysedType::Enum(enum_type) => {
+            out.push_str(&format!(
+                "export type {} = {};\n\n",
+                name,
+                if enum_type.cases.is_empty() {
+                    "never".to_string()
+                } else {
+                    enum_type
+                        .cases
+                        .iter()
+                        .map(|case| format!("\"{}\"", case))
+                        .collect::<Vec<_>>()
+                        .join(" | ")
+                }
+            ));
+            out.push_str(&format!(
+                "function encode{}(value: {}): unknown {{\n  return value;\n}}\n\n",
+                name, name
+            ));
+            out.push_str(&format!(
+                "function decode{}(value: any): {} {{\n  return value as {};\n}}\n",
+                name, name, name
+            ));
+        }
+        AnalysedType::Flags(flags) => {
+            out.push_str(&format!("export interface {} {{\n", name));
+            for flag in &flags.names {
+                out.push_str(&format!("  {}: boolean;\n", ident(flag)));
+            }
+            out.push_str("}\n\n");
+
+            out.push_str(&format!(
+                "function encode{}(value: {}): unknown {{\n  const flags: string[] = [];\n",
+                name, name
+            ));
+            for flag in &flags.names {
+                out.push_str(&format!(
+                    "  if (value.{}) {{\n    flags.push(\"{}\");\n  }}\n",
+                    ident(flag),
+                    flag
+                ));
+            }
+            out.push_str("  return flags;\n}\n\n");
+
+            out.push_str(&format!(
+                "function decode{}(value: any): {} {{\n  const flags = value as string[];\n  return {{\n",
+                name, name
+            ));
+            for flag in &flags.names {
+                out.push_str(&format!(
+                    "    {}: flags.includes(\"{}\"),\n",
+                    ident(flag),
+                    flag
+                ));
+            }
+            out.push_str("  };\n}\n");
+        }
+        _ => {}
+    }
+    out
+}
+
+fn function_defs(sdk: &ClientSdk, function: &ClientFunction) -> String {
+    let method_name = ident(&function.name);
+    let params = function
+        .parameters
+        .iter()
+        .map(|(name, typ)| format!("{}: {}", ident(name), ts_type(sdk, typ)))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let encoded_params = function
+        .parameters
+        .iter()
+        .map(|(name, typ)| {
+            format!(
+                "{{ typ: {}, value: {}({}) }}",
+                type_json(typ),
+                encoder(sdk, typ),
+                ident(name)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let (result_type, result_expr) = match function.results.as_slice() {
+        [] => ("void".to_string(), None),
+        [typ] => (
+            ts_type(sdk, typ),
+            Some(format!("{}(result[0])", decoder(sdk, typ))),
+        ),
+        results => (
+            format!(
+                "[{}]",
+                results
+                    .iter()
+                    .map(|typ| ts_type(sdk, typ))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Some(format!(
+                "tupleDecoder([{}])(result) as [{}]",
+                results
+                    .iter()
+                    .map(|typ| decoder(sdk, typ))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                results
+                    .iter()
+                    .map(|typ| ts_type(sdk, typ))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )),
+        ),
+    };
+
+    let mut out = String::new();
+    out.push_str(&format!(
+        "  /** Invokes `{}` and awaits its result */\n",
+        function.qualified_name
+    ));
+    out.push_str(&format!(
+        "  async {}({}): Promise<{}> {{\n",
+        method_name, params, result_type
+    ));
+    match result_expr {
+        Some(result_expr) => {
+            out.push_str(&format!(
+                "    const result = await this.invokeAndAwait(\"{}\", [{}]);\n",
+                function.qualified_name, encoded_params
+            ));
+            out.push_str(&format!("    return {};\n", result_expr));
+        }
+        None => out.push_str(&format!(
+            "    await this.invokeAndAwait(\"{}\", [{}]);\n",
+            function.qualified_name, encoded_params
+        )),
+    }
+    out.push_str("  }\n\n");
+
+    out.push_str(&format!(
+        "  /** Enqueues an invocation of `{}` without awaiting its result */\n",
+        function.qualified_name
+    ));
+    out.push_str(&format!(
+        "  async enqueue{}({}): Promise<void> {{\n",
+        function.name.to_upper_camel_case(),
+        params
+    ));
+    out.push_str(&format!(
+        "    await this.invoke(\"{}\", [{}]);\n  }}\n",
+        function.qualified_name, encoded_params
+    ));
+    out
+}
+
+fn ts_type(sdk: &ClientSdk, typ: &AnalysedType) -> String {
+    if let Some(name) = sdk.type_name(typ) {
+        return name.to_string();
+    }
+    let unit_or = |typ: &Option<Box<AnalysedType>>| match typ {
+        Some(typ) => ts_type(sdk, typ),
+        None => "undefined".to_string(),
+    };
+    match typ {
+        AnalysedType::Bool(_) => "boolean".to_string(),
+        AnalysedType::S8(_)
+        | AnalysedType::U8(_)
+        | AnalysedType::S16(_)
+        | AnalysedType::U16(_)
+        | AnalysedType::S32(_)
+        | AnalysedType::U32(_)
+        | AnalysedType::S64(_)
+        | AnalysedType::U64(_)
+        | AnalysedType::F32(_)
+        | AnalysedType::F64(_) => "number".to_string(),
+        AnalysedType::Chr(_) | AnalysedType::Str(_) | AnalysedType::Handle(_) => {
+            "string".to_string()
+        }
+        AnalysedType::List(list) => format!("Array<{}>", ts_type(sdk, &list.inner)),
+        AnalysedType::Tuple(tuple) => format!(
+            "[{}]",
+            tuple
+                .items
+                .iter()
+                .map(|item| ts_type(sdk, item))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        AnalysedType::Option(option) => format!("{} | undefined", ts_type(sdk, &option.inner)),
+        AnalysedType::Result(result) => {
+            format!("Result<{}, {}>", unit_or(&result.ok), unit_or(&result.err))
+        }
+        AnalysedType::Record(_)
+        | AnalysedType::Variant(_)
+        | AnalysedType::Enum(_)
+        | AnalysedType::Flags(_) => "never".to_string(),
+    }
+}
+
+fn encoder(sdk: &ClientSdk, typ: &AnalysedType) -> String {
+    if let Some(name) = sdk.type_name(typ) {
+        return format!("encode{}", name);
+    }
+    let unit_or = |typ: &Option<Box<AnalysedType>>| match typ {
+        Some(typ) => encoder(sdk, typ),
+        None => "encodeUnit".to_string(),
+    };
+    match typ {
+        AnalysedType::List(list) => format!("listEncoder({})", encoder(sdk, &list.inner)),
+        AnalysedType::Tuple(tuple) => format!(
+            "tupleEncoder([{}])",
+            tuple
+                .items
+                .iter()
+                .map(|item| encoder(sdk, item))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        AnalysedType::Option(option) => format!("optionEncoder({})", encoder(sdk, &option.inner)),
+        AnalysedType::Result(result) => format!(
+            "resultEncoder({}, {})",
+            unit_or(&result.ok),
+            unit_or(&result.err)
+        ),
+        _ => "identity".to_string(),
+    }
+}
+
+fn decoder(sdk: &ClientSdk, typ: &AnalysedType) -> String {
+    if let Some(name) = sdk.type_name(typ) {
+        return format!("decode{}", name);
+    }
+    let unit_or = |typ: &Option<Box<AnalysedType>>| match typ {
+        Some(typ) => decoder(sdk, typ),
+        None => "decodeUnit".to_string(),
+    };
+    match typ {
+        AnalysedType::List(list) => format!("listDecoder({})", decoder(sdk, &list.inner)),
+        AnalysedType::Tuple(tuple) => format!(
+            "tupleDecoder([{}])",
+            tuple
+                .items
+                .iter()
+                .map(|item| decoder(sdk, item))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        AnalysedType::Option(option) => format!("optionDecoder({})", decoder(sdk, &option.inner)),
+        AnalysedType::Result(result) => format!(
+            "resultDecoder({}, {})",
+            unit_or(&result.ok),
+            unit_or(&result.err)
+        ),
+        _ => "identity".to_string(),
+    }
+}
+
+fn ident(name: &str) -> String {
+    let ident = name.to_lower_camel_case();
+    if RESERVED_WORDS.contains(&ident.as_str()) {
+        format!("{}_", ident)
+    } else {
+        ident
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::client_sdk::test::{example_exports, example_item};
+    use crate::client_sdk::typescript::{generate, RUNTIME};
+    use crate::client_sdk::{type_json, ClientSdk};
+    use assert2::assert;
+    use golem_wasm_ast::analysis::analysed_type::{field, flags, option, record, str};
+    use golem_wasm_ast::analysis::{
+        AnalysedExport, AnalysedFunction, AnalysedFunctionParameter, AnalysedFunctionResult,
+    };
+    use indoc::indoc;
+    use test_r::test;
+    use uuid::Uuid;
+
+    #[test]
+    fn generates_typed_client() {
+        let exports = vec![AnalysedExport::Function(AnalysedFunction {
+            name: "get-user".to_string(),
+            parameters: vec![AnalysedFunctionParameter {
+                name: "user-id".to_string(),
+                typ: str(),
+            }],
+            results: vec![AnalysedFunctionResult {
+                name: None,
+                typ: option(record(vec![
+                    field("display-name", str()),
+                    field("roles", flags(&["admin", "delete"])),
+                ])),
+            }],
+        })];
+        let sdk = ClientSdk::new("test", Uuid::nil(), &exports);
+
+        let files = generate(&sdk);
+        let content = &files[0].content;
+
+        assert!(content.contains("export interface GetUserResult {\n  displayName: string;\n  roles: GetUserResultRoles;\n}"));
+        assert!(content.contains("  delete_: boolean;"));
+        assert!(
+            content.contains("async getUser(userId: string): Promise<GetUserResult | undefined>")
+        );
+        assert!(content.contains("return optionDecoder(decodeGetUserResult)(result[0]);"));
+        assert!(content.contains("async enqueueGetUser(userId: string): Promise<void>"));
+        assert!(content.contains("\"display-name\": identity(value.displayName),"));
+    }
+
+    #[test]
+    fn generates_record_variant_resource_interface() {
+        let sdk = ClientSdk::new("test", Uuid::nil(), &example_exports());
+
+        let files = generate(&sdk);
+        assert!(files.len() == 1);
+        assert!(files[0].path.to_string_lossy() == "client.ts");
+
+        let (header, generated) = files[0].content.split_once(RUNTIME).unwrap();
+        assert!(
+            header
+                == "// Generated by golem-cli for component test (00000000-0000-0000-0000-000000000000), do not edit manually\n\n\
+                    export const COMPONENT_ID = \"00000000-0000-0000-0000-000000000000\";\n\n"
+        );
+
+        let expected = indoc! {r#"
+            export interface AddItemItem {
+              name: string;
+              count: number;
+              status: AddItemItemStatus;
+            }
+
+            function encodeAddItemItem(value: AddItemItem): unknown {
+              return {
+                "name": identity(value.name),
+                "count": identity(value.count),
+                "status": encodeAddItemItemStatus(value.status),
+              };
+            }
+
+            function decodeAddItemItem(value: any): AddItemItem {
+              return {
+                name: identity(value["name"]),
+                count: identity(value["count"]),
+                status: decodeAddItemItemStatus(value["status"]),
+              };
+            }
+
+            export type AddItemItemStatus = "open" | "closed";
+
+            function encodeAddItemItemStatus(value: AddItemItemStatus): unknown {
+              return value;
+            }
+
+            function decodeAddItemItemStatus(value: any): AddItemItemStatus {
+              return value as AddItemItemStatus;
+            }
+
+            export type AddItemResult =
+              | { tag: "added"; val: number }
+              | { tag: "rejected" };
+
+            function encodeAddItemResult(value: AddItemResult): unknown {
+              switch (value.tag) {
+                case "added":
+                  return { "added": identity(value.val) };
+                case "rejected":
+                  return { "rejected": null };
+              }
+            }
+
+            function decodeAddItemResult(value: any): AddItemResult {
+              const [tag, val] = variantCase(value);
+              switch (tag) {
+                case "added":
+                  return { tag: "added", val: identity(val) };
+                case "rejected":
+                  return { tag: "rejected" };
+                default:
+                  throw new Error(`Unknown case of AddItemResult: ${tag}`);
+              }
+            }
+
+            /** Client for the exported interface `golem:it/api` */
+            export class ApiClient extends Invoker {
+              /** Invokes `golem:it/api.{add-item}` and awaits its result */
+              async addItem(item: AddItemItem): Promise<AddItemResult> {
+                const result = await this.invokeAndAwait("golem:it/api.{add-item}", [{ typ: ITEM_TYPE, value: encodeAddItemItem(item) }]);
+                return decodeAddItemResult(result[0]);
+              }
+
+              /** Enqueues an invocation of `golem:it/api.{add-item}` without awaiting its result */
+              async enqueueAddItem(item: AddItemItem): Promise<void> {
+                await this.invoke("golem:it/api.{add-item}", [{ typ: ITEM_TYPE, value: encodeAddItemItem(item) }]);
+              }
+
+              /** Invokes `golem:it/api.{get-items}` and awaits its result */
+              async getItems(): Promise<Array<AddItemItem | undefined>> {
+                const result = await this.invokeAndAwait("golem:it/api.{get-items}", []);
+                return listDecoder(optionDecoder(decodeAddItemItem))(result[0]);
+              }
+
+              /** Enqueues an invocation of `golem:it/api.{get-items}` without awaiting its result */
+              async enqueueGetItems(): Promise<void> {
+                await this.invoke("golem:it/api.{get-items}", []);
+              }
+            }
+        "#}
+        .replace("ITEM_TYPE", &type_json(&example_item()));
+
+        // Resource functions are skipped, all named types are emitted before the clients
+        assert!(generated == format!("\n{}", expected));
+        assert!(sdk.skipped_functions == vec!["golem:it/api.{[constructor]counter}"]);
+    }
+}
@@ -617,6 +617,20 @@ pub mod shared_args {
         pub force_build: bool,
     }
 
+    #[derive(Debug, Args)]
+    pub struct StrictWitLintArg {
+        /// Fail the build if linting the source WIT of the components reports any issues, linting is never skipped as up-to-date in this mode
+        #[clap(long, default_value = "false")]
+        pub strict: bool,
+    }
+
+    #[derive(Debug, Args)]
+    pub struct WitCompatCheckArg {
+        /// Compare the exports of the built components with their latest deployed versions, and do not deploy if there are breaking changes
+        #[clap(long, default_value = "false")]
+        pub check_wit_compat: bool,
+    }
+
     #[derive(Debug, Args)]
     pub struct BuildArgs {
         /// Select specific build step(s)
@@ -624,6 +638,8 @@ pub mod shared_args {
         pub step: Vec<AppBuildStep>,
         #[command(flatten)]
         pub force_build: ForceBuildArg,
+        #[command(flatten)]
+        pub strict_wit_lint: StrictWitLintArg,
     }
 
     #[derive(Debug, Args)]
@@ -699,11 +715,13 @@ pub mod shared_args {
 
 pub mod app {
     use crate::command::shared_args::{
-        AppOptionalComponentNames, BuildArgs, ForceBuildArg, WorkerUpdateOrRedeployArgs,
+        AppOptionalComponentNames, BuildArgs, ForceBuildArg, StrictWitLintArg, WitCompatCheckArg,
+        WorkerUpdateOrRedeployArgs,
     };
     use crate::model::WorkerUpdateMode;
     use clap::Subcommand;
     use golem_templates::model::GuestLanguage;
+    use std::path::PathBuf;
 
     #[derive(Debug, Subcommand)]
     pub enum AppSubcommand {
@@ -729,7 +747,11 @@ pub mod app {
             #[command(flatten)]
             force_build: ForceBuildArg,
             #[command(flatten)]
+            strict_wit_lint: StrictWitLintArg,
+            #[command(flatten)]
             update_or_redeploy: WorkerUpdateOrRedeployArgs,
+            #[command(flatten)]
+            wit_compat_check: WitCompatCheckArg,
         },
         /// Clean all components in the application or by selection
         Clean {
@@ -754,21 +776,59 @@ pub mod app {
             #[command(flatten)]
             component_name: AppOptionalComponentNames,
         },
+        /// Lint the source WIT of all or selected components for issues affecting RPC and client generation
+        LintWit {
+            #[command(flatten)]
+            component_name: AppOptionalComponentNames,
+            /// Exit with failure if any issues are found
+            #[clap(long, default_value = "false")]
+            strict: bool,
+        },
+        /// Manage the WIT packages of the shared wit deps directory
+        WitDeps {
+            #[command(subcommand)]
+            subcommand: AppWitDepsSubcommand,
+        },
         /// Run custom command
         #[clap(external_subcommand)]
         CustomCommand(Vec<String>),
     }
+
+    #[derive(Debug, Subcommand)]
+    pub enum AppWitDepsSubcommand {
+        /// Add a WIT package (and the packages in its deps directory) from a local directory or zip archive to the shared wit deps directory, and lock it by content hash
+        Add {
+            /// Local WIT package directory or zip archive
+            source: PathBuf,
+            /// Replace the package if it is already locked
+            #[arg(long)]
+            replace: bool,
+            /// Overwrite existing directories in the shared wit deps directory which are not locked
+            #[arg(long)]
+            force: bool,
+        },
+        /// Update locked packages from their sources, then sync all component copies with the shared wit deps directory
+        Update {
+            /// Packages to update (e.g. wasi:io), defaults to all locked packages
+            package: Vec<String>,
+        },
+        /// List packages of the shared wit deps directory, their lock status and the components using them
+        List,
+    }
 }
 
 pub mod component {
+    use crate::client_sdk::ClientLanguage;
     use crate::command::component::plugin::ComponentPluginSubcommand;
     use crate::command::shared_args::{
         BuildArgs, ComponentOptionalComponentName, ComponentOptionalComponentNames,
-        ComponentTemplatePositionalArg, ForceBuildArg, WorkerUpdateOrRedeployArgs,
+        ComponentTemplatePositionalArg, ForceBuildArg, StrictWitLintArg, WitCompatCheckArg,
+        WorkerUpdateOrRedeployArgs,
     };
     use crate::model::WorkerUpdateMode;
     use clap::Subcommand;
     use golem_templates::model::PackageName;
+    use std::path::PathBuf;
 
     #[derive(Debug, Subcommand)]
     pub enum ComponentSubcommand {
@@ -798,7 +858,11 @@ pub mod component {
             #[command(flatten)]
             force_build: ForceBuildArg,
             #[command(flatten)]
+            strict_wit_lint: StrictWitLintArg,
+            #[command(flatten)]
             update_or_redeploy: WorkerUpdateOrRedeployArgs,
+            #[command(flatten)]
+            wit_compat_check: WitCompatCheckArg,
         },
         /// Clean component(s) based on the current directory or by selection
         Clean {
@@ -840,6 +904,53 @@ pub mod component {
             #[command(flatten)]
             component_name: ComponentOptionalComponentNames,
         },
+        /// Generate a typed HTTP client SDK for invoking the exported functions of a deployed component
+        GenerateClient {
+            #[command(flatten)]
+            component_name: ComponentOptionalComponentName,
+            /// Optional component version to generate the client for, defaults to the latest version
+            version: Option<u64>,
+            /// Target language of the generated client
+            #[arg(long)]
+            lang: ClientLanguage,
+            /// Output directory of the generated client
+            #[arg(long)]
+            output_dir: PathBuf,
+        },
+        /// Build component(s) and check if their exported interfaces are compatible with the latest deployed versions
+        WitCompat {
+            #[command(flatten)]
+            component_name: ComponentOptionalComponentNames,
+            #[command(flatten)]
+            force_build: ForceBuildArg,
+        },
+        /// Inspect the imports, exports, metadata and custom sections of a component WASM
+        Inspect {
+            /// Path of a WASM file, or a component name. For components of the current application the local linked WASM is used, otherwise the component is downloaded
+            target: String,
+            /// Component version to download, always downloads from the server
+            #[arg(long)]
+            version: Option<u64>,
+        },
+        /// Build component(s) without linking, and show which imports would be satisfied by which static WASM RPC client or library during linking
+        InspectLink {
+            #[command(flatten)]
+            component_name: ComponentOptionalComponentNames,
+            /// Print the import graph in Graphviz DOT format, instead of the format selected by --format
+            #[arg(long)]
+            dot: bool,
+        },
+        /// Export the WASM RPC client of a component as a self-contained package (client WIT with deps and Rust stub crate), to be used from other applications
+        ExportClient {
+            #[command(flatten)]
+            component_name: ComponentOptionalComponentName,
+            /// Output directory of the exported client package
+            #[arg(long)]
+            out: PathBuf,
+            /// Also build the client WASM and include it in the package
+            #[arg(long)]
+            with_wasm: bool,
+        },
     }
 
     pub mod plugin {
@@ -1052,6 +1163,9 @@ pub mod api {
     use crate::command::api::definition::ApiDefinitionSubcommand;
     use crate::command::api::deployment::ApiDeploymentSubcommand;
     use crate::command::api::security_scheme::ApiSecuritySchemeSubcommand;
+    use crate::command::parse_key_val;
+    use crate::command::shared_args::{ProjectNameOptionalArg, StreamArgs};
+    use crate::model::PathBufOrStdin;
     use clap::Subcommand;
 
     #[derive(Debug, Subcommand)]
@@ -1076,12 +1190,41 @@ pub mod api {
             #[clap(subcommand)]
             subcommand: ApiCloudSubcommand,
         },
+        /// Call a route of an API deployment
+        Call {
+            #[command(flatten)]
+            project: ProjectNameOptionalArg,
+            /// Deployment site
+            #[arg(value_name = "subdomain.host")]
+            site: String,
+            /// HTTP method of the request
+            method: String,
+            /// Request path, optionally including the query
+            path: String,
+            /// Request header, can be defined multiple times
+            #[arg(long, short = 'H', value_parser = parse_key_val, value_name = "NAME=VALUE")]
+            header: Vec<(String, String)>,
+            /// Request body
+            #[arg(long, conflicts_with = "body_file")]
+            body: Option<String>,
+            /// Request body file, or '-' to use STDIN
+            #[arg(long, conflicts_with = "body", value_hint = clap::ValueHint::FilePath)]
+            body_file: Option<PathBufOrStdin>,
+            /// Connect to the worker resolved by the matching route
+            /// and live stream its standard output, error and log channels
+            #[arg(long, short)]
+            stream: bool,
+            #[command(flatten)]
+            stream_args: StreamArgs,
+        },
     }
 
     pub mod definition {
         use crate::command::shared_args::ProjectNameOptionalArg;
+        use crate::model::openapi::ApiDefinitionExportFormat;
         use crate::model::{ApiDefinitionId, ApiDefinitionVersion, PathBufOrStdin};
         use clap::Subcommand;
+        use std::path::PathBuf;
 
         #[derive(Debug, Subcommand)]
         pub enum ApiDefinitionSubcommand {
@@ -1095,6 +1238,54 @@ pub mod api {
                 #[arg(value_hint = clap::ValueHint::FilePath)]
                 definition: PathBufOrStdin,
             },
+            /// Export an API definition as OpenAPI document
+            Export {
+                #[command(flatten)]
+                project: ProjectNameOptionalArg,
+                /// Api definition id
+                #[arg(short, long)]
+                id: ApiDefinitionId,
+                /// Version of the api definition
+                #[arg(long)]
+                version: ApiDefinitionVersion,
+                /// Export format
+                #[arg(long, value_enum, default_value = "openapi-yaml")]
+                export_format: ApiDefinitionExportFormat,
+                /// Output file, if not specified the document is printed to STDOUT
+                #[arg(long, short, value_hint = clap::ValueHint::FilePath)]
+                output: Option<PathBuf>,
+            },
+            /// Shows route level differences between two versions of an API definition
+            Diff {
+                #[command(flatten)]
+                project: ProjectNameOptionalArg,
+                /// Api definition id
+                #[arg(short, long)]
+                id: ApiDefinitionId,
+                /// Base version of the api definition
+                #[arg(long)]
+                from: ApiDefinitionVersion,
+                /// Compared version of the api definition
+                #[arg(long)]
+                to: ApiDefinitionVersion,
+            },
+            /// Creates a new, non-draft version of an API definition from an existing version
+            Promote {
+                #[command(flatten)]
+                project: ProjectNameOptionalArg,
+                /// Api definition id
+                #[arg(short, long)]
+                id: ApiDefinitionId,
+                /// Source version of the api definition
+                #[arg(long)]
+                from: ApiDefinitionVersion,
+                /// New version of the api definition
+                #[arg(long)]
+                to: ApiDefinitionVersion,
+                /// Redeploy all sites using the source version with the new version
+                #[arg(long)]
+                redeploy: bool,
+            },
             /// Creates an API definition
             New {
                 #[command(flatten)]
@@ -1193,9 +1384,23 @@ pub mod api {
 
     pub mod security_scheme {
         use crate::command::shared_args::ProjectNameOptionalArg;
-        use crate::model::IdentityProviderType;
+        use crate::model::{IdentityProviderType, PathBufOrStdin};
         use clap::Subcommand;
 
+        #[derive(clap::Args, Debug)]
+        #[group(required = true, multiple = false)]
+        pub struct ClientSecretArgs {
+            /// Security Scheme client secret
+            #[arg(long)]
+            pub client_secret: Option<String>,
+            /// File containing the Security Scheme client secret, or '-' to use STDIN
+            #[arg(long, value_hint = clap::ValueHint::FilePath)]
+            pub client_secret_file: Option<PathBufOrStdin>,
+            /// Environment variable containing the Security Scheme client secret
+            #[arg(long)]
+            pub client_secret_env: Option<String>,
+        }
+
         #[derive(Debug, Subcommand)]
         pub enum ApiSecuritySchemeSubcommand {
             /// Create API Security Scheme
@@ -1210,9 +1415,8 @@ pub mod api {
                 /// Security Scheme client ID
                 #[arg(long)]
                 client_id: String,
-                /// Security Scheme client secret
-                #[arg(long)]
-                client_secret: String,
+                #[command(flatten)]
+                client_secret: ClientSecretArgs,
                 #[arg(long)]
                 /// Security Scheme Scopes, can be defined multiple times
                 scope: Vec<String>,
@@ -1228,6 +1432,18 @@ pub mod api {
                 /// Security Scheme ID
                 security_scheme_id: String,
             },
+
+            /// List API Security Schemes referenced by API definitions, together with the referencing definitions
+            List {
+                #[command(flatten)]
+                project: ProjectNameOptionalArg,
+            },
+            /* TODO: Missing from HTTP API, which only supports create and get for security schemes
+            /// List all API Security Schemes, including the ones not referenced by any API definition
+            /// Update API Security Scheme scopes, redirect URL or client ID
+            /// Rotate API Security Scheme client secret
+            /// Delete API Security Scheme, refused while referenced by API definitions unless --force is used
+             */
         }
     }
 
@@ -1281,6 +1497,7 @@ pub mod api {
         pub mod certificate {
             use crate::model::{PathBufOrStdin, ProjectName};
             use clap::Subcommand;
+            use std::path::PathBuf;
             use uuid::Uuid;
 
             #[derive(Debug, Subcommand)]
@@ -1297,14 +1514,40 @@ pub mod api {
                     /// Project name
                     project_name: ProjectName,
                     /// Domain name
-                    #[arg(short, long)]
+                    #[arg(short, long, alias = "domain")]
                     domain_name: String,
                     /// Certificate
-                    #[arg(long, value_hint = clap::ValueHint::FilePath)]
-                    certificate_body: PathBufOrStdin,
+                    #[arg(long, value_hint = clap::ValueHint::FilePath, required_unless_present = "self_signed")]
+                    certificate_body: Option<PathBufOrStdin>,
                     /// Certificate private key
-                    #[arg(long, value_hint = clap::ValueHint::FilePath)]
-                    certificate_private_key: PathBufOrStdin,
+                    #[arg(long, value_hint = clap::ValueHint::FilePath, required_unless_present = "self_signed")]
+                    certificate_private_key: Option<PathBufOrStdin>,
+                    /// Generate a self-signed certificate and private key for the domain, instead of using existing ones
+                    #[arg(long, conflicts_with_all = ["certificate_body", "certificate_private_key"])]
+                    self_signed: bool,
+                    /// Validity of the generated self-signed certificate in days
+                    #[arg(long, default_value = "90", requires = "self_signed")]
+                    validity_days: u32,
+                    /// Directory for saving the generated self-signed certificate and private key
+                    #[arg(long, value_hint = clap::ValueHint::DirPath, requires = "self_signed")]
+                    output_dir: Option<PathBuf>,
+                },
+                /// Checks PEM certificate chains for expiry, domain name mismatches and incomplete chains
+                ///
+                /// Exits with an error if any of the certificates has errors, so it can be used as a CI gate.
+                Check {
+                    /// PEM certificate files, starting with the leaf certificate and followed by the chain
+                    #[arg(required = true, value_hint = clap::ValueHint::FilePath)]
+                    certificate: Vec<PathBuf>,
+                    /// Expected domain name of the certificates
+                    #[arg(short, long, alias = "domain")]
+                    domain_name: Option<String>,
+                    /// Warn about certificates expiring within the given number of days
+                    #[arg(long, default_value = "30")]
+                    expiry_warning_days: u32,
+                    /// Also exit with an error if there are warnings
+                    #[arg(long)]
+                    fail_on_warning: bool,
                 },
                 /// Delete an existing certificate
                 #[command()]
@@ -1694,35 +1937,327 @@ pub mod cloud {
 }
 
 pub mod server {
-    use clap::Subcommand;
+    use crate::command::server::config::ServerConfigSubcommand;
+    use crate::command::server::data::ServerDataSubcommand;
+    use crate::command::server::executor::ServerExecutorSubcommand;
+    use crate::command::server::snapshot::{ServerDataArgs, ServerSnapshotSubcommand};
+    use clap::{Args, Subcommand};
     use std::path::PathBuf;
 
+    #[derive(Debug, Clone, Args)]
+    pub struct ServerRunArgs {
+        /// Address to serve the main API on, defaults to 0.0.0.0, use :: for both IPv4 and IPv6
+        #[clap(long)]
+        pub router_addr: Option<String>,
+
+        /// Port to serve the main API on, defaults to 9881
+        #[clap(long)]
+        pub router_port: Option<u16>,
+
+        /// Port to serve custom requests on, defaults to 9006
+        #[clap(long)]
+        pub custom_request_port: Option<u16>,
+
+        /// Directory to store data in. Defaults to $XDG_STATE_HOME/golem
+        #[clap(long)]
+        pub data_dir: Option<PathBuf>,
+
+        /// Clean the data directory before starting
+        #[clap(long, default_value = "false")]
+        pub clean: bool,
+
+        /// Server config file (golem-server.toml), can be overridden by GOLEM_SERVER__ prefixed env vars
+        #[clap(long, value_hint = clap::ValueHint::FilePath)]
+        pub config: Option<PathBuf>,
+
+        /// Serve the main API and custom requests over HTTPS, with a generated self-signed certificate
+        /// when no certificate is provided
+        #[clap(long, default_value = "false")]
+        pub tls: bool,
+
+        /// PEM encoded certificate chain to use for HTTPS
+        #[clap(long, value_hint = clap::ValueHint::FilePath, requires = "tls_key")]
+        pub tls_cert: Option<PathBuf>,
+
+        /// PEM encoded private key to use for HTTPS
+        #[clap(long, value_hint = clap::ValueHint::FilePath, requires = "tls_cert")]
+        pub tls_key: Option<PathBuf>,
+
+        /// Number of worker executors to start, defaults to 1
+        #[clap(long)]
+        pub executors: Option<usize>,
+
+        /// Application manifest (golem.yaml) to build and deploy once the server is ready
+        #[clap(long, value_hint = clap::ValueHint::FilePath, conflicts_with = "seed")]
+        pub app: Option<PathBuf>,
+
+        /// Seed file describing the application, workers, API definitions and warm-up invocations
+        /// to set up once the server is ready
+        #[clap(long, value_hint = clap::ValueHint::FilePath, conflicts_with = "app")]
+        pub seed: Option<PathBuf>,
+
+        /// Stop the server when seeding fails, by default the error is only reported
+        /// and the server keeps running
+        #[clap(long, default_value = "false")]
+        pub fail_on_seed_error: bool,
+
+        /// Record the served requests into a rotating journal in the data directory,
+        /// see `golem server requests`
+        #[clap(long, default_value = "false")]
+        pub request_journal: bool,
+
+        /// Export traces and metrics via OTLP to the given collector endpoint,
+        /// e.g. http://localhost:4317 for a local Jaeger
+        #[clap(long)]
+        pub otlp_endpoint: Option<String>,
+
+        /// Protocol of the OTLP export, defaults to grpc
+        #[clap(long, value_enum)]
+        pub otlp_protocol: Option<ServerOtlpProtocol>,
+
+        /// Allow applying pending database migrations to an existing data directory
+        /// after upgrading golem
+        #[clap(long, default_value = "false")]
+        pub migrate: bool,
+    }
+
+    #[derive(Debug, Clone, Args)]
+    pub struct ServerAddressArgs {
+        /// Address of the main API of the server
+        #[clap(long, default_value = "localhost")]
+        pub router_addr: String,
+
+        /// Port of the main API of the server
+        #[clap(long, default_value_t = 9881)]
+        pub router_port: u16,
+
+        /// Connect using HTTPS, accepting self-signed certificates
+        #[clap(long, default_value = "false")]
+        pub tls: bool,
+    }
+
     #[derive(Debug, Subcommand)]
     pub enum ServerSubcommand {
         /// Run golem server for local development
         Run {
-            /// Address to serve the main API on
-            #[clap(long, default_value = "0.0.0.0")]
-            router_addr: String,
-
-            /// Port to serve the main API on
-            #[clap(long, default_value_t = 9881)]
-            router_port: u16,
-
-            /// Port to serve custom requests on
-            #[clap(long, default_value_t = 9006)]
-            custom_request_port: u16,
-
-            /// Directory to store data in. Defaults to $XDG_STATE_HOME/golem
+            #[command(flatten)]
+            args: ServerRunArgs,
+        },
+        /// Start golem server in the background, writing a pidfile and logs to the data directory
+        Start {
+            #[command(flatten)]
+            args: ServerRunArgs,
+        },
+        /// Stop the golem server running in the background
+        Stop {
+            /// Data directory of the server. Defaults to $XDG_STATE_HOME/golem
+            #[clap(long)]
+            data_dir: Option<PathBuf>,
+        },
+        /// Restart the golem server running in the background
+        Restart {
+            #[command(flatten)]
+            args: ServerRunArgs,
+        },
+        /// Show the logs of the golem server running in the background
+        Logs {
+            /// Data directory of the server. Defaults to $XDG_STATE_HOME/golem
             #[clap(long)]
             data_dir: Option<PathBuf>,
 
-            /// Clean the data directory before starting
+            /// Keep printing new log lines
             #[clap(long, default_value = "false")]
-            clean: bool,
+            follow: bool,
+
+            /// Only show the logs of the selected service
+            #[clap(long, value_enum)]
+            service: Option<ServerService>,
         },
         /// Clean the local server data directory
         Clean,
+        /// Query the health of the services of a running golem server
+        Status {
+            #[command(flatten)]
+            server: ServerAddressArgs,
+
+            /// Wait until all services are healthy
+            #[clap(long, default_value = "false")]
+            wait: bool,
+
+            /// Maximum time to wait for the services in seconds
+            #[clap(long, default_value_t = 60)]
+            wait_timeout: u64,
+        },
+        /// Inspect the local server config
+        Config {
+            #[clap(subcommand)]
+            subcommand: ServerConfigSubcommand,
+        },
+        /// Save and restore snapshots of the local server data directory
+        Snapshot {
+            #[clap(subcommand)]
+            subcommand: ServerSnapshotSubcommand,
+        },
+        /// Manage the worker executors of a running golem server, for simulating node loss
+        Executor {
+            #[clap(subcommand)]
+            subcommand: ServerExecutorSubcommand,
+        },
+        /// Check the compatibility of the local server data directory with this version
+        Data {
+            #[clap(subcommand)]
+            subcommand: ServerDataSubcommand,
+        },
+        /// Show the request journal of the server, started with --request-journal
+        Requests {
+            #[command(flatten)]
+            data: ServerDataArgs,
+
+            /// Keep printing new requests
+            #[clap(long, default_value = "false")]
+            follow: bool,
+
+            /// Only show matching requests, in `field op value` form, e.g. `status>=400`, `worker=w1`
+            /// or `path~invoke`. Fields: service, method, path, status, latency, component, worker, error.
+            /// Operators: =, !=, ~ (contains), >, >=, <, <=. Can be repeated, all filters have to match.
+            #[clap(long)]
+            filter: Vec<String>,
+        },
+    }
+
+    #[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+    #[clap(rename_all = "kebab_case")]
+    pub enum ServerOtlpProtocol {
+        Grpc,
+        Http,
+    }
+
+    #[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+    #[clap(rename_all = "kebab_case")]
+    pub enum ServerService {
+        ShardManager,
+        ComponentCompilationService,
+        ComponentService,
+        WorkerExecutor,
+        WorkerService,
+    }
+
+    impl ServerService {
+        /// Name of the tracing span of the service
+        pub fn span_name(&self) -> &'static str {
+            match self {
+                ServerService::ShardManager => "shard-manager",
+                ServerService::ComponentCompilationService => "component-compilation-service",
+                ServerService::ComponentService => "component-service",
+                ServerService::WorkerExecutor => "worker-executor",
+                ServerService::WorkerService => "worker-service",
+            }
+        }
+    }
+
+    pub mod config {
+        use clap::Subcommand;
+        use std::path::PathBuf;
+
+        #[derive(Debug, Subcommand)]
+        pub enum ServerConfigSubcommand {
+            /// Print the effective server config, after applying the config file and env var overrides
+            Print {
+                /// Server config file (golem-server.toml)
+                #[clap(long, value_hint = clap::ValueHint::FilePath)]
+                config: Option<PathBuf>,
+            },
+        }
+    }
+
+    pub mod data {
+        use crate::command::server::snapshot::ServerDataArgs;
+        use clap::Subcommand;
+
+        #[derive(Debug, Subcommand)]
+        pub enum ServerDataSubcommand {
+            /// Report the version stamp of the data directory, and the applied and pending
+            /// migrations of its databases
+            Check {
+                #[command(flatten)]
+                data: ServerDataArgs,
+            },
+        }
+    }
+
+    pub mod executor {
+        use crate::command::server::ServerAddressArgs;
+        use clap::Subcommand;
+
+        #[derive(Debug, Subcommand)]
+        pub enum ServerExecutorSubcommand {
+            /// List the worker executors and their state
+            List {
+                #[command(flatten)]
+                server: ServerAddressArgs,
+            },
+            /// Stop a worker executor, its shards are reassigned to the remaining executors
+            Kill {
+                /// Index of the worker executor
+                index: usize,
+
+                #[command(flatten)]
+                server: ServerAddressArgs,
+            },
+            /// Restart a stopped or running worker executor
+            Restart {
+                /// Index of the worker executor
+                index: usize,
+
+                #[command(flatten)]
+                server: ServerAddressArgs,
+            },
+        }
+    }
+
+    pub mod snapshot {
+        use clap::{Args, Subcommand};
+        use std::path::PathBuf;
+
+        #[derive(Debug, Clone, Args)]
+        pub struct ServerDataArgs {
+            /// Data directory of the server. Defaults to $XDG_STATE_HOME/golem
+            #[clap(long)]
+            pub data_dir: Option<PathBuf>,
+
+            /// Server config file (golem-server.toml), used for locating the data and blob storage directories
+            #[clap(long, value_hint = clap::ValueHint::FilePath)]
+            pub config: Option<PathBuf>,
+        }
+
+        #[derive(Debug, Subcommand)]
+        pub enum ServerSnapshotSubcommand {
+            /// Save the databases, blob storage and shard manager state of the stopped server into a compressed archive
+            Save {
+                /// Name of the snapshot, or path of the snapshot archive (.zip)
+                name: String,
+
+                #[command(flatten)]
+                data: ServerDataArgs,
+
+                /// Overwrite an existing snapshot with the same name
+                #[clap(long, default_value = "false")]
+                force: bool,
+            },
+            /// Replace the data of the stopped server with a previously saved snapshot
+            Restore {
+                /// Name of the snapshot, or path of the snapshot archive (.zip)
+                name: String,
+
+                #[command(flatten)]
+                data: ServerDataArgs,
+            },
+            /// List the saved snapshots
+            List {
+                #[command(flatten)]
+                data: ServerDataArgs,
+            },
+        }
     }
 }
 
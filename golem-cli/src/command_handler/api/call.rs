@@ -0,0 +1,332 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::command::shared_args::{ProjectNameOptionalArg, StreamArgs};
+use crate::command_handler::worker::stream::WorkerConnection;
+use crate::command_handler::Handlers;
+use crate::context::{Context, GolemClients};
+use crate::error::NonSuccessfulExit;
+use crate::log::{log_action, log_warn_action, logln, LogColorize, LogIndent};
+use crate::model::api_route::{match_route_path, resolve_worker_name, route_path_specificity};
+use crate::model::text::fmt::log_error;
+use crate::model::{
+    ApiDefinitionId, ApiDefinitionVersion, ComponentName, IdempotencyKey, PathBufOrStdin,
+    ProjectNameAndId,
+};
+use anyhow::{anyhow, bail, Context as AnyhowContext};
+use golem_client::model::RouteResponseData;
+use reqwest::header::{HeaderName, HeaderValue, CONTENT_TYPE, HOST};
+use reqwest::Method;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::timeout;
+
+const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+
+pub struct ApiCallCommandHandler {
+    ctx: Arc<Context>,
+}
+
+impl ApiCallCommandHandler {
+    pub fn new(ctx: Arc<Context>) -> Self {
+        Self { ctx }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn cmd_call(
+        &self,
+        project: ProjectNameOptionalArg,
+        site: String,
+        method: String,
+        path: String,
+        headers: Vec<(String, String)>,
+        body: Option<String>,
+        body_file: Option<PathBufOrStdin>,
+        stream: bool,
+        stream_args: StreamArgs,
+    ) -> anyhow::Result<()> {
+        let method = Method::from_bytes(method.to_uppercase().as_bytes())
+            .with_context(|| format!("Invalid HTTP method: {}", method))?;
+        let path = if path.starts_with('/') {
+            path
+        } else {
+            format!("/{}", path)
+        };
+
+        let project = self
+            .ctx
+            .cloud_project_handler()
+            .opt_select_project(None /* TODO: account id */, project.project.as_ref())
+            .await?;
+
+        let deployment = self
+            .ctx
+            .api_deployment_handler()
+            .deployment(project.as_ref(), &site)
+            .await?;
+
+        let (route, path_vars) = self
+            .matching_route(
+                project.as_ref(),
+                &deployment.api_definitions,
+                &method,
+                &path,
+            )
+            .await?;
+
+        log_action(
+            "Matched",
+            format!(
+                "route {} {}",
+                route.method.to_string().log_color_highlight(),
+                route.path.log_color_highlight()
+            ),
+        );
+
+        // The worker stream stops at the end of the invocation with this key, which the API
+        // gateway takes from the request header
+        let idempotency_key = headers
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(IDEMPOTENCY_KEY_HEADER))
+            .map(|(_, value)| IdempotencyKey::from(value.as_str()))
+            .unwrap_or_default();
+
+        let stream_handle = if stream {
+            match self
+                .worker_connection(
+                    project.as_ref(),
+                    &route,
+                    &path_vars,
+                    stream_args,
+                    &idempotency_key,
+                )
+                .await?
+            {
+                Some(connection) => {
+                    Some(tokio::task::spawn(
+                        async move { connection.run_forever().await },
+                    ))
+                }
+                None => None,
+            }
+        } else {
+            None
+        };
+
+        let body = match (body, body_file) {
+            (Some(body), _) => Some(body),
+            (None, Some(body_file)) => Some(body_file.read_to_string()?),
+            (None, None) => None,
+        };
+
+        let base_url = match self.ctx.golem_clients().await? {
+            GolemClients::Oss(_) => format!("http://{}", deployment.site.host),
+            GolemClients::Cloud(_) => format!("https://{}", site),
+        };
+
+        let mut request = self
+            .ctx
+            .api_gateway_client()
+            .await?
+            .request(method, format!("{}{}", base_url, path))
+            .header(HOST, site.as_str())
+            .header(IDEMPOTENCY_KEY_HEADER, idempotency_key.0.as_str());
+        for (name, value) in headers
+            .into_iter()
+            .filter(|(name, _)| !name.eq_ignore_ascii_case(IDEMPOTENCY_KEY_HEADER))
+        {
+            request = request.header(
+                HeaderName::from_bytes(name.as_bytes())
+                    .with_context(|| format!("Invalid header name: {}", name))?,
+                HeaderValue::from_str(&value)
+                    .with_context(|| format!("Invalid header value for {}: {}", name, value))?,
+            );
+        }
+        if let Some(body) = body {
+            request = request.body(body);
+        }
+
+        let response = request
+            .send()
+            .await
+            .with_context(|| format!("Failed to send request to {}", site))?;
+
+        let status = response.status();
+        if status.is_success() {
+            log_action(
+                "Received",
+                status.to_string().log_color_ok_highlight().to_string(),
+            );
+        } else {
+            log_warn_action(
+                "Received",
+                status.to_string().log_color_error_highlight().to_string(),
+            );
+        }
+
+        {
+            let _indent = LogIndent::new();
+            for (name, value) in response.headers() {
+                logln(format!(
+                    "{}: {}",
+                    name.as_str().log_color_highlight(),
+                    value.to_str().unwrap_or("<non-ascii>")
+                ));
+            }
+        }
+        logln("");
+
+        let is_json = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|content_type| content_type.to_str().ok())
+            .map(|content_type| content_type.contains("json"))
+            .unwrap_or(false);
+        let body = response
+            .text()
+            .await
+            .context("Failed to read response body")?;
+
+        if is_json {
+            match serde_json::from_str::<serde_json::Value>(&body) {
+                Ok(json) => logln(serde_json::to_string_pretty(&json)?),
+                Err(_) => logln(body),
+            }
+        } else if !body.is_empty() {
+            logln(body);
+        }
+
+        // When a response is received, we wait a few seconds to make sure we get all the log lines
+        // but the worker stream task will quit as soon as the invocation end marker is reached
+        if let Some(stream_handle) = stream_handle {
+            logln("");
+            let _ = timeout(Duration::from_secs(3), stream_handle).await;
+        }
+
+        Ok(())
+    }
+
+    async fn matching_route(
+        &self,
+        project: Option<&ProjectNameAndId>,
+        api_definitions: &[golem_client::model::ApiDefinitionInfo],
+        method: &Method,
+        path: &str,
+    ) -> anyhow::Result<(RouteResponseData, BTreeMap<String, String>)> {
+        let mut candidates = Vec::<(RouteResponseData, BTreeMap<String, String>)>::new();
+        for api_definition in api_definitions {
+            let definition = self
+                .ctx
+                .api_definition_handler()
+                .api_definition(
+                    project,
+                    &ApiDefinitionId(api_definition.id.clone()),
+                    &ApiDefinitionVersion(api_definition.version.clone()),
+                )
+                .await?;
+
+            for route in definition.routes {
+                if !route
+                    .method
+                    .to_string()
+                    .eq_ignore_ascii_case(method.as_str())
+                {
+                    continue;
+                }
+                if let Some(path_vars) = match_route_path(&route.path, path) {
+                    candidates.push((route, path_vars));
+                }
+            }
+        }
+
+        match candidates
+            .into_iter()
+            .max_by_key(|(route, _)| route_path_specificity(&route.path))
+        {
+            Some(candidate) => Ok(candidate),
+            None => {
+                log_error(format!(
+                    "No route found for {} {}",
+                    method.as_str().log_color_highlight(),
+                    path.log_color_highlight()
+                ));
+                bail!(NonSuccessfulExit)
+            }
+        }
+    }
+
+    async fn worker_connection(
+        &self,
+        project: Option<&ProjectNameAndId>,
+        route: &RouteResponseData,
+        path_vars: &BTreeMap<String, String>,
+        stream_args: StreamArgs,
+        idempotency_key: &IdempotencyKey,
+    ) -> anyhow::Result<Option<WorkerConnection>> {
+        let Some(component) = &route.binding.component else {
+            log_warn_action(
+                "Skipping",
+                "worker streaming, the route is not bound to a component",
+            );
+            return Ok(None);
+        };
+
+        let Some(worker_name) = route
+            .binding
+            .worker_name
+            .as_ref()
+            .and_then(|worker_name| resolve_worker_name(worker_name, path_vars))
+        else {
+            log_warn_action(
+                "Skipping",
+                "worker streaming, the worker name of the route cannot be resolved statically",
+            );
+            return Ok(None);
+        };
+
+        let component_name = ComponentName::from(component.name.as_str());
+        let component_id = self
+            .ctx
+            .component_handler()
+            .component_id_by_name(project, &component_name)
+            .await?
+            .ok_or_else(|| anyhow!("Component {} not found", component_name))?;
+
+        log_action(
+            "Connecting",
+            format!(
+                "to worker {}/{}",
+                component_name.0.log_color_highlight(),
+                worker_name.log_color_highlight()
+            ),
+        );
+
+        Ok(Some(
+            WorkerConnection::new(
+                self.ctx.worker_service_url().clone(),
+                self.ctx.auth_token().await?,
+                component_id.0,
+                worker_name,
+                stream_args.into(),
+                self.ctx.allow_insecure(),
+                self.ctx.format(),
+                Some(golem_common::model::IdempotencyKey::new(
+                    idempotency_key.0.clone(),
+                )),
+            )
+            .await?,
+        ))
+    }
+}
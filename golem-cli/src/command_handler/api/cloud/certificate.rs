@@ -17,13 +17,19 @@ use crate::command_handler::Handlers;
 use crate::context::Context;
 use crate::error::service::AnyhowMapServiceError;
 use crate::error::NonSuccessfulExit;
-use crate::log::log_warn_action;
-use crate::model::text::certificate::{CertificateListView, CertificateNewView};
+use crate::fs;
+use crate::log::{log_action, log_warn_action, LogColorize, LogIndent};
+use crate::model::certificate::{check_certificate_pem, generate_self_signed_certificate};
+use crate::model::text::certificate::{
+    CertificateCheckView, CertificateListView, CertificateNewView,
+};
 use crate::model::text::fmt::log_error;
 use crate::model::{PathBufOrStdin, ProjectName};
 use anyhow::bail;
+use chrono::Utc;
 use golem_cloud_client::api::ApiCertificateClient;
 use golem_cloud_client::model::CertificateRequest;
+use std::path::PathBuf;
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -47,15 +53,34 @@ impl ApiCloudCertificateCommandHandler {
                 domain_name,
                 certificate_body,
                 certificate_private_key,
+                self_signed,
+                validity_days,
+                output_dir,
             } => {
-                self.cmd_new(
-                    project_name,
-                    domain_name,
-                    certificate_body,
-                    certificate_private_key,
-                )
-                .await
+                if self_signed {
+                    self.cmd_new_self_signed(project_name, domain_name, validity_days, output_dir)
+                        .await
+                } else {
+                    self.cmd_new(
+                        project_name,
+                        domain_name,
+                        certificate_body,
+                        certificate_private_key,
+                    )
+                    .await
+                }
             }
+            ApiCertificateSubcommand::Check {
+                certificate,
+                domain_name,
+                expiry_warning_days,
+                fail_on_warning,
+            } => self.cmd_check(
+                certificate,
+                domain_name,
+                expiry_warning_days,
+                fail_on_warning,
+            ),
             ApiCertificateSubcommand::Delete {
                 project_name,
                 certificate_id,
@@ -97,14 +122,81 @@ impl ApiCloudCertificateCommandHandler {
         &self,
         project_name: ProjectName,
         domain_name: String,
-        certificate_body: PathBufOrStdin,
-        certificate_private_key: PathBufOrStdin,
+        certificate_body: Option<PathBufOrStdin>,
+        certificate_private_key: Option<PathBufOrStdin>,
     ) -> anyhow::Result<()> {
+        let (Some(certificate_body), Some(certificate_private_key)) =
+            (certificate_body, certificate_private_key)
+        else {
+            log_error("Both certificate body and private key are required!");
+            bail!(NonSuccessfulExit)
+        };
+
         if certificate_body.is_stdin() && certificate_private_key.is_stdin() {
             log_error("Cannot use STDIN for multiple inputs!");
             bail!(NonSuccessfulExit)
         }
 
+        self.create_certificate(
+            project_name,
+            domain_name,
+            certificate_body.read_to_string()?,
+            certificate_private_key.read_to_string()?,
+        )
+        .await
+    }
+
+    async fn cmd_new_self_signed(
+        &self,
+        project_name: ProjectName,
+        domain_name: String,
+        validity_days: u32,
+        output_dir: Option<PathBuf>,
+    ) -> anyhow::Result<()> {
+        log_action(
+            "Generating",
+            format!(
+                "self-signed certificate for {}, valid for {} days",
+                domain_name.log_color_highlight(),
+                validity_days.to_string().log_color_highlight()
+            ),
+        );
+
+        let certificate = generate_self_signed_certificate(&domain_name, validity_days)?;
+
+        if let Some(output_dir) = output_dir {
+            let certificate_path = output_dir.join(format!("{}.crt", domain_name));
+            let private_key_path = output_dir.join(format!("{}.key", domain_name));
+            fs::write_str(&certificate_path, &certificate.certificate_pem)?;
+            fs::write_str_owner_only(&private_key_path, &certificate.private_key_pem)?;
+
+            let _indent = LogIndent::new();
+            log_action(
+                "Saved",
+                format!(
+                    "certificate to {} and private key to {}",
+                    certificate_path.log_color_highlight(),
+                    private_key_path.log_color_highlight()
+                ),
+            );
+        }
+
+        self.create_certificate(
+            project_name,
+            domain_name,
+            certificate.certificate_pem,
+            certificate.private_key_pem,
+        )
+        .await
+    }
+
+    async fn create_certificate(
+        &self,
+        project_name: ProjectName,
+        domain_name: String,
+        certificate_body: String,
+        certificate_private_key: String,
+    ) -> anyhow::Result<()> {
         let certificate = self
             .ctx
             .golem_clients_cloud()
@@ -119,8 +211,8 @@ impl ApiCloudCertificateCommandHandler {
                     .project_id
                     .0,
                 domain_name,
-                certificate_body: "".to_string(),
-                certificate_private_key: "".to_string(),
+                certificate_body,
+                certificate_private_key,
             })
             .await
             .map_service_error()?;
@@ -132,6 +224,43 @@ impl ApiCloudCertificateCommandHandler {
         Ok(())
     }
 
+    fn cmd_check(
+        &self,
+        certificates: Vec<PathBuf>,
+        domain_name: Option<String>,
+        expiry_warning_days: u32,
+        fail_on_warning: bool,
+    ) -> anyhow::Result<()> {
+        let now = Utc::now();
+
+        let checks = certificates
+            .into_iter()
+            .map(|path| {
+                let pem = fs::read_to_string(&path)?;
+                Ok(check_certificate_pem(
+                    path.display().to_string(),
+                    &pem,
+                    domain_name.as_deref(),
+                    now,
+                    expiry_warning_days,
+                ))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let has_errors = checks.iter().any(|check| check.has_errors());
+        let has_warnings = checks.iter().any(|check| check.has_warnings());
+
+        self.ctx
+            .log_handler()
+            .log_view(&CertificateCheckView(checks));
+
+        if has_errors || (fail_on_warning && has_warnings) {
+            bail!(NonSuccessfulExit)
+        }
+
+        Ok(())
+    }
+
     async fn cmd_delete(
         &self,
         project_name: ProjectName,
@@ -17,17 +17,30 @@ use crate::command::shared_args::ProjectNameOptionalArg;
 use crate::command_handler::Handlers;
 use crate::context::{Context, GolemClients};
 use crate::error::service::AnyhowMapServiceError;
-use crate::log::{log_warn_action, LogColorize};
+use crate::fs;
+use crate::log::{log_action, log_warn_action, LogColorize};
+use crate::model::api_definition::{api_definition_request_json, ApiDefinitionDiff};
+use crate::model::openapi::{
+    api_definition_to_openapi, ApiDefinitionExportFormat, BoundComponents,
+};
 use crate::model::text::api_definition::{
     ApiDefinitionGetView, ApiDefinitionNewView, ApiDefinitionUpdateView,
 };
-use crate::model::{ApiDefinitionId, ApiDefinitionVersion, PathBufOrStdin};
+use crate::model::text::fmt::log_warn;
+use crate::model::{
+    ApiDefinitionId, ApiDefinitionIdWithVersion, ApiDefinitionVersion, ApiSecurityScheme,
+    ComponentName, PathBufOrStdin, ProjectNameAndId,
+};
 use anyhow::Context as AnyhowContext;
 use golem_client::api::ApiDefinitionClient as ApiDefinitionClientOss;
-use golem_client::model::HttpApiDefinitionRequest as HttpApiDefinitionRequestOss;
+use golem_client::model::{
+    HttpApiDefinitionRequest as HttpApiDefinitionRequestOss, HttpApiDefinitionResponseData,
+};
 use golem_cloud_client::api::ApiDefinitionClient as ApiDefinitionClientCloud;
 use golem_cloud_client::model::HttpApiDefinitionRequest as HttpApiDefinitionRequestCloud;
 use serde::de::DeserializeOwned;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
 use std::sync::Arc;
 
 pub struct ApiDefinitionCommandHandler {
@@ -58,6 +71,29 @@ impl ApiDefinitionCommandHandler {
                 id,
                 version,
             } => self.cmd_get(project, id, version).await,
+            ApiDefinitionSubcommand::Export {
+                project,
+                id,
+                version,
+                export_format,
+                output,
+            } => {
+                self.cmd_export(project, id, version, export_format, output)
+                    .await
+            }
+            ApiDefinitionSubcommand::Diff {
+                project,
+                id,
+                from,
+                to,
+            } => self.cmd_diff(project, id, from, to).await,
+            ApiDefinitionSubcommand::Promote {
+                project,
+                id,
+                from,
+                to,
+                redeploy,
+            } => self.cmd_promote(project, id, from, to, redeploy).await,
             ApiDefinitionSubcommand::Delete {
                 project,
                 id,
@@ -120,33 +156,303 @@ impl ApiDefinitionCommandHandler {
             .opt_select_project(None /* TODO: account id */, project.project.as_ref())
             .await?;
 
+        let result = self
+            .api_definition(project.as_ref(), &api_def_id, &version)
+            .await?;
+
+        self.ctx
+            .log_handler()
+            .log_view(&ApiDefinitionGetView(result));
+
+        Ok(())
+    }
+
+    async fn cmd_export(
+        &self,
+        project: ProjectNameOptionalArg,
+        api_def_id: ApiDefinitionId,
+        version: ApiDefinitionVersion,
+        format: ApiDefinitionExportFormat,
+        output: Option<PathBuf>,
+    ) -> anyhow::Result<()> {
+        let project = self
+            .ctx
+            .cloud_project_handler()
+            .opt_select_project(None /* TODO: account id */, project.project.as_ref())
+            .await?;
+
+        let definition = self
+            .api_definition(project.as_ref(), &api_def_id, &version)
+            .await?;
+
+        let mut bound_components = BoundComponents::new();
+        let mut security_schemes = BTreeMap::<String, ApiSecurityScheme>::new();
+        for route in &definition.routes {
+            if let Some(component) = &route.binding.component {
+                let key = (component.name.clone(), component.version);
+                if !bound_components.contains_key(&key) {
+                    let component_name = ComponentName::from(component.name.as_str());
+                    match self
+                        .ctx
+                        .component_handler()
+                        .component(
+                            project.as_ref(),
+                            (&component_name).into(),
+                            Some(component.version.into()),
+                        )
+                        .await?
+                    {
+                        Some(component) => {
+                            bound_components.insert(key, component);
+                        }
+                        None => log_warn(format!(
+                            "Component {} version {} not found, schemas for its routes will not be inferred",
+                            component.name.log_color_highlight(),
+                            component.version.to_string().log_color_highlight(),
+                        )),
+                    }
+                }
+            }
+
+            if let Some(security) = &route.security {
+                if !security_schemes.contains_key(security) {
+                    let scheme = self
+                        .ctx
+                        .api_security_scheme_handler()
+                        .security_scheme(project.as_ref(), security)
+                        .await?;
+                    security_schemes.insert(security.clone(), scheme);
+                }
+            }
+        }
+
+        let document = api_definition_to_openapi(&definition, &bound_components, &security_schemes);
+
+        let document = match format {
+            ApiDefinitionExportFormat::OpenapiYaml => {
+                serde_yaml::to_string(&document).context("Failed to serialize OpenAPI document")?
+            }
+            ApiDefinitionExportFormat::OpenapiJson => serde_json::to_string_pretty(&document)
+                .context("Failed to serialize OpenAPI document")?,
+        };
+
+        match output {
+            Some(output) => {
+                fs::write_str(&output, document)?;
+                log_action(
+                    "Exported",
+                    format!(
+                        "API definition {}/{} to {}",
+                        api_def_id.0.log_color_highlight(),
+                        version.0.log_color_highlight(),
+                        output.log_color_highlight()
+                    ),
+                );
+            }
+            None => println!("{}", document),
+        }
+
+        Ok(())
+    }
+
+    async fn cmd_diff(
+        &self,
+        project: ProjectNameOptionalArg,
+        api_def_id: ApiDefinitionId,
+        from: ApiDefinitionVersion,
+        to: ApiDefinitionVersion,
+    ) -> anyhow::Result<()> {
+        let project = self
+            .ctx
+            .cloud_project_handler()
+            .opt_select_project(None /* TODO: account id */, project.project.as_ref())
+            .await?;
+
+        let from = self
+            .api_definition(project.as_ref(), &api_def_id, &from)
+            .await?;
+        let to = self
+            .api_definition(project.as_ref(), &api_def_id, &to)
+            .await?;
+
+        self.ctx
+            .log_handler()
+            .log_view(&ApiDefinitionDiff::new(&from, &to)?);
+
+        Ok(())
+    }
+
+    async fn cmd_promote(
+        &self,
+        project: ProjectNameOptionalArg,
+        api_def_id: ApiDefinitionId,
+        from: ApiDefinitionVersion,
+        to: ApiDefinitionVersion,
+        redeploy: bool,
+    ) -> anyhow::Result<()> {
+        let project = self
+            .ctx
+            .cloud_project_handler()
+            .opt_select_project(None /* TODO: account id */, project.project.as_ref())
+            .await?;
+
+        let source = self
+            .api_definition(project.as_ref(), &api_def_id, &from)
+            .await?;
+        let request = api_definition_request_json(&source, &to.0, false)?;
+
         let result = match self.ctx.golem_clients().await? {
-            GolemClients::Oss(clients) => clients
-                .api_definition
-                .get_definition(&api_def_id.0, &version.0)
-                .await
-                .map_service_error()?,
+            GolemClients::Oss(clients) => {
+                let request: HttpApiDefinitionRequestOss = serde_json::from_value(request)
+                    .context("Failed to create API definition request")?;
+                clients
+                    .api_definition
+                    .create_definition_json(&request)
+                    .await
+                    .map_service_error()?
+            }
             GolemClients::Cloud(clients) => {
+                let request: HttpApiDefinitionRequestCloud = serde_json::from_value(request)
+                    .context("Failed to create API definition request")?;
                 let project = self
                     .ctx
                     .cloud_project_handler()
-                    .selected_project_or_default(project)
+                    .selected_project_or_default(project.clone())
                     .await?;
                 clients
                     .api_definition
-                    .get_definition(&project.project_id.0, &api_def_id.0, &version.0)
+                    .create_definition_json(&project.project_id.0, &request)
                     .await
                     .map_service_error()?
             }
         };
 
+        log_action(
+            "Promoted",
+            format!(
+                "API definition {} version {} to version {}",
+                api_def_id.0.log_color_highlight(),
+                from.0.log_color_highlight(),
+                to.0.log_color_highlight()
+            ),
+        );
+
         self.ctx
             .log_handler()
-            .log_view(&ApiDefinitionGetView(result));
+            .log_view(&ApiDefinitionNewView(result));
+
+        if redeploy {
+            self.redeploy(project.as_ref(), &api_def_id, &from, &to)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn redeploy(
+        &self,
+        project: Option<&ProjectNameAndId>,
+        api_def_id: &ApiDefinitionId,
+        from: &ApiDefinitionVersion,
+        to: &ApiDefinitionVersion,
+    ) -> anyhow::Result<()> {
+        let deployments = self
+            .ctx
+            .api_deployment_handler()
+            .list(project, Some(api_def_id))
+            .await?;
+
+        let mut redeployed = 0;
+        for deployment in deployments {
+            if !deployment
+                .api_definitions
+                .iter()
+                .any(|def| def.id == api_def_id.0 && def.version == from.0)
+            {
+                continue;
+            }
+
+            let api_defs = deployment
+                .api_definitions
+                .iter()
+                .map(|def| ApiDefinitionIdWithVersion {
+                    id: ApiDefinitionId(def.id.clone()),
+                    version: if def.id == api_def_id.0 && def.version == from.0 {
+                        to.clone()
+                    } else {
+                        ApiDefinitionVersion(def.version.clone())
+                    },
+                })
+                .collect::<Vec<_>>();
+
+            let site = match &deployment.site.subdomain {
+                Some(subdomain) => format!("{}.{}", subdomain, deployment.site.host),
+                None => deployment.site.host.clone(),
+            };
+
+            self.ctx
+                .api_deployment_handler()
+                .deploy(
+                    project,
+                    &api_defs,
+                    Some(deployment.site.host.clone()),
+                    deployment.site.subdomain.clone(),
+                )
+                .await?;
+            redeployed += 1;
+
+            log_action(
+                "Redeployed",
+                format!(
+                    "site {} with API definition {} version {}",
+                    site.log_color_highlight(),
+                    api_def_id.0.log_color_highlight(),
+                    to.0.log_color_highlight()
+                ),
+            );
+        }
+
+        if redeployed == 0 {
+            log_warn_action(
+                "Skipping",
+                format!(
+                    "redeploy, no sites are using API definition {} version {}",
+                    api_def_id.0.log_color_highlight(),
+                    from.0.log_color_highlight()
+                ),
+            );
+        }
 
         Ok(())
     }
 
+    pub async fn api_definition(
+        &self,
+        project: Option<&ProjectNameAndId>,
+        api_def_id: &ApiDefinitionId,
+        version: &ApiDefinitionVersion,
+    ) -> anyhow::Result<HttpApiDefinitionResponseData> {
+        match self.ctx.golem_clients().await? {
+            GolemClients::Oss(clients) => clients
+                .api_definition
+                .get_definition(&api_def_id.0, &version.0)
+                .await
+                .map_service_error(),
+            GolemClients::Cloud(clients) => {
+                let project = self
+                    .ctx
+                    .cloud_project_handler()
+                    .selected_project_or_default(project.cloned())
+                    .await?;
+                clients
+                    .api_definition
+                    .get_definition(&project.project_id.0, &api_def_id.0, &version.0)
+                    .await
+                    .map_service_error()
+            }
+        }
+    }
+
     async fn cmd_update(
         &self,
         project: ProjectNameOptionalArg,
@@ -248,32 +554,42 @@ impl ApiDefinitionCommandHandler {
             .opt_select_project(None /* TODO: account id */, project.project.as_ref())
             .await?;
 
-        let definitions = match self.ctx.golem_clients().await? {
+        let definitions = self
+            .definitions(project.as_ref(), api_definition_id.as_ref())
+            .await?;
+
+        self.ctx.log_handler().log_view(&definitions);
+
+        Ok(())
+    }
+
+    pub async fn definitions(
+        &self,
+        project: Option<&ProjectNameAndId>,
+        api_definition_id: Option<&ApiDefinitionId>,
+    ) -> anyhow::Result<Vec<HttpApiDefinitionResponseData>> {
+        match self.ctx.golem_clients().await? {
             GolemClients::Oss(clients) => clients
                 .api_definition
-                .list_definitions(api_definition_id.as_ref().map(|id| id.0.as_str()))
+                .list_definitions(api_definition_id.map(|id| id.0.as_str()))
                 .await
-                .map_service_error()?,
+                .map_service_error(),
             GolemClients::Cloud(clients) => {
                 let project = self
                     .ctx
                     .cloud_project_handler()
-                    .selected_project_or_default(project)
+                    .selected_project_or_default(project.cloned())
                     .await?;
                 clients
                     .api_definition
                     .list_definitions(
                         &project.project_id.0,
-                        api_definition_id.as_ref().map(|id| id.0.as_str()),
+                        api_definition_id.map(|id| id.0.as_str()),
                     )
                     .await
-                    .map_service_error()?
+                    .map_service_error()
             }
-        };
-
-        self.ctx.log_handler().log_view(&definitions);
-
-        Ok(())
+        }
     }
 
     async fn cmd_delete(
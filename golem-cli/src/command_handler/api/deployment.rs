@@ -20,7 +20,7 @@ use crate::error::service::AnyhowMapServiceError;
 use crate::error::NonSuccessfulExit;
 use crate::log::{log_warn_action, LogColorize};
 use crate::model::text::fmt::log_error;
-use crate::model::{ApiDefinitionId, ApiDefinitionIdWithVersion, ApiDeployment};
+use crate::model::{ApiDefinitionId, ApiDefinitionIdWithVersion, ApiDeployment, ProjectNameAndId};
 use anyhow::bail;
 use golem_client::api::ApiDeploymentClient as ApiDeploymentClientOss;
 use golem_client::model::{
@@ -75,14 +75,30 @@ impl ApiDeploymentCommandHandler {
             .opt_select_project(None /* TODO: account id */, project.project.as_ref())
             .await?;
 
-        let result: ApiDeployment = match self.ctx.golem_clients().await? {
+        let result = self
+            .deploy(project.as_ref(), &api_defs, host, subdomain)
+            .await?;
+
+        self.ctx.log_handler().log_view(&result);
+
+        Ok(())
+    }
+
+    pub async fn deploy(
+        &self,
+        project: Option<&ProjectNameAndId>,
+        api_defs: &[ApiDefinitionIdWithVersion],
+        host: Option<String>,
+        subdomain: Option<String>,
+    ) -> anyhow::Result<ApiDeployment> {
+        match self.ctx.golem_clients().await? {
             GolemClients::Oss(clients) => {
                 let site = ApiSiteOss {
                     host: host.unwrap_or_else(|| "localhost:9006".to_string()),
                     subdomain,
                 };
 
-                clients
+                Ok(clients
                     .api_deployment
                     .deploy(&ApiDeploymentRequestOss {
                         api_definitions: api_defs
@@ -96,20 +112,20 @@ impl ApiDeploymentCommandHandler {
                     })
                     .await
                     .map_service_error()?
-                    .into()
+                    .into())
             }
             GolemClients::Cloud(clients) => {
                 let project = self
                     .ctx
                     .cloud_project_handler()
-                    .selected_project_or_default(project)
+                    .selected_project_or_default(project.cloned())
                     .await?;
 
                 let host = host.ok_or(anyhow::anyhow!(
                     "Host is required to work with cloud API deployments"
                 ))?;
 
-                clients
+                Ok(clients
                     .api_deployment
                     .deploy(&ApiDeploymentRequestCloud {
                         project_id: project.project_id.0,
@@ -124,13 +140,9 @@ impl ApiDeploymentCommandHandler {
                     })
                     .await
                     .map_service_error()?
-                    .into()
+                    .into())
             }
-        };
-
-        self.ctx.log_handler().log_view(&result);
-
-        Ok(())
+        }
     }
 
     async fn cmd_get(&self, project: ProjectNameOptionalArg, site: String) -> anyhow::Result<()> {
@@ -140,32 +152,40 @@ impl ApiDeploymentCommandHandler {
             .opt_select_project(None /* TODO: account id */, project.project.as_ref())
             .await?;
 
-        let result: ApiDeployment = match self.ctx.golem_clients().await? {
-            GolemClients::Oss(clients) => clients
+        let result = self.deployment(project.as_ref(), &site).await?;
+
+        self.ctx.log_handler().log_view(&result);
+
+        Ok(())
+    }
+
+    pub async fn deployment(
+        &self,
+        project: Option<&ProjectNameAndId>,
+        site: &str,
+    ) -> anyhow::Result<ApiDeployment> {
+        match self.ctx.golem_clients().await? {
+            GolemClients::Oss(clients) => Ok(clients
                 .api_deployment
-                .get_deployment(&site)
+                .get_deployment(site)
                 .await
                 .map_service_error()?
-                .into(),
+                .into()),
             GolemClients::Cloud(clients) => {
                 let project = self
                     .ctx
                     .cloud_project_handler()
-                    .selected_project_or_default(project)
+                    .selected_project_or_default(project.cloned())
                     .await?;
 
-                clients
+                Ok(clients
                     .api_deployment
-                    .get_deployment(&project.project_id.0, &site)
+                    .get_deployment(&project.project_id.0, site)
                     .await
                     .map_service_error()?
-                    .into()
+                    .into())
             }
-        };
-
-        self.ctx.log_handler().log_view(&result);
-
-        Ok(())
+        }
     }
 
     async fn cmd_list(
@@ -173,39 +193,51 @@ impl ApiDeploymentCommandHandler {
         project: ProjectNameOptionalArg,
         definition: Option<ApiDefinitionId>,
     ) -> anyhow::Result<()> {
-        let id = definition.as_ref().map(|id| id.0.as_str());
-
         let project = self
             .ctx
             .cloud_project_handler()
             .opt_select_project(None /* TODO: account id */, project.project.as_ref())
             .await?;
 
-        let result: Vec<ApiDeployment> = match self.ctx.golem_clients().await? {
-            GolemClients::Oss(clients) => clients
+        let result = self.list(project.as_ref(), definition.as_ref()).await?;
+
+        self.ctx.log_handler().log_view(&result);
+
+        Ok(())
+    }
+
+    pub async fn list(
+        &self,
+        project: Option<&ProjectNameAndId>,
+        definition: Option<&ApiDefinitionId>,
+    ) -> anyhow::Result<Vec<ApiDeployment>> {
+        let id = definition.map(|id| id.0.as_str());
+
+        match self.ctx.golem_clients().await? {
+            GolemClients::Oss(clients) => Ok(clients
                 .api_deployment
                 .list_deployments(id)
                 .await
                 .map_service_error()?
                 .into_iter()
                 .map(ApiDeployment::from)
-                .collect::<Vec<_>>(),
+                .collect::<Vec<_>>()),
             GolemClients::Cloud(clients) => {
                 let project = self
                     .ctx
                     .cloud_project_handler()
-                    .selected_project_or_default(project)
+                    .selected_project_or_default(project.cloned())
                     .await?;
 
                 match id {
-                    Some(id) => clients
+                    Some(id) => Ok(clients
                         .api_deployment
                         .list_deployments(&project.project_id.0, id)
                         .await
                         .map_service_error()?
                         .into_iter()
                         .map(ApiDeployment::from)
-                        .collect::<Vec<_>>(),
+                        .collect::<Vec<_>>()),
                     None => {
                         // TODO: update in cloud to allow listing without id
                         log_error("API definition ID for Cloud is required");
@@ -213,11 +245,7 @@ impl ApiDeploymentCommandHandler {
                     }
                 }
             }
-        };
-
-        self.ctx.log_handler().log_view(&result);
-
-        Ok(())
+        }
     }
 
     async fn cmd_delete(
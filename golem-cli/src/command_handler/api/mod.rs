@@ -17,6 +17,7 @@ use crate::command_handler::Handlers;
 use crate::context::Context;
 use std::sync::Arc;
 
+pub mod call;
 pub mod cloud;
 pub mod definition;
 pub mod deployment;
@@ -57,6 +58,32 @@ impl ApiCommandHandler {
                     .handle_command(subcommand)
                     .await
             }
+            ApiSubcommand::Call {
+                project,
+                site,
+                method,
+                path,
+                header,
+                body,
+                body_file,
+                stream,
+                stream_args,
+            } => {
+                self.ctx
+                    .api_call_handler()
+                    .cmd_call(
+                        project,
+                        site,
+                        method,
+                        path,
+                        header,
+                        body,
+                        body_file,
+                        stream,
+                        stream_args,
+                    )
+                    .await
+            }
         }
     }
 }
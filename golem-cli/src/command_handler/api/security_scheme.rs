@@ -12,18 +12,25 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::command::api::security_scheme::ApiSecuritySchemeSubcommand;
+use crate::command::api::security_scheme::{ApiSecuritySchemeSubcommand, ClientSecretArgs};
 use crate::command::shared_args::ProjectNameOptionalArg;
 use crate::command_handler::Handlers;
 use crate::context::{Context, GolemClients};
 use crate::error::service::AnyhowMapServiceError;
-use crate::model::{ApiSecurityScheme, IdentityProviderType};
+use crate::model::{
+    ApiSecurityScheme, ApiSecuritySchemeUsage, IdentityProviderType, ProjectNameAndId,
+};
+use anyhow::{bail, Context as AnyhowContext};
 use golem_client::api::ApiSecurityClient as ApiSecurityClientOss;
-use golem_client::model::{Provider as ProviderOss, SecuritySchemeData as SecuritySchemeDataOss};
+use golem_client::model::{
+    ApiDefinitionInfo, Provider as ProviderOss, SecuritySchemeData as SecuritySchemeDataOss,
+};
 use golem_cloud_client::api::ApiSecurityClient as ApiSecurityClientCloud;
 use golem_cloud_client::model::{
     Provider as ProviderCloud, SecuritySchemeData as SecuritySchemeDataCloud,
 };
+use itertools::Itertools;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 pub struct ApiSecuritySchemeCommandHandler {
@@ -64,6 +71,7 @@ impl ApiSecuritySchemeCommandHandler {
                 project,
                 security_scheme_id,
             } => self.cmd_get(project, security_scheme_id).await,
+            ApiSecuritySchemeSubcommand::List { project } => self.cmd_list(project).await,
         }
     }
 
@@ -73,10 +81,12 @@ impl ApiSecuritySchemeCommandHandler {
         scheme_identifier: String,
         provider_type: IdentityProviderType,
         client_id: String,
-        client_secret: String,
+        client_secret: ClientSecretArgs,
         scopes: Vec<String>,
         redirect_url: String,
     ) -> anyhow::Result<()> {
+        let client_secret = read_client_secret(client_secret)?;
+
         let project = self
             .ctx
             .cloud_project_handler()
@@ -149,31 +159,114 @@ impl ApiSecuritySchemeCommandHandler {
             .opt_select_project(None /* TODO: account id */, project.project.as_ref())
             .await?;
 
-        let result: ApiSecurityScheme = match self.ctx.golem_clients().await? {
-            GolemClients::Oss(clients) => clients
+        let result = self
+            .security_scheme(project.as_ref(), &security_scheme_id)
+            .await?;
+
+        self.ctx.log_handler().log_view(&result);
+
+        Ok(())
+    }
+
+    async fn cmd_list(&self, project: ProjectNameOptionalArg) -> anyhow::Result<()> {
+        let project = self
+            .ctx
+            .cloud_project_handler()
+            .opt_select_project(None /* TODO: account id */, project.project.as_ref())
+            .await?;
+
+        let definitions = self
+            .ctx
+            .api_definition_handler()
+            .definitions(project.as_ref(), None)
+            .await?;
+
+        // Security schemes can only be queried by their ID (the HTTP API has no list, update or
+        // delete endpoints), so the list is based on the schemes referenced by routes of the
+        // existing API definitions
+        let mut used_by = BTreeMap::<String, Vec<ApiDefinitionInfo>>::new();
+        for definition in &definitions {
+            for security in definition
+                .routes
+                .iter()
+                .filter_map(|route| route.security.as_ref())
+                .unique()
+            {
+                used_by
+                    .entry(security.clone())
+                    .or_default()
+                    .push(ApiDefinitionInfo {
+                        id: definition.id.clone(),
+                        version: definition.version.clone(),
+                    });
+            }
+        }
+
+        let mut schemes = Vec::with_capacity(used_by.len());
+        for (security_scheme_id, used_by) in used_by {
+            schemes.push(ApiSecuritySchemeUsage {
+                scheme: self
+                    .security_scheme(project.as_ref(), &security_scheme_id)
+                    .await?,
+                used_by,
+            });
+        }
+
+        self.ctx.log_handler().log_view(&schemes);
+
+        Ok(())
+    }
+
+    pub async fn security_scheme(
+        &self,
+        project: Option<&ProjectNameAndId>,
+        security_scheme_id: &str,
+    ) -> anyhow::Result<ApiSecurityScheme> {
+        match self.ctx.golem_clients().await? {
+            GolemClients::Oss(clients) => Ok(clients
                 .api_security
-                .get(&security_scheme_id)
+                .get(security_scheme_id)
                 .await
                 .map_service_error()?
-                .into(),
+                .into()),
             GolemClients::Cloud(clients) => {
                 let project = self
                     .ctx
                     .cloud_project_handler()
-                    .selected_project_or_default(project)
+                    .selected_project_or_default(project.cloned())
                     .await?;
 
-                clients
+                Ok(clients
                     .api_security
-                    .get(&project.project_id.0, &security_scheme_id)
+                    .get(&project.project_id.0, security_scheme_id)
                     .await
                     .map_service_error()?
-                    .into()
+                    .into())
             }
-        };
+        }
+    }
+}
 
-        self.ctx.log_handler().log_view(&result);
+fn read_client_secret(args: ClientSecretArgs) -> anyhow::Result<String> {
+    let client_secret = match (
+        args.client_secret,
+        args.client_secret_file,
+        args.client_secret_env,
+    ) {
+        (Some(client_secret), _, _) => client_secret,
+        (None, Some(file), _) => file.read_to_string()?.trim_end().to_string(),
+        (None, None, Some(env_var)) => std::env::var(&env_var).with_context(|| {
+            format!(
+                "Failed to read client secret from environment variable {}",
+                env_var
+            )
+        })?,
+        (None, None, None) => bail!("Missing client secret"),
+    };
 
-        Ok(())
+    if client_secret.is_empty() {
+        bail!("The client secret must not be empty");
     }
+
+    Ok(client_secret)
 }
@@ -13,10 +13,16 @@
 // limitations under the License.
 
 use crate::app::error::CustomCommandError;
-use crate::command::app::AppSubcommand;
+use crate::app::wit_deps::{
+    component_package_copies, component_package_usages, copy_package_dir, package_dir_hash,
+    parse_deps_dir, parse_package_dir, prepare_package_source, source_packages,
+};
+use crate::app::wit_lint::lint_wit;
+use crate::command::app::{AppSubcommand, AppWitDepsSubcommand};
 use crate::command::builtin_app_subcommands;
 use crate::command::shared_args::{
-    AppOptionalComponentNames, BuildArgs, ForceBuildArg, WorkerUpdateOrRedeployArgs,
+    AppOptionalComponentNames, BuildArgs, ForceBuildArg, StrictWitLintArg, WitCompatCheckArg,
+    WorkerUpdateOrRedeployArgs,
 };
 use crate::command_handler::Handlers;
 use crate::context::Context;
@@ -25,10 +31,13 @@ use crate::error::{HintError, NonSuccessfulExit};
 use crate::fs;
 use crate::fuzzy::{Error, FuzzySearch};
 use crate::log::{log_action, logln, LogColorize, LogIndent, LogOutput, Output};
-use crate::model::app::{ApplicationComponentSelectMode, DynamicHelpSections};
+use crate::model::app::{Application, ApplicationComponentSelectMode, DynamicHelpSections};
 use crate::model::component::Component;
 use crate::model::text::fmt::{log_error, log_fuzzy_matches, log_text_view, log_warn};
 use crate::model::text::help::AvailableComponentNamesHelp;
+use crate::model::wit_deps::{
+    WitDepStatus, WitDepView, WitDepsLock, WitDepsLockEntry, WIT_DEPS_LOCK_FILE_NAME,
+};
 use crate::model::{ComponentName, WorkerUpdateMode};
 use anyhow::{anyhow, bail};
 use colored::Colorize;
@@ -37,7 +46,7 @@ use golem_templates::model::{
     ComposableAppGroupName, GuestLanguage, PackageName, Template, TemplateName,
 };
 use itertools::Itertools;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use strum::IntoEnumIterator;
 
@@ -63,10 +72,18 @@ impl AppCommandHandler {
             AppSubcommand::Deploy {
                 component_name,
                 force_build,
+                strict_wit_lint,
                 update_or_redeploy,
+                wit_compat_check,
             } => {
-                self.cmd_deploy(component_name, force_build, update_or_redeploy)
-                    .await
+                self.cmd_deploy(
+                    component_name,
+                    force_build,
+                    strict_wit_lint,
+                    update_or_redeploy,
+                    wit_compat_check,
+                )
+                .await
             }
             AppSubcommand::Clean { component_name } => self.cmd_clean(component_name).await,
             AppSubcommand::UpdateWorkers {
@@ -81,6 +98,11 @@ impl AppCommandHandler {
                     .await
             }
             AppSubcommand::Diagnose { component_name } => self.cmd_diagnose(component_name).await,
+            AppSubcommand::LintWit {
+                component_name,
+                strict,
+            } => self.cmd_lint_wit(component_name, strict).await,
+            AppSubcommand::WitDeps { subcommand } => self.cmd_wit_deps(subcommand).await,
             AppSubcommand::CustomCommand(command) => self.cmd_custom_command(command).await,
         }
     }
@@ -208,7 +230,9 @@ impl AppCommandHandler {
         &mut self,
         component_name: AppOptionalComponentNames,
         force_build: ForceBuildArg,
+        strict_wit_lint: StrictWitLintArg,
         update_or_redeploy: WorkerUpdateOrRedeployArgs,
+        wit_compat_check: WitCompatCheckArg,
     ) -> anyhow::Result<()> {
         self.ctx
             .component_handler()
@@ -220,8 +244,10 @@ impl AppCommandHandler {
                     .as_ref(),
                 component_name.component_name,
                 Some(force_build),
+                strict_wit_lint,
                 &ApplicationComponentSelectMode::All,
                 update_or_redeploy,
+                wit_compat_check.check_wit_compat,
             )
             .await
     }
@@ -331,6 +357,375 @@ impl AppCommandHandler {
         .await
     }
 
+    async fn cmd_lint_wit(
+        &mut self,
+        component_name: AppOptionalComponentNames,
+        strict: bool,
+    ) -> anyhow::Result<()> {
+        self.must_select_components(
+            component_name.component_name,
+            &ApplicationComponentSelectMode::All,
+        )
+        .await?;
+
+        let findings = {
+            let app_ctx = self.ctx.app_context_lock().await;
+            let app_ctx = app_ctx.some_or_err()?;
+            lint_wit(app_ctx, app_ctx.selected_component_names())?
+        };
+
+        self.ctx.log_handler().log_view(&findings);
+
+        if strict && !findings.is_empty() {
+            bail!(NonSuccessfulExit);
+        }
+
+        Ok(())
+    }
+
+    async fn cmd_wit_deps(&mut self, subcommand: AppWitDepsSubcommand) -> anyhow::Result<()> {
+        self.must_select_components(vec![], &ApplicationComponentSelectMode::All)
+            .await?;
+
+        match subcommand {
+            AppWitDepsSubcommand::Add {
+                source,
+                replace,
+                force,
+            } => self.wit_deps_add(source, replace, force).await,
+            AppWitDepsSubcommand::Update { package } => self.wit_deps_update(package).await,
+            AppWitDepsSubcommand::List => self.wit_deps_list().await,
+        }
+    }
+
+    async fn wit_deps_add(
+        &mut self,
+        source: PathBuf,
+        replace: bool,
+        force: bool,
+    ) -> anyhow::Result<()> {
+        let (paths, source) = {
+            let app_ctx = self.ctx.app_context_lock().await;
+            let app_ctx = app_ctx.some_or_err()?;
+            (
+                SharedWitDepsPaths::new(&app_ctx.application)?,
+                app_ctx.calling_working_dir.join(source),
+            )
+        };
+        let recorded_source = paths.recorded_source(&source);
+        let mut lock = WitDepsLock::load(&paths.lock_file)?;
+
+        let temp_dir = tempfile::tempdir()?;
+        let package_dir = prepare_package_source(&source, temp_dir.path()).await?;
+        let (main_package, dep_packages) = source_packages(&package_dir)?;
+
+        log_action(
+            "Adding",
+            format!(
+                "wit package {} to {}",
+                main_package.name.to_string().log_color_highlight(),
+                paths.deps_dir.log_color_highlight()
+            ),
+        );
+        let _indent = LogIndent::new();
+
+        for (source_dir, package) in std::iter::once((None, main_package)).chain(
+            dep_packages
+                .into_iter()
+                .map(|(dir, package)| (Some(dir), package)),
+        ) {
+            let key = package.key();
+            let hash = package_dir_hash(&package.dir)?;
+
+            let existing_dir = match lock.packages.get(&key) {
+                Some(entry) if entry.hash == hash => {
+                    log_action(
+                        "Skipping",
+                        format!(
+                            "wit package {}, already locked with the same content",
+                            key.log_color_highlight()
+                        ),
+                    );
+                    continue;
+                }
+                Some(entry) if !replace => {
+                    log_error(format!(
+                        "Wit package {} is already locked with different content, added from {}",
+                        key.log_color_highlight(),
+                        entry.source.log_color_highlight()
+                    ));
+                    logln("");
+                    logln(format!(
+                        "Use {} to replace it, or {} to update it from its source.",
+                        "--replace".log_color_highlight(),
+                        "app wit-deps update".log_color_highlight()
+                    ));
+                    bail!(NonSuccessfulExit);
+                }
+                Some(entry) => Some(entry.dir.clone()),
+                None => None,
+            };
+
+            let is_locked = existing_dir.is_some();
+            let dir = existing_dir.unwrap_or_else(|| {
+                let name_taken = lock
+                    .packages
+                    .values()
+                    .any(|entry| entry.dir == package.name.name);
+                if name_taken {
+                    format!("{}-{}", package.name.namespace, package.name.name)
+                } else {
+                    package.name.name.clone()
+                }
+            });
+
+            let target_dir = paths.deps_dir.join(&dir);
+            if !is_locked && target_dir.exists() && !force {
+                log_error(format!(
+                    "Target directory {} for wit package {} already exists, but it is not locked",
+                    target_dir.log_color_highlight(),
+                    key.log_color_highlight()
+                ));
+                logln("");
+                logln(format!(
+                    "Use {} to overwrite it.",
+                    "--force".log_color_highlight()
+                ));
+                bail!(NonSuccessfulExit);
+            }
+
+            copy_package_dir(&package.dir, &target_dir)?;
+            lock.packages.insert(
+                key,
+                WitDepsLockEntry {
+                    version: package.name.version.as_ref().map(|v| v.to_string()),
+                    dir,
+                    source: recorded_source.clone(),
+                    source_dir,
+                    hash,
+                },
+            );
+        }
+
+        log_action(
+            "Writing",
+            format!("lock file {}", paths.lock_file.log_color_highlight()),
+        );
+        lock.save(&paths.lock_file)?;
+
+        Ok(())
+    }
+
+    async fn wit_deps_update(&mut self, packages: Vec<String>) -> anyhow::Result<()> {
+        let app_ctx = self.ctx.app_context_lock().await;
+        let app_ctx = app_ctx.some_or_err()?;
+
+        let paths = SharedWitDepsPaths::new(&app_ctx.application)?;
+        let mut lock = WitDepsLock::load(&paths.lock_file)?;
+
+        let unknown_packages = packages
+            .iter()
+            .filter(|package| !lock.packages.contains_key(*package))
+            .collect::<Vec<_>>();
+        if !unknown_packages.is_empty() {
+            log_error(format!(
+                "The following packages are not locked: {}",
+                unknown_packages
+                    .iter()
+                    .map(|package| package.log_color_highlight())
+                    .join(", ")
+            ));
+            bail!(NonSuccessfulExit);
+        }
+        let keys = if packages.is_empty() {
+            lock.packages.keys().cloned().collect::<Vec<_>>()
+        } else {
+            packages
+        };
+
+        log_action("Updating", "wit packages from their sources");
+        {
+            let _indent = LogIndent::new();
+            for key in &keys {
+                let entry = lock.packages.get_mut(key).unwrap();
+                let source = paths.root.join(&entry.source);
+                if !source.exists() {
+                    log_warn(format!(
+                        "Source {} of wit package {} does not exist, keeping the locked copy",
+                        source.log_color_highlight(),
+                        key.log_color_highlight()
+                    ));
+                    continue;
+                }
+
+                let temp_dir = tempfile::tempdir()?;
+                let mut package_dir = prepare_package_source(&source, temp_dir.path()).await?;
+                if let Some(source_dir) = &entry.source_dir {
+                    package_dir = package_dir.join(source_dir);
+                }
+                let package = parse_package_dir(&package_dir)?;
+                if &package.key() != key {
+                    bail!(
+                        "Source {} contains wit package {}, expected {}",
+                        package_dir.log_color_error_highlight(),
+                        package.name.to_string().log_color_highlight(),
+                        key.log_color_highlight()
+                    );
+                }
+
+                let hash = package_dir_hash(&package_dir)?;
+                let shared_dir = paths.deps_dir.join(&entry.dir);
+                let shared_up_to_date =
+                    shared_dir.is_dir() && package_dir_hash(&shared_dir)? == hash;
+                if entry.hash == hash && shared_up_to_date {
+                    log_action(
+                        "Skipping",
+                        format!("wit package {}, up to date", key.log_color_highlight()),
+                    );
+                    continue;
+                }
+
+                log_action(
+                    "Updating",
+                    format!(
+                        "wit package {} to {}",
+                        key.log_color_highlight(),
+                        package.name.to_string().log_color_highlight()
+                    ),
+                );
+                let _indent = LogIndent::new();
+                copy_package_dir(&package_dir, &shared_dir)?;
+                entry.version = package.name.version.as_ref().map(|v| v.to_string());
+                entry.hash = hash;
+            }
+        }
+
+        log_action(
+            "Writing",
+            format!("lock file {}", paths.lock_file.log_color_highlight()),
+        );
+        lock.save(&paths.lock_file)?;
+
+        log_action("Syncing", "component copies of wit packages");
+        let _indent = LogIndent::new();
+        let mut synced_any = false;
+        for (component_name, copy) in component_package_copies(app_ctx, &paths.deps_dir)? {
+            let key = copy.key();
+            if !keys.contains(&key) {
+                continue;
+            }
+            let entry = &lock.packages[&key];
+            let shared_dir = paths.deps_dir.join(&entry.dir);
+            if !shared_dir.is_dir() || package_dir_hash(&shared_dir)? != entry.hash {
+                log_warn(format!(
+                    "Shared copy of wit package {} is missing or was modified since it was locked, skipping sync",
+                    key.log_color_highlight()
+                ));
+                continue;
+            }
+            if package_dir_hash(&copy.dir)? == entry.hash {
+                continue;
+            }
+
+            log_action(
+                "Syncing",
+                format!(
+                    "wit package {} of component {}",
+                    copy.name.to_string().log_color_highlight(),
+                    component_name.as_str().log_color_highlight()
+                ),
+            );
+            let _indent = LogIndent::new();
+            copy_package_dir(&shared_dir, &copy.dir)?;
+            synced_any = true;
+        }
+        if !synced_any {
+            log_action("Skipping", "sync, all component copies are up to date");
+        }
+
+        Ok(())
+    }
+
+    async fn wit_deps_list(&mut self) -> anyhow::Result<()> {
+        let views = {
+            let app_ctx = self.ctx.app_context_lock().await;
+            let app_ctx = app_ctx.some_or_err()?;
+
+            let paths = SharedWitDepsPaths::new(&app_ctx.application)?;
+            let lock = WitDepsLock::load(&paths.lock_file)?;
+            let shared_packages = parse_deps_dir(&paths.deps_dir)?;
+            let usages = component_package_usages(app_ctx)?;
+            let copies = component_package_copies(app_ctx, &paths.deps_dir)?;
+
+            let mut views = Vec::new();
+            let mut reference_hashes = Vec::new();
+            for (key, entry) in &lock.packages {
+                let shared_dir = paths.deps_dir.join(&entry.dir);
+                let status = if !shared_dir.is_dir() {
+                    WitDepStatus::Missing
+                } else if package_dir_hash(&shared_dir)? == entry.hash {
+                    WitDepStatus::Locked
+                } else {
+                    WitDepStatus::Modified
+                };
+                views.push(WitDepView {
+                    package: key.clone(),
+                    version: entry.version.clone(),
+                    dir: entry.dir.clone(),
+                    source: Some(entry.source.clone()),
+                    status,
+                    used_by: vec![],
+                    out_of_sync_copies: vec![],
+                });
+                reference_hashes.push(entry.hash.clone());
+            }
+            for package in &shared_packages {
+                let key = package.key();
+                if lock.packages.contains_key(&key) {
+                    continue;
+                }
+                views.push(WitDepView {
+                    package: key,
+                    version: package.name.version.as_ref().map(|v| v.to_string()),
+                    dir: package.dir_name()?,
+                    source: None,
+                    status: WitDepStatus::Unlocked,
+                    used_by: vec![],
+                    out_of_sync_copies: vec![],
+                });
+                reference_hashes.push(package_dir_hash(&package.dir)?);
+            }
+
+            for (view, reference_hash) in views.iter_mut().zip(reference_hashes) {
+                view.used_by = usages
+                    .get(&view.package)
+                    .map(|component_names| {
+                        component_names
+                            .iter()
+                            .map(|component_name| component_name.to_string())
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                for (component_name, copy) in &copies {
+                    if copy.key() == view.package && package_dir_hash(&copy.dir)? != reference_hash
+                    {
+                        view.out_of_sync_copies.push(format!(
+                            "{} ({})",
+                            component_name,
+                            copy.dir.display()
+                        ));
+                    }
+                }
+            }
+
+            views
+        };
+
+        self.ctx.log_handler().log_view(&views);
+
+        Ok(())
+    }
+
     pub async fn build(
         &mut self,
         component_names: Vec<ComponentName>,
@@ -344,6 +739,9 @@ impl AppCommandHandler {
             self.ctx
                 .set_skip_up_to_date_checks(build.force_build.force_build)
                 .await;
+            self.ctx
+                .set_strict_wit_lint(build.strict_wit_lint.strict)
+                .await;
         }
         self.must_select_components(component_names, default_component_select_mode)
             .await?;
@@ -689,3 +1087,42 @@ impl AppCommandHandler {
         Ok(())
     }
 }
+
+struct SharedWitDepsPaths {
+    root: PathBuf,
+    deps_dir: PathBuf,
+    lock_file: PathBuf,
+}
+
+impl SharedWitDepsPaths {
+    /// Uses the first witDeps dir of the application manifest as the shared deps dir, and places
+    /// the lock file next to the manifest defining it
+    fn new(application: &Application) -> anyhow::Result<Self> {
+        let wit_deps = application.wit_deps();
+        let Some(deps_dir) = wit_deps.value.first() else {
+            log_error("No witDeps directories are defined in the application manifest");
+            bail!(NonSuccessfulExit);
+        };
+        Ok(Self {
+            root: wit_deps.source.clone(),
+            deps_dir: wit_deps.source.join(deps_dir),
+            lock_file: wit_deps.source.join(WIT_DEPS_LOCK_FILE_NAME),
+        })
+    }
+
+    /// Sources inside the application are recorded relative to the lock file
+    fn recorded_source(&self, source: &Path) -> String {
+        let source = source
+            .canonicalize()
+            .unwrap_or_else(|_| source.to_path_buf());
+        let root = self
+            .root
+            .canonicalize()
+            .unwrap_or_else(|_| self.root.clone());
+        source
+            .strip_prefix(&root)
+            .unwrap_or(&source)
+            .to_string_lossy()
+            .to_string()
+    }
+}
@@ -12,22 +12,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::app::build::link::explain_link;
 use crate::app::context::ApplicationContext;
+use crate::client_sdk::{generate_client_sdk, ClientLanguage, ClientSdk};
 use crate::cloud::AccountId;
 use crate::command::builtin_app_subcommands;
 use crate::command::component::ComponentSubcommand;
 use crate::command::shared_args::{
     BuildArgs, ComponentOptionalComponentNames, ComponentTemplatePositionalArg, ForceBuildArg,
-    WorkerUpdateOrRedeployArgs,
+    StrictWitLintArg, WitCompatCheckArg, WorkerUpdateOrRedeployArgs,
 };
 use crate::command_handler::component::ifs::IfsArchiveBuilder;
 use crate::command_handler::Handlers;
 use crate::context::{Context, GolemClients};
 use crate::error::service::AnyhowMapServiceError;
 use crate::error::NonSuccessfulExit;
+use crate::fs;
 use crate::log::{log_action, logln, LogColorize, LogIndent};
 use crate::model::app::{
-    AppComponentName, ApplicationComponentSelectMode, BuildProfileName, DynamicHelpSections,
+    AppBuildStep, AppComponentName, ApplicationComponentSelectMode, BuildProfileName,
+    DynamicHelpSections,
 };
 use crate::model::app::{DependencyType, InitialComponentFile};
 use crate::model::component::{Component, ComponentSelection, ComponentView};
@@ -36,11 +40,15 @@ use crate::model::text::component::{ComponentCreateView, ComponentGetView, Compo
 use crate::model::text::fmt::{log_error, log_text_view, log_warn};
 use crate::model::text::help::ComponentNameHelp;
 use crate::model::to_cloud::ToCloud;
+use crate::model::wasm_inspect::WasmInspection;
+use crate::model::wit_compat::{component_exports, WitCompatReport};
 use crate::model::{
     ComponentName, ComponentNameMatchKind, ComponentVersionSelection, ProjectNameAndId,
     SelectedComponents, WorkerUpdateMode,
 };
+use crate::wasm_rpc_stubgen::commands::generate::export_client;
 use anyhow::{anyhow, bail, Context as AnyhowContext};
+use futures_util::{Stream, StreamExt};
 use golem_client::api::ComponentClient as ComponentClientOss;
 use golem_client::model::DynamicLinkedInstance as DynamicLinkedInstanceOss;
 use golem_client::model::DynamicLinkedWasmRpc as DynamicLinkedWasmRpcOss;
@@ -86,10 +94,18 @@ impl ComponentCommandHandler {
             ComponentSubcommand::Deploy {
                 component_name,
                 force_build,
+                strict_wit_lint,
                 update_or_redeploy,
+                wit_compat_check,
             } => {
-                self.cmd_deploy(component_name, force_build, update_or_redeploy)
-                    .await
+                self.cmd_deploy(
+                    component_name,
+                    force_build,
+                    strict_wit_lint,
+                    update_or_redeploy,
+                    wit_compat_check,
+                )
+                .await
             }
             ComponentSubcommand::Clean { component_name } => self.cmd_clean(component_name).await,
             ComponentSubcommand::List { component_name } => {
@@ -120,6 +136,34 @@ impl ComponentCommandHandler {
             ComponentSubcommand::Diagnose { component_name } => {
                 self.cmd_diagnose(component_name).await
             }
+            ComponentSubcommand::GenerateClient {
+                component_name,
+                version,
+                lang,
+                output_dir,
+            } => {
+                self.cmd_generate_client(component_name.component_name, version, lang, output_dir)
+                    .await
+            }
+            ComponentSubcommand::WitCompat {
+                component_name,
+                force_build,
+            } => self.cmd_wit_compat(component_name, force_build).await,
+            ComponentSubcommand::Inspect { target, version } => {
+                self.cmd_inspect(target, version).await
+            }
+            ComponentSubcommand::InspectLink {
+                component_name,
+                dot,
+            } => self.cmd_inspect_link(component_name, dot).await,
+            ComponentSubcommand::ExportClient {
+                component_name,
+                out,
+                with_wasm,
+            } => {
+                self.cmd_export_client(component_name.component_name, out, with_wasm)
+                    .await
+            }
         }
     }
 
@@ -229,7 +273,9 @@ impl ComponentCommandHandler {
         &mut self,
         component_name: ComponentOptionalComponentNames,
         force_build: ForceBuildArg,
+        strict_wit_lint: StrictWitLintArg,
         update_or_redeploy: WorkerUpdateOrRedeployArgs,
+        wit_compat_check: WitCompatCheckArg,
     ) -> anyhow::Result<()> {
         self.deploy(
             self.ctx
@@ -239,12 +285,281 @@ impl ComponentCommandHandler {
                 .as_ref(),
             component_name.component_name,
             Some(force_build),
+            strict_wit_lint,
             &ApplicationComponentSelectMode::CurrentDir,
             update_or_redeploy,
+            wit_compat_check.check_wit_compat,
         )
         .await
     }
 
+    async fn cmd_wit_compat(
+        &mut self,
+        component_name: ComponentOptionalComponentNames,
+        force_build: ForceBuildArg,
+    ) -> anyhow::Result<()> {
+        let project = self
+            .ctx
+            .cloud_project_handler()
+            .opt_select_project(None, None)
+            .await?;
+
+        self.ctx
+            .app_handler()
+            .build(
+                component_name.component_name,
+                Some(BuildArgs {
+                    step: vec![],
+                    force_build,
+                    strict_wit_lint: StrictWitLintArg { strict: false },
+                }),
+                &ApplicationComponentSelectMode::CurrentDir,
+            )
+            .await?;
+
+        let reports = self.wit_compat_reports(project.as_ref()).await?;
+        self.ctx.log_handler().log_view(&reports);
+
+        if reports.iter().any(|report| report.is_breaking()) {
+            logln("");
+            log_error("Found breaking changes in the exported interfaces");
+            bail!(NonSuccessfulExit);
+        }
+
+        Ok(())
+    }
+
+    async fn cmd_inspect(&self, target: String, version: Option<u64>) -> anyhow::Result<()> {
+        let target_path = PathBuf::from(&target);
+        let inspection = if version.is_none() && target_path.is_file() {
+            WasmInspection::new(target.clone(), &fs::read(&target_path)?)?
+        } else {
+            let component_name = ComponentName::from(target.as_str());
+            let selected_components = self
+                .must_select_components_by_app_or_name(Some(&component_name))
+                .await?;
+            let component_name = &selected_components.component_names[0];
+
+            let local_linked_wasm = match version {
+                Some(_) => None,
+                None => {
+                    let app_ctx = self.ctx.app_context_lock().await;
+                    app_ctx.opt()?.and_then(|app_ctx| {
+                        let app_component_name = AppComponentName::from(component_name.0.as_str());
+                        app_ctx
+                            .application
+                            .contains_component(&app_component_name)
+                            .then(|| {
+                                app_ctx.application.component_final_linked_wasm(
+                                    &app_component_name,
+                                    app_ctx.profile(),
+                                )
+                            })
+                            .filter(|linked_wasm| linked_wasm.is_file())
+                    })
+                }
+            };
+
+            match local_linked_wasm {
+                Some(linked_wasm) => WasmInspection::new(
+                    linked_wasm.display().to_string(),
+                    &fs::read(&linked_wasm)?,
+                )?,
+                None => {
+                    let Some(component) = self
+                        .component(
+                            selected_components.project.as_ref(),
+                            component_name.into(),
+                            version.map(ComponentVersionSelection::ByExplicitVersion),
+                        )
+                        .await?
+                    else {
+                        log_error(format!(
+                            "Component {} not found locally or on the server",
+                            component_name.0.log_color_highlight()
+                        ));
+                        bail!(NonSuccessfulExit);
+                    };
+
+                    let wasm = self.download_component_wasm(&component).await?;
+                    WasmInspection::new(
+                        format!(
+                            "{}@{}",
+                            component.component_name, component.versioned_component_id.version
+                        ),
+                        &wasm,
+                    )?
+                }
+            }
+        };
+
+        self.ctx.log_handler().log_view(&inspection);
+
+        Ok(())
+    }
+
+    async fn download_component_wasm(&self, component: &Component) -> anyhow::Result<Vec<u8>> {
+        log_action(
+            "Downloading",
+            format!(
+                "component {} version {}",
+                component.component_name.0.log_color_highlight(),
+                component
+                    .versioned_component_id
+                    .version
+                    .to_string()
+                    .log_color_highlight()
+            ),
+        );
+
+        let component_id = &component.versioned_component_id.component_id;
+        let version = Some(component.versioned_component_id.version);
+        match self.ctx.golem_clients().await? {
+            GolemClients::Oss(clients) => {
+                read_byte_stream(
+                    clients
+                        .component
+                        .download_component(component_id, version)
+                        .await
+                        .map_service_error()?,
+                )
+                .await
+            }
+            GolemClients::Cloud(clients) => {
+                read_byte_stream(
+                    clients
+                        .component
+                        .download_component(component_id, version)
+                        .await
+                        .map_service_error()?,
+                )
+                .await
+            }
+        }
+    }
+
+    async fn cmd_inspect_link(
+        &mut self,
+        component_name: ComponentOptionalComponentNames,
+        dot: bool,
+    ) -> anyhow::Result<()> {
+        self.ctx
+            .app_handler()
+            .build(
+                component_name.component_name,
+                Some(BuildArgs {
+                    step: vec![AppBuildStep::GenRpc, AppBuildStep::Componentize],
+                    force_build: ForceBuildArg { force_build: false },
+                    strict_wit_lint: StrictWitLintArg { strict: false },
+                }),
+                &ApplicationComponentSelectMode::CurrentDir,
+            )
+            .await?;
+
+        let explanations = {
+            let app_ctx = self.ctx.app_context_lock().await;
+            let app_ctx = app_ctx.some_or_err()?;
+            app_ctx
+                .selected_component_names()
+                .iter()
+                .map(|component_name| explain_link(app_ctx, component_name))
+                .collect::<anyhow::Result<Vec<_>>>()?
+        };
+
+        if dot {
+            for explanation in &explanations {
+                print!("{}", explanation.to_dot());
+            }
+        } else {
+            self.ctx.log_handler().log_view(&explanations);
+        }
+
+        Ok(())
+    }
+
+    async fn cmd_export_client(
+        &mut self,
+        component_name: Option<ComponentName>,
+        out: PathBuf,
+        with_wasm: bool,
+    ) -> anyhow::Result<()> {
+        self.ctx
+            .app_handler()
+            .build(
+                component_name.into_iter().collect(),
+                Some(BuildArgs {
+                    step: vec![AppBuildStep::GenRpc],
+                    force_build: ForceBuildArg { force_build: false },
+                    strict_wit_lint: StrictWitLintArg { strict: false },
+                }),
+                &ApplicationComponentSelectMode::CurrentDir,
+            )
+            .await?;
+
+        let app_ctx = self.ctx.app_context_lock().await;
+        let app_ctx = app_ctx.some_or_err()?;
+
+        let selected_component_names = app_ctx.selected_component_names();
+        if selected_component_names.len() != 1 {
+            log_error("A client can only be exported for a single component!");
+            logln("");
+            logln(format!(
+                "Selected components: {}",
+                selected_component_names
+                    .iter()
+                    .map(|cn| cn.as_str().log_color_highlight())
+                    .join(", ")
+            ));
+            logln("");
+            logln("Specify the requested component name or switch to a component directory!");
+            logln("");
+            bail!(NonSuccessfulExit);
+        }
+        let component_name = selected_component_names.iter().next().unwrap();
+
+        let out = app_ctx.calling_working_dir.join(out);
+        fs::create_dir_all(&out)?;
+        let out = out.canonicalize().with_context(|| {
+            anyhow!(
+                "Failed to canonicalize client output dir {}",
+                out.log_color_error_highlight()
+            )
+        })?;
+        let stub_def = app_ctx.component_exported_client_stub_def(component_name, &out)?;
+
+        log_action(
+            "Exporting",
+            format!(
+                "client of component {} (version {}) to {}",
+                component_name.as_str().log_color_highlight(),
+                stub_def.config.stub_crate_version.log_color_highlight(),
+                out.log_color_highlight()
+            ),
+        );
+        let _indent = LogIndent::new();
+
+        if let Some(wasm_path) = export_client(&stub_def, with_wasm, app_ctx.config.offline).await?
+        {
+            log_action(
+                "Exported",
+                format!("client WASM to {}", wasm_path.log_color_highlight()),
+            );
+        }
+        log_action(
+            "Exported",
+            format!(
+                "client crate {} and WIT package {}",
+                stub_def.client_crate_name().log_color_highlight(),
+                stub_def
+                    .client_parser_package_name()
+                    .to_string()
+                    .log_color_highlight()
+            ),
+        );
+
+        Ok(())
+    }
+
     fn cmd_templates(&self, filter: Option<String>) {
         match filter {
             Some(filter) => {
@@ -563,13 +878,95 @@ impl ComponentCommandHandler {
             .await
     }
 
+    async fn cmd_generate_client(
+        &self,
+        component_name: Option<ComponentName>,
+        version: Option<u64>,
+        language: ClientLanguage,
+        output_dir: PathBuf,
+    ) -> anyhow::Result<()> {
+        let selected_components = self
+            .must_select_components_by_app_or_name(component_name.as_ref())
+            .await?;
+
+        if selected_components.component_names.len() != 1 {
+            log_error("A client can only be generated for a single component!");
+            logln("");
+            logln(format!(
+                "Selected components: {}",
+                selected_components
+                    .component_names
+                    .iter()
+                    .map(|cn| cn.0.log_color_highlight())
+                    .join(", ")
+            ));
+            logln("");
+            logln("Specify the requested component name or switch to an application directory with exactly one component!");
+            logln("");
+            bail!(NonSuccessfulExit);
+        }
+        let component_name = &selected_components.component_names[0];
+
+        let Some(component) = self
+            .component(
+                selected_components.project.as_ref(),
+                component_name.into(),
+                version.map(ComponentVersionSelection::ByExplicitVersion),
+            )
+            .await?
+        else {
+            log_error(format!(
+                "Component {} not found, deploy it first",
+                component_name.0.log_color_highlight()
+            ));
+            bail!(NonSuccessfulExit);
+        };
+
+        log_action(
+            "Generating",
+            format!(
+                "{} client for {} (version {}) to {}",
+                language,
+                component.component_name.0.log_color_highlight(),
+                component.versioned_component_id.version,
+                output_dir.log_color_highlight()
+            ),
+        );
+        let _indent = LogIndent::new();
+
+        let sdk = ClientSdk::new(
+            &component.component_name.0,
+            component.versioned_component_id.component_id,
+            &component.metadata.exports,
+        );
+        for file in generate_client_sdk(&sdk, language) {
+            let path = output_dir.join(&file.path);
+            log_action("Writing", format!("file {}", path.log_color_highlight()));
+            fs::write_str(&path, &file.content)?;
+        }
+
+        if !sdk.skipped_functions.is_empty() {
+            log_warn(format!(
+                "Skipped functions using resources, which are not supported by the generated clients: {}",
+                sdk.skipped_functions
+                    .iter()
+                    .map(|name| name.log_color_highlight())
+                    .join(", ")
+            ));
+        }
+
+        Ok(())
+    }
+
     pub async fn deploy(
         &mut self,
         project: Option<&ProjectNameAndId>,
         component_names: Vec<ComponentName>,
         force_build: Option<ForceBuildArg>,
+        strict_wit_lint: StrictWitLintArg,
         default_component_select_mode: &ApplicationComponentSelectMode,
         update_or_redeploy: WorkerUpdateOrRedeployArgs,
+        check_wit_compat: bool,
     ) -> anyhow::Result<()> {
         self.ctx
             .app_handler()
@@ -578,11 +975,22 @@ impl ComponentCommandHandler {
                 force_build.map(|force_build| BuildArgs {
                     step: vec![],
                     force_build,
+                    strict_wit_lint,
                 }),
                 default_component_select_mode,
             )
             .await?;
 
+        if check_wit_compat {
+            let reports = self.wit_compat_reports(project).await?;
+            if reports.iter().any(|report| report.is_breaking()) {
+                self.ctx.log_handler().log_view(&reports);
+                logln("");
+                log_error("Found breaking changes in the exported interfaces, not deploying");
+                bail!(NonSuccessfulExit);
+            }
+        }
+
         // TODO: hash <-> version check for skipping deploy
 
         let selected_component_names = {
@@ -630,6 +1038,66 @@ impl ComponentCommandHandler {
         Ok(())
     }
 
+    /// Compares the linked WASM exports of the selected, already built components with their
+    /// latest deployed versions. Components that were never deployed are skipped.
+    async fn wit_compat_reports(
+        &self,
+        project: Option<&ProjectNameAndId>,
+    ) -> anyhow::Result<Vec<WitCompatReport>> {
+        let build_profile = self.ctx.build_profile().cloned();
+        let linked_wasm_paths = {
+            let app_ctx = self.ctx.app_context_lock().await;
+            let app_ctx = app_ctx.some_or_err()?;
+            app_ctx
+                .selected_component_names()
+                .iter()
+                .filter(|component_name| {
+                    app_ctx
+                        .application
+                        .component_properties(component_name, build_profile.as_ref())
+                        .is_deployable()
+                })
+                .map(|component_name| {
+                    (
+                        component_name.clone(),
+                        app_ctx
+                            .application
+                            .component_final_linked_wasm(component_name, build_profile.as_ref()),
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let mut reports = Vec::with_capacity(linked_wasm_paths.len());
+        for (component_name, linked_wasm_path) in linked_wasm_paths {
+            let deployed_component_name: ComponentName = component_name.as_str().into();
+            let Some(component) = self
+                .component(project, (&deployed_component_name).into(), None)
+                .await?
+            else {
+                continue;
+            };
+
+            let local_exports =
+                component_exports(&fs::read(&linked_wasm_path)?).with_context(|| {
+                    anyhow!(
+                        "Failed to get exports of component {} from {}",
+                        component_name.as_str().log_color_highlight(),
+                        linked_wasm_path.log_color_highlight()
+                    )
+                })?;
+
+            reports.push(WitCompatReport::new(
+                component_name.to_string(),
+                component.versioned_component_id.version,
+                &component.metadata.exports,
+                &local_exports,
+            ));
+        }
+
+        Ok(reports)
+    }
+
     async fn deploy_component(
         &mut self,
         build_profile: Option<&BuildProfileName>,
@@ -1094,8 +1562,10 @@ impl ComponentCommandHandler {
                             project,
                             vec![component_name.clone()],
                             None,
+                            StrictWitLintArg { strict: false },
                             &ApplicationComponentSelectMode::CurrentDir,
                             WorkerUpdateOrRedeployArgs::default(),
+                            false,
                         )
                         .await?;
                     self.ctx
@@ -1230,6 +1700,19 @@ impl ComponentCommandHandler {
     }
 }
 
+async fn read_byte_stream<S, B, E>(mut stream: S) -> anyhow::Result<Vec<u8>>
+where
+    S: Stream<Item = Result<B, E>> + Unpin,
+    B: AsRef<[u8]>,
+    E: std::error::Error + Send + Sync + 'static,
+{
+    let mut bytes = Vec::new();
+    while let Some(chunk) = stream.next().await {
+        bytes.extend_from_slice(chunk?.as_ref());
+    }
+    Ok(bytes)
+}
+
 struct ComponentDeployProperties {
     component_type: ComponentType,
     linked_wasm_path: PathBuf,
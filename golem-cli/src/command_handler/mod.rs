@@ -19,6 +19,7 @@ use crate::command::{
     GolemCliCommand, GolemCliCommandParseResult, GolemCliFallbackCommand, GolemCliGlobalFlags,
     GolemCliSubcommand,
 };
+use crate::command_handler::api::call::ApiCallCommandHandler;
 use crate::command_handler::api::cloud::certificate::ApiCloudCertificateCommandHandler;
 use crate::command_handler::api::cloud::domain::ApiCloudDomainCommandHandler;
 use crate::command_handler::api::cloud::ApiCloudCommandHandler;
@@ -50,6 +51,8 @@ use crate::error::{ContextInitHintError, HintError, NonSuccessfulExit};
 use crate::log::{logln, set_log_output, Output};
 use crate::model::text::fmt::log_error;
 use crate::{command_name, init_tracing};
+#[cfg(feature = "server-commands")]
+use crate::{init_tracing_with_layers, TracingLayer};
 use anyhow::anyhow;
 use clap::CommandFactory;
 use clap_complete::Shell;
@@ -84,10 +87,14 @@ pub trait CommandHandlerHooks {
     ) -> impl std::future::Future<Output = anyhow::Result<()>>;
 
     #[cfg(feature = "server-commands")]
-    fn override_verbosity(verbosity: Verbosity) -> Verbosity;
+    fn override_verbosity(verbosity: Verbosity, subcommand: &ServerSubcommand) -> Verbosity;
 
     #[cfg(feature = "server-commands")]
     fn override_pretty_mode() -> bool;
+
+    /// Additional tracing layers for the server commands, e.g. for exporting traces
+    #[cfg(feature = "server-commands")]
+    fn tracing_layers(subcommand: &ServerSubcommand) -> Vec<TracingLayer>;
 }
 
 // CommandHandler is responsible for matching commands and producing CLI output using Context,
@@ -146,14 +153,16 @@ impl<Hooks: CommandHandlerHooks> CommandHandler<Hooks> {
         let result = match GolemCliCommand::try_parse_from_lenient(args_iterator, true) {
             GolemCliCommandParseResult::FullMatch(command) => {
                 #[cfg(feature = "server-commands")]
-                let verbosity = if matches!(command.subcommand, GolemCliSubcommand::Server { .. }) {
-                    Hooks::override_verbosity(command.global_flags.verbosity())
-                } else {
-                    command.global_flags.verbosity()
+                match &command.subcommand {
+                    GolemCliSubcommand::Server { subcommand } => init_tracing_with_layers(
+                        Hooks::override_verbosity(command.global_flags.verbosity(), subcommand),
+                        false,
+                        Hooks::tracing_layers(subcommand),
+                    ),
+                    _ => init_tracing(command.global_flags.verbosity(), false),
                 };
                 #[cfg(not(feature = "server-commands"))]
-                let verbosity = command.global_flags.verbosity();
-                init_tracing(verbosity, false);
+                init_tracing(command.global_flags.verbosity(), false);
 
                 match Self::new_with_init_hint_error_handler(&command.global_flags, hooks) {
                     Ok(mut handler) => {
@@ -303,6 +312,7 @@ impl<Hooks: CommandHandlerHooks> CommandHandler<Hooks> {
 //       by moving these simple factory methods into the specific handlers on demand,
 //       if the need ever arises
 pub trait Handlers {
+    fn api_call_handler(&self) -> ApiCallCommandHandler;
     fn api_cloud_certificate_handler(&self) -> ApiCloudCertificateCommandHandler;
     fn api_cloud_domain_handler(&self) -> ApiCloudDomainCommandHandler;
     fn api_cloud_handler(&self) -> ApiCloudCommandHandler;
@@ -331,6 +341,10 @@ pub trait Handlers {
 }
 
 impl Handlers for Arc<Context> {
+    fn api_call_handler(&self) -> ApiCallCommandHandler {
+        ApiCallCommandHandler::new(self.clone())
+    }
+
     fn api_cloud_certificate_handler(&self) -> ApiCloudCertificateCommandHandler {
         ApiCloudCertificateCommandHandler::new(self.clone())
     }
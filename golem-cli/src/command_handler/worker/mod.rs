@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-mod stream;
+pub mod stream;
 mod stream_output;
 
 use crate::cloud::AccountId;
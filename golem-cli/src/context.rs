@@ -23,6 +23,7 @@ use crate::error::HintError;
 use crate::log::{set_log_output, LogOutput, Output};
 use crate::model::app::{AppBuildStep, ApplicationSourceMode};
 use crate::model::app::{ApplicationConfig, BuildProfileName as AppBuildProfileName};
+use crate::model::trace_context::{TraceContext, TRACEPARENT_HEADER};
 use crate::model::{Format, HasFormatConfig};
 use crate::wasm_rpc_stubgen::stub::RustDependencyOverride;
 use anyhow::anyhow;
@@ -54,6 +55,7 @@ use golem_cloud_client::{Context as ContextCloud, Security};
 use golem_rib_repl::ReplDependencies;
 use golem_templates::model::{ComposableAppGroupName, GuestLanguage};
 use golem_templates::ComposableAppTemplate;
+use reqwest::header::{HeaderMap, HeaderValue};
 use std::collections::{BTreeMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
@@ -199,6 +201,10 @@ impl Context {
         Ok(self.clients().await?.file_download.clone())
     }
 
+    pub async fn api_gateway_client(&self) -> anyhow::Result<reqwest::Client> {
+        Ok(self.clients().await?.api_gateway.clone())
+    }
+
     pub async fn golem_clients_cloud(&self) -> anyhow::Result<&GolemClientsCloud> {
         match &self.clients().await?.golem {
             GolemClients::Oss(_) => Err(anyhow!(HintError::ExpectedCloudProfile)),
@@ -289,6 +295,16 @@ impl Context {
         .await;
     }
 
+    pub async fn set_strict_wit_lint(&self, strict: bool) {
+        self.set_app_ctx_init_config(
+            "strict_wit_lint",
+            |ctx| &mut ctx.strict_wit_lint,
+            |ctx| &mut ctx.strict_wit_lint_was_set,
+            strict,
+        )
+        .await
+    }
+
     pub async fn set_rib_repl_dependencies(&self, dependencies: ReplDependencies) {
         let mut rib_repl_state = self.rib_repl_state.write().await;
         rib_repl_state.dependencies = dependencies;
@@ -313,6 +329,7 @@ impl Context {
 pub struct Clients {
     pub golem: GolemClients,
     pub file_download: reqwest::Client,
+    pub api_gateway: reqwest::Client,
 }
 
 impl Clients {
@@ -323,10 +340,15 @@ impl Clients {
         auth_config: Option<&CloudAuthenticationConfig>,
         config_dir: &Path,
     ) -> anyhow::Result<Self> {
-        let service_http_client = new_reqwest_client(&config.service_http_client_config)?;
-        let invoke_http_client = new_reqwest_client(&config.invoke_http_client_config)?;
+        let trace_context = TraceContext::from_env_or_new();
+        debug!(traceparent = %trace_context, "Trace context");
+
+        let service_http_client =
+            new_reqwest_client(&config.service_http_client_config, &trace_context)?;
+        let invoke_http_client =
+            new_reqwest_client(&config.invoke_http_client_config, &trace_context)?;
         let file_download_http_client =
-            new_reqwest_client(&config.file_download_http_client_config)?;
+            new_reqwest_client(&config.file_download_http_client_config, &trace_context)?;
 
         match &config.cloud_url {
             Some(cloud_url) => {
@@ -432,6 +454,7 @@ impl Clients {
                         },
                     }),
                     file_download: file_download_http_client,
+                    api_gateway: invoke_http_client.clone(),
                 })
             }
             None => {
@@ -475,6 +498,7 @@ impl Clients {
                         },
                     }),
                     file_download: file_download_http_client,
+                    api_gateway: invoke_http_client.clone(),
                 })
             }
         }
@@ -544,6 +568,8 @@ pub struct ApplicationContextState {
     skip_up_to_date_checks_was_set: bool,
     pub build_steps_filter: HashSet<AppBuildStep>,
     build_steps_filter_was_set: bool,
+    pub strict_wit_lint: bool,
+    strict_wit_lint_was_set: bool,
 
     app_context: Option<Result<Option<ApplicationContext>, Arc<anyhow::Error>>>,
 }
@@ -575,6 +601,7 @@ impl ApplicationContextState {
             profile: config.build_profile.as_ref().map(|p| p.to_string().into()),
             offline: config.wasm_rpc_client_build_offline,
             steps_filter: self.build_steps_filter.clone(),
+            strict_wit_lint: self.strict_wit_lint,
             golem_rust_override: config.golem_rust_override.clone(),
         };
 
@@ -634,9 +661,19 @@ impl Default for RibReplState {
     }
 }
 
-fn new_reqwest_client(config: &HttpClientConfig) -> anyhow::Result<reqwest::Client> {
+fn new_reqwest_client(
+    config: &HttpClientConfig,
+    trace_context: &TraceContext,
+) -> anyhow::Result<reqwest::Client> {
     let mut builder = reqwest::Client::builder();
 
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        TRACEPARENT_HEADER,
+        HeaderValue::from_str(&trace_context.to_string())?,
+    );
+    builder = builder.default_headers(headers);
+
     if config.allow_insecure {
         builder = builder.danger_accept_invalid_certs(true);
     }
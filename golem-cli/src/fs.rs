@@ -120,6 +120,34 @@ pub fn write_str<P: AsRef<Path>, S: AsRef<str>>(path: P, str: S) -> anyhow::Resu
     std::fs::write(&path, str.as_bytes()).with_context(context)
 }
 
+/// Writes secrets, like private keys: on unix the file is only readable and writable by the owner,
+/// also when it already existed
+pub fn write_str_owner_only<P: AsRef<Path>, S: AsRef<str>>(path: P, str: S) -> anyhow::Result<()> {
+    let path = PathExtra(path);
+    let str = str.as_ref();
+
+    let context = || anyhow!("Failed to write string to {}", path.log_color_highlight());
+
+    let target_parent = path.parent().with_context(context)?;
+    create_dir_all(target_parent).with_context(context)?;
+
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+        options.mode(0o600);
+        if path.exists() {
+            std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600))
+                .with_context(context)?;
+        }
+    }
+    options
+        .open(&path)
+        .and_then(|mut file| file.write_all(str.as_bytes()))
+        .with_context(context)
+}
+
 pub fn append_str<P: AsRef<Path>, S: AsRef<str>>(path: P, str: S) -> anyhow::Result<()> {
     let path = PathExtra(path);
     let str = str.as_ref();
@@ -547,7 +575,7 @@ pub fn compile_and_collect_globs(root_dir: &Path, globs: &[String]) -> Result<Ve
 
 #[cfg(test)]
 mod test {
-    use crate::fs::resolve_relative_glob;
+    use crate::fs::{resolve_relative_glob, write_str_owner_only};
     use assert2::check;
     use std::path::PathBuf;
     use test_r::test;
@@ -570,4 +598,23 @@ mod test {
                 == (base_dir.join("../../../"), "target/a".to_string())
         );
     }
+
+    #[cfg(unix)]
+    #[test]
+    fn write_str_owner_only_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("secret").join("domain.key");
+
+        write_str_owner_only(&path, "first").unwrap();
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        check!(mode & 0o777 == 0o600);
+
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644)).unwrap();
+        write_str_owner_only(&path, "second").unwrap();
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        check!(mode & 0o777 == 0o600);
+        check!(std::fs::read_to_string(&path).unwrap() == "second");
+    }
 }
@@ -14,10 +14,14 @@
 
 use clap_verbosity_flag::Verbosity;
 use shadow_rs::shadow;
-use tracing_subscriber::FmtSubscriber;
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{Layer, Registry};
 
 pub mod app;
 pub mod auth;
+pub mod client_sdk;
 pub mod cloud;
 pub mod command;
 pub mod command_handler;
@@ -55,27 +59,36 @@ pub fn version() -> &'static str {
     }
 }
 
+/// Additional tracing layer, e.g. for exporting spans, registered next to the log output
+pub type TracingLayer = Box<dyn Layer<Registry> + Send + Sync>;
+
 pub fn init_tracing(verbosity: Verbosity, pretty_mode: bool) {
+    init_tracing_with_layers(verbosity, pretty_mode, vec![])
+}
+
+pub fn init_tracing_with_layers(
+    verbosity: Verbosity,
+    pretty_mode: bool,
+    layers: Vec<TracingLayer>,
+) {
     if let Some(level) = verbosity.tracing_level() {
-        let subscriber = FmtSubscriber::builder();
-        if pretty_mode {
-            let subscriber = subscriber
+        let fmt_layer = if pretty_mode {
+            tracing_subscriber::fmt::layer()
                 .pretty()
-                .with_max_level(level)
                 .with_writer(std::io::stderr)
-                .finish();
-
-            tracing::subscriber::set_global_default(subscriber)
-                .expect("setting default subscriber failed");
+                .boxed()
         } else {
-            let subscriber = subscriber
-                .with_max_level(level)
+            tracing_subscriber::fmt::layer()
                 .with_writer(std::io::stderr)
-                .finish();
-
-            tracing::subscriber::set_global_default(subscriber)
-                .expect("setting default subscriber failed");
+                .boxed()
         };
+
+        tracing_subscriber::registry()
+            .with(layers)
+            .with(fmt_layer)
+            .with(LevelFilter::from_level(level))
+            .try_init()
+            .expect("setting default subscriber failed");
     }
 }
 
@@ -22,6 +22,7 @@ mod hooks {
     use golem_cli::command::server::ServerSubcommand;
     use golem_cli::command_handler::CommandHandlerHooks;
     use golem_cli::context::Context;
+    use golem_cli::TracingLayer;
 
     use clap_verbosity_flag::Verbosity;
     use std::sync::Arc;
@@ -39,7 +40,7 @@ mod hooks {
         }
 
         #[cfg(feature = "server-commands")]
-        fn override_verbosity(verbosity: Verbosity) -> Verbosity {
+        fn override_verbosity(verbosity: Verbosity, _subcommand: &ServerSubcommand) -> Verbosity {
             verbosity
         }
 
@@ -47,6 +48,11 @@ mod hooks {
         fn override_pretty_mode() -> bool {
             false
         }
+
+        #[cfg(feature = "server-commands")]
+        fn tracing_layers(_subcommand: &ServerSubcommand) -> Vec<TracingLayer> {
+            vec![]
+        }
     }
 }
 
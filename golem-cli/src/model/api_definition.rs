@@ -0,0 +1,344 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::Context;
+use golem_client::model::{HttpApiDefinitionResponseData, RouteResponseData};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+
+/// Binding properties which are accepted when creating or updating API definitions,
+/// the rest of the response binding fields are derived (e.g. inferred Rib types).
+const REQUEST_BINDING_FIELDS: &[&str] = &[
+    "bindingType",
+    "workerName",
+    "idempotencyKey",
+    "response",
+    "invocationContext",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RouteKey {
+    pub method: String,
+    pub path: String,
+}
+
+impl From<&RouteResponseData> for RouteKey {
+    fn from(route: &RouteResponseData) -> Self {
+        Self {
+            method: route.method.to_string(),
+            path: route.path.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RouteFieldChange {
+    pub field: String,
+    pub from: Option<String>,
+    pub to: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RouteChange {
+    #[serde(flatten)]
+    pub route: RouteKey,
+    pub changes: Vec<RouteFieldChange>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiDefinitionDiff {
+    pub id: String,
+    pub from_version: String,
+    pub to_version: String,
+    pub added: Vec<RouteKey>,
+    pub removed: Vec<RouteKey>,
+    pub changed: Vec<RouteChange>,
+}
+
+impl ApiDefinitionDiff {
+    pub fn new(
+        from: &HttpApiDefinitionResponseData,
+        to: &HttpApiDefinitionResponseData,
+    ) -> anyhow::Result<Self> {
+        let from_routes = route_fields_by_key(from)?;
+        let to_routes = route_fields_by_key(to)?;
+
+        let added = to_routes
+            .keys()
+            .filter(|key| !from_routes.contains_key(key))
+            .cloned()
+            .collect();
+
+        let removed = from_routes
+            .keys()
+            .filter(|key| !to_routes.contains_key(key))
+            .cloned()
+            .collect();
+
+        let changed = from_routes
+            .iter()
+            .filter_map(|(key, from_fields)| {
+                let to_fields = to_routes.get(key)?;
+                let changes = diff_fields(from_fields, to_fields);
+                (!changes.is_empty()).then(|| RouteChange {
+                    route: key.clone(),
+                    changes,
+                })
+            })
+            .collect();
+
+        Ok(Self {
+            id: to.id.clone(),
+            from_version: from.version.clone(),
+            to_version: to.version.clone(),
+            added,
+            removed,
+            changed,
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+fn route_fields_by_key(
+    definition: &HttpApiDefinitionResponseData,
+) -> anyhow::Result<BTreeMap<RouteKey, BTreeMap<String, String>>> {
+    definition
+        .routes
+        .iter()
+        .map(|route| Ok((RouteKey::from(route), route_fields(route)?)))
+        .collect()
+}
+
+/// Flattens the comparable properties of a route (binding, Rib expressions and security)
+fn route_fields(route: &RouteResponseData) -> anyhow::Result<BTreeMap<String, String>> {
+    let mut fields = BTreeMap::new();
+
+    if let Some(security) = &route.security {
+        fields.insert("security".to_string(), security.clone());
+    }
+
+    if let Some(component) = &route.binding.component {
+        fields.insert(
+            "component".to_string(),
+            format!("{}@{}", component.name, component.version),
+        );
+    }
+
+    let binding =
+        serde_json::to_value(&route.binding).context("Failed to serialize route binding")?;
+    if let Value::Object(binding) = binding {
+        for field in REQUEST_BINDING_FIELDS {
+            match binding.get(*field) {
+                None | Some(Value::Null) => {}
+                Some(Value::String(value)) => {
+                    fields.insert(field.to_string(), value.clone());
+                }
+                Some(value) => {
+                    fields.insert(field.to_string(), value.to_string());
+                }
+            }
+        }
+    }
+
+    Ok(fields)
+}
+
+fn diff_fields(
+    from: &BTreeMap<String, String>,
+    to: &BTreeMap<String, String>,
+) -> Vec<RouteFieldChange> {
+    let mut field_names = from.keys().chain(to.keys()).collect::<Vec<_>>();
+    field_names.sort();
+    field_names.dedup();
+
+    field_names
+        .into_iter()
+        .filter_map(|field| {
+            let from = from.get(field);
+            let to = to.get(field);
+            (from != to).then(|| RouteFieldChange {
+                field: field.clone(),
+                from: from.cloned(),
+                to: to.cloned(),
+            })
+        })
+        .collect()
+}
+
+/// Converts a stored API definition to the JSON form of a create request with a new version.
+///
+/// The result can be deserialized into both the OSS and the Cloud `HttpApiDefinitionRequest`.
+pub fn api_definition_request_json(
+    definition: &HttpApiDefinitionResponseData,
+    version: &str,
+    draft: bool,
+) -> anyhow::Result<Value> {
+    let routes = definition
+        .routes
+        .iter()
+        .map(|route| {
+            let binding = serde_json::to_value(&route.binding)
+                .context("Failed to serialize route binding")?;
+            Ok(route_request_json(
+                &route.method.to_string(),
+                &route.path,
+                &binding,
+                route
+                    .binding
+                    .component
+                    .as_ref()
+                    .map(|component| (component.name.as_str(), component.version)),
+                route.security.as_deref(),
+            ))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let mut request = Map::new();
+    request.insert("id".to_string(), definition.id.clone().into());
+    request.insert("version".to_string(), version.into());
+    request.insert("draft".to_string(), draft.into());
+    request.insert("routes".to_string(), Value::Array(routes));
+
+    Ok(Value::Object(request))
+}
+
+/// Builds a request route from the serialized response binding, keeping only the request
+/// binding fields, and binding the component with the nested `{name, version}` form
+fn route_request_json(
+    method: &str,
+    path: &str,
+    binding: &Value,
+    component: Option<(&str, u64)>,
+    security: Option<&str>,
+) -> Value {
+    let mut request_binding = Map::new();
+    if let Value::Object(binding) = binding {
+        for field in REQUEST_BINDING_FIELDS {
+            if let Some(value) = binding.get(*field).filter(|value| !value.is_null()) {
+                request_binding.insert(field.to_string(), value.clone());
+            }
+        }
+    }
+    if let Some((name, version)) = component {
+        request_binding.insert(
+            "component".to_string(),
+            json!({ "name": name, "version": version }),
+        );
+    }
+
+    let mut request_route = Map::new();
+    request_route.insert("method".to_string(), method.into());
+    request_route.insert("path".to_string(), path.into());
+    request_route.insert("binding".to_string(), Value::Object(request_binding));
+    if let Some(security) = security {
+        request_route.insert("security".to_string(), security.into());
+    }
+
+    Value::Object(request_route)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::model::api_definition::{diff_fields, route_request_json};
+    use assert2::assert;
+    use golem_client::model::HttpApiDefinitionRequest as HttpApiDefinitionRequestOss;
+    use golem_cloud_client::model::HttpApiDefinitionRequest as HttpApiDefinitionRequestCloud;
+    use serde_json::json;
+    use std::collections::BTreeMap;
+    use test_r::test;
+
+    fn request_json() -> serde_json::Value {
+        let response_binding = json!({
+            "bindingType": "default",
+            "component": { "name": "shopping-cart", "version": 2, "componentId": "ignored" },
+            "workerName": "\"cart\"",
+            "response": "let result = golem:it/api.{get-cart}(); {status: 200u64, body: result}",
+            "responseMappingInput": { "types": {} },
+        });
+
+        json!({
+            "id": "shopping-cart-api",
+            "version": "0.0.2",
+            "draft": true,
+            "routes": [
+                route_request_json(
+                    "Get",
+                    "/cart",
+                    &response_binding,
+                    Some(("shopping-cart", 2)),
+                    None,
+                )
+            ],
+        })
+    }
+
+    #[test]
+    fn request_json_binds_component_in_oss_request() {
+        let request: HttpApiDefinitionRequestOss = serde_json::from_value(request_json()).unwrap();
+
+        let component = request.routes[0].binding.component.as_ref().unwrap();
+        assert!(component.name == "shopping-cart");
+        assert!(component.version == Some(2));
+    }
+
+    #[test]
+    fn request_json_binds_component_in_cloud_request() {
+        let request: HttpApiDefinitionRequestCloud =
+            serde_json::from_value(request_json()).unwrap();
+
+        let component = request.routes[0].binding.component.as_ref().unwrap();
+        assert!(component.name == "shopping-cart");
+        assert!(component.version == Some(2));
+    }
+
+    #[test]
+    fn diff_fields_reports_added_removed_and_changed_fields() {
+        let from = BTreeMap::from([
+            ("component".to_string(), "shopping-cart@1".to_string()),
+            ("response".to_string(), "let x = 1; x".to_string()),
+            ("security".to_string(), "google".to_string()),
+        ]);
+        let to = BTreeMap::from([
+            ("component".to_string(), "shopping-cart@2".to_string()),
+            ("response".to_string(), "let x = 1; x".to_string()),
+            ("workerName".to_string(), "\"cart\"".to_string()),
+        ]);
+
+        let changes = diff_fields(&from, &to)
+            .into_iter()
+            .map(|change| (change.field, change.from, change.to))
+            .collect::<Vec<_>>();
+
+        assert!(
+            changes
+                == vec![
+                    (
+                        "component".to_string(),
+                        Some("shopping-cart@1".to_string()),
+                        Some("shopping-cart@2".to_string())
+                    ),
+                    ("security".to_string(), Some("google".to_string()), None),
+                    ("workerName".to_string(), None, Some("\"cart\"".to_string())),
+                ]
+        );
+    }
+}
@@ -0,0 +1,153 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+/// Matches a request path against a Golem route path pattern, returning the bound path variables.
+///
+/// Supports literal segments, `{var}` segment variables and a trailing `{+var}` catch-all.
+/// Query variables in the pattern (`?{var}`) and the query part of the path are ignored.
+pub fn match_route_path(pattern: &str, path: &str) -> Option<BTreeMap<String, String>> {
+    let pattern = pattern.split_once('?').map(|(p, _)| p).unwrap_or(pattern);
+    let path = path.split_once('?').map(|(p, _)| p).unwrap_or(path);
+
+    let pattern_segments = path_segments(pattern);
+    let path_segments = path_segments(path);
+
+    let mut vars = BTreeMap::new();
+    for (idx, pattern_segment) in pattern_segments.iter().enumerate() {
+        match pattern_segment
+            .strip_prefix('{')
+            .and_then(|segment| segment.strip_suffix('}'))
+        {
+            Some(var) => match var.strip_prefix('+') {
+                Some(catch_all_var) => {
+                    vars.insert(
+                        catch_all_var.to_string(),
+                        path_segments.get(idx..).unwrap_or_default().join("/"),
+                    );
+                    return Some(vars);
+                }
+                None => {
+                    vars.insert(var.to_string(), path_segments.get(idx)?.to_string());
+                }
+            },
+            None => {
+                if path_segments.get(idx) != Some(pattern_segment) {
+                    return None;
+                }
+            }
+        }
+    }
+
+    (pattern_segments.len() == path_segments.len()).then_some(vars)
+}
+
+/// Number of literal segments, used to prefer the most specific route when multiple ones match
+pub fn route_path_specificity(pattern: &str) -> usize {
+    let pattern = pattern.split_once('?').map(|(p, _)| p).unwrap_or(pattern);
+    path_segments(pattern)
+        .iter()
+        .filter(|segment| !segment.starts_with('{'))
+        .count()
+}
+
+/// Tries to statically resolve the worker name Rib expression of a binding.
+///
+/// Only string literals (optionally interpolating `request.path` variables) and plain
+/// `request.path.<var>` references are supported, as these cover the common cases without
+/// the need of evaluating Rib.
+pub fn resolve_worker_name(
+    worker_name_expr: &str,
+    path_vars: &BTreeMap<String, String>,
+) -> Option<String> {
+    let expr = worker_name_expr.trim();
+
+    if let Some(var) = expr.strip_prefix("request.path.") {
+        return path_vars.get(var).cloned();
+    }
+
+    let literal = expr.strip_prefix('"')?.strip_suffix('"')?;
+
+    let mut result = String::with_capacity(literal.len());
+    let mut rest = literal;
+    while let Some(start) = rest.find("${") {
+        result.push_str(&rest[..start]);
+        let end = start + rest[start..].find('}')?;
+        let var = rest[start + 2..end].trim().strip_prefix("request.path.")?;
+        result.push_str(path_vars.get(var)?);
+        rest = &rest[end + 1..];
+    }
+    result.push_str(rest);
+
+    Some(result)
+}
+
+fn path_segments(path: &str) -> Vec<&str> {
+    path.split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::model::api_route::{match_route_path, resolve_worker_name, route_path_specificity};
+    use assert2::assert;
+    use std::collections::BTreeMap;
+    use test_r::test;
+
+    #[test]
+    fn match_literal_route() {
+        assert!(match_route_path("/health", "/health") == Some(BTreeMap::new()));
+        assert!(match_route_path("/health", "/healthz").is_none());
+        assert!(match_route_path("/health", "/health/more").is_none());
+    }
+
+    #[test]
+    fn match_route_with_vars() {
+        let vars = match_route_path("/users/{user}/cart?{limit}", "/users/alice/cart?limit=10");
+        assert!(vars == Some(BTreeMap::from([("user".to_string(), "alice".to_string())])));
+    }
+
+    #[test]
+    fn match_route_with_catch_all() {
+        let vars = match_route_path("/files/{+path}", "/files/a/b/c.txt");
+        assert!(
+            vars == Some(BTreeMap::from([(
+                "path".to_string(),
+                "a/b/c.txt".to_string()
+            )]))
+        );
+    }
+
+    #[test]
+    fn specificity_counts_literal_segments() {
+        assert!(route_path_specificity("/users/{user}/cart") == 2);
+        assert!(route_path_specificity("/{+path}") == 0);
+    }
+
+    #[test]
+    fn resolve_literal_and_interpolated_worker_names() {
+        let vars = BTreeMap::from([("user".to_string(), "alice".to_string())]);
+        assert!(
+            resolve_worker_name(r#""static-worker""#, &vars) == Some("static-worker".to_string())
+        );
+        assert!(
+            resolve_worker_name(r#""cart-${request.path.user}""#, &vars)
+                == Some("cart-alice".to_string())
+        );
+        assert!(resolve_worker_name("request.path.user", &vars) == Some("alice".to_string()));
+        assert!(resolve_worker_name("let x = 1; x", &vars).is_none());
+    }
+}
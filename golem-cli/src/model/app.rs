@@ -29,6 +29,7 @@ pub struct ApplicationConfig {
     pub profile: Option<BuildProfileName>,
     pub offline: bool,
     pub steps_filter: HashSet<AppBuildStep>,
+    pub strict_wit_lint: bool,
     pub golem_rust_override: RustDependencyOverride,
 }
 
@@ -628,6 +629,12 @@ impl Application {
         self.temp_dir().join("client")
     }
 
+    /// Root of the Cargo workspace used for building all static WASM RPC clients,
+    /// the client temp build dirs are its members
+    pub fn client_workspace_dir(&self) -> PathBuf {
+        self.client_build_dir()
+    }
+
     pub fn client_temp_build_dir(&self, component_name: &AppComponentName) -> PathBuf {
         self.client_build_dir()
             .join(self.component_name_as_safe_path_elem(component_name))
@@ -0,0 +1,389 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::{anyhow, Context};
+use chrono::{DateTime, Datelike, Duration, Utc};
+use rcgen::{CertificateParams, DnType, KeyPair};
+use serde::{Deserialize, Serialize};
+use x509_parser::certificate::X509Certificate;
+use x509_parser::extensions::GeneralName;
+use x509_parser::pem::Pem;
+
+pub struct SelfSignedCertificate {
+    pub certificate_pem: String,
+    pub private_key_pem: String,
+}
+
+pub fn generate_self_signed_certificate(
+    domain_name: &str,
+    validity_days: u32,
+) -> anyhow::Result<SelfSignedCertificate> {
+    generate_self_signed_certificate_for_names(&[domain_name.to_string()], validity_days)
+}
+
+/// Generates a certificate valid for all the given domain names and IP addresses,
+/// the first name is used as the common name
+pub fn generate_self_signed_certificate_for_names(
+    names: &[String],
+    validity_days: u32,
+) -> anyhow::Result<SelfSignedCertificate> {
+    let common_name = names
+        .first()
+        .ok_or_else(|| anyhow!("At least one name is required for the certificate"))?;
+    let mut params = CertificateParams::new(names.to_vec())
+        .with_context(|| anyhow!("Invalid domain name: {}", names.join(", ")))?;
+    params
+        .distinguished_name
+        .push(DnType::CommonName, common_name);
+
+    let now = Utc::now();
+    let not_after = now + Duration::days(validity_days as i64);
+    params.not_before = rcgen::date_time_ymd(now.year(), now.month() as u8, now.day() as u8);
+    params.not_after = rcgen::date_time_ymd(
+        not_after.year(),
+        not_after.month() as u8,
+        not_after.day() as u8,
+    );
+
+    let key_pair = KeyPair::generate().context("Failed to generate private key")?;
+    let certificate = params
+        .self_signed(&key_pair)
+        .context("Failed to generate self-signed certificate")?;
+
+    Ok(SelfSignedCertificate {
+        certificate_pem: certificate.pem(),
+        private_key_pem: key_pair.serialize_pem(),
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CertificateCheckSeverity {
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CertificateCheckIssue {
+    pub severity: CertificateCheckSeverity,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CertificateCheck {
+    pub source: String,
+    pub subject: Option<String>,
+    pub dns_names: Vec<String>,
+    pub not_after: Option<DateTime<Utc>>,
+    pub chain_length: usize,
+    pub issues: Vec<CertificateCheckIssue>,
+}
+
+impl CertificateCheck {
+    pub fn has_errors(&self) -> bool {
+        self.has_severity(CertificateCheckSeverity::Error)
+    }
+
+    pub fn has_warnings(&self) -> bool {
+        self.has_severity(CertificateCheckSeverity::Warning)
+    }
+
+    fn has_severity(&self, severity: CertificateCheckSeverity) -> bool {
+        self.issues.iter().any(|issue| issue.severity == severity)
+    }
+
+    fn warn(&mut self, message: String) {
+        self.issues.push(CertificateCheckIssue {
+            severity: CertificateCheckSeverity::Warning,
+            message,
+        });
+    }
+
+    fn error(&mut self, message: String) {
+        self.issues.push(CertificateCheckIssue {
+            severity: CertificateCheckSeverity::Error,
+            message,
+        });
+    }
+}
+
+/// Checks a PEM encoded certificate chain, starting with the leaf certificate.
+///
+/// Reports expired and soon to expire certificates, domain name mismatches of the leaf
+/// certificate, and chains where an issuer is missing or out of order.
+pub fn check_certificate_pem(
+    source: String,
+    pem: &str,
+    domain_name: Option<&str>,
+    now: DateTime<Utc>,
+    expiry_warning_days: u32,
+) -> CertificateCheck {
+    let mut check = CertificateCheck {
+        source,
+        subject: None,
+        dns_names: vec![],
+        not_after: None,
+        chain_length: 0,
+        issues: vec![],
+    };
+
+    let pems = match Pem::iter_from_buffer(pem.as_bytes())
+        .filter(|pem| {
+            pem.as_ref()
+                .map(|pem| pem.label == "CERTIFICATE")
+                .unwrap_or(true)
+        })
+        .collect::<Result<Vec<_>, _>>()
+    {
+        Ok(pems) => pems,
+        Err(err) => {
+            check.error(format!("Failed to parse PEM: {}", err));
+            return check;
+        }
+    };
+
+    let certificates = match pems
+        .iter()
+        .map(|pem| pem.parse_x509())
+        .collect::<Result<Vec<_>, _>>()
+    {
+        Ok(certificates) => certificates,
+        Err(err) => {
+            check.error(format!("Failed to parse certificate: {}", err));
+            return check;
+        }
+    };
+
+    check.chain_length = certificates.len();
+    let Some(leaf) = certificates.first() else {
+        check.error("No certificate found".to_string());
+        return check;
+    };
+
+    check.subject = Some(leaf.subject().to_string());
+    check.dns_names = dns_names(leaf);
+
+    for (idx, certificate) in certificates.iter().enumerate() {
+        let name = if idx == 0 {
+            "Certificate".to_string()
+        } else {
+            format!("Chain certificate {} ({})", idx, certificate.subject())
+        };
+
+        let not_before = DateTime::from_timestamp(certificate.validity().not_before.timestamp(), 0);
+        let not_after = DateTime::from_timestamp(certificate.validity().not_after.timestamp(), 0);
+        if idx == 0 {
+            check.not_after = not_after;
+        }
+
+        if let Some(not_before) = not_before {
+            if now < not_before {
+                check.error(format!("{} is not valid before {}", name, not_before));
+            }
+        }
+        if let Some(not_after) = not_after {
+            if now > not_after {
+                check.error(format!("{} expired at {}", name, not_after));
+            } else if now + Duration::days(expiry_warning_days as i64) > not_after {
+                check.warn(format!(
+                    "{} expires in {} days, at {}",
+                    name,
+                    (not_after - now).num_days(),
+                    not_after
+                ));
+            }
+        }
+    }
+
+    if let Some(domain_name) = domain_name {
+        if !check
+            .dns_names
+            .iter()
+            .any(|dns_name| domain_name_matches(dns_name, domain_name))
+        {
+            check.error(format!(
+                "Certificate is not valid for domain {}, valid names: {}",
+                domain_name,
+                check.dns_names.join(", ")
+            ));
+        }
+    }
+
+    if is_self_signed(leaf) {
+        check.warn("Certificate is self-signed".to_string());
+    } else if certificates.len() == 1 {
+        check.warn(format!(
+            "Incomplete chain, the certificate is issued by {}, but no intermediate certificates are included",
+            leaf.issuer()
+        ));
+    }
+
+    for (certificate, issuer) in certificates.iter().zip(certificates.iter().skip(1)) {
+        if certificate.issuer() != issuer.subject() {
+            check.error(format!(
+                "Broken chain, {} is issued by {}, but it is followed by {}",
+                certificate.subject(),
+                certificate.issuer(),
+                issuer.subject()
+            ));
+        }
+    }
+
+    check
+}
+
+fn dns_names(certificate: &X509Certificate) -> Vec<String> {
+    let mut dns_names = match certificate.subject_alternative_name() {
+        Ok(Some(san)) => san
+            .value
+            .general_names
+            .iter()
+            .filter_map(|name| match name {
+                GeneralName::DNSName(dns_name) => Some(dns_name.to_string()),
+                _ => None,
+            })
+            .collect::<Vec<_>>(),
+        _ => vec![],
+    };
+
+    // The common name is only considered when no alternative names are present
+    if dns_names.is_empty() {
+        dns_names.extend(
+            certificate
+                .subject()
+                .iter_common_name()
+                .filter_map(|cn| cn.as_str().ok())
+                .map(|cn| cn.to_string()),
+        );
+    }
+
+    dns_names
+}
+
+fn is_self_signed(certificate: &X509Certificate) -> bool {
+    certificate.subject() == certificate.issuer()
+}
+
+/// Matches a certificate DNS name, optionally using a leftmost wildcard label, against a domain
+pub fn domain_name_matches(dns_name: &str, domain_name: &str) -> bool {
+    let dns_name = dns_name.trim_end_matches('.').to_lowercase();
+    let domain_name = domain_name.trim_end_matches('.').to_lowercase();
+
+    match dns_name.strip_prefix("*.") {
+        Some(dns_name_suffix) => match domain_name.split_once('.') {
+            Some((label, domain_name_suffix)) => {
+                !label.is_empty() && domain_name_suffix == dns_name_suffix
+            }
+            None => false,
+        },
+        None => dns_name == domain_name,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::model::certificate::{
+        check_certificate_pem, domain_name_matches, generate_self_signed_certificate,
+        generate_self_signed_certificate_for_names, CertificateCheckSeverity,
+    };
+    use assert2::assert;
+    use chrono::{Duration, Utc};
+    use test_r::test;
+
+    #[test]
+    fn domain_name_matching() {
+        assert!(domain_name_matches("example.test", "example.test"));
+        assert!(domain_name_matches("Example.Test.", "example.test"));
+        assert!(domain_name_matches("*.example.test", "api.example.test"));
+        assert!(!domain_name_matches("*.example.test", "example.test"));
+        assert!(!domain_name_matches("*.example.test", "a.api.example.test"));
+        assert!(!domain_name_matches("example.test", "api.example.test"));
+    }
+
+    #[test]
+    fn check_generated_self_signed_certificate() {
+        let certificate = generate_self_signed_certificate("api.example.test", 10).unwrap();
+
+        let check = check_certificate_pem(
+            "test".to_string(),
+            &certificate.certificate_pem,
+            Some("api.example.test"),
+            Utc::now(),
+            30,
+        );
+
+        assert!(check.chain_length == 1);
+        assert!(check.dns_names == vec!["api.example.test".to_string()]);
+        assert!(!check.has_errors());
+        assert!(check.issues.len() == 2);
+        assert!(check
+            .issues
+            .iter()
+            .all(|issue| issue.severity == CertificateCheckSeverity::Warning));
+    }
+
+    #[test]
+    fn check_generated_certificate_with_ip_addresses() {
+        let certificate = generate_self_signed_certificate_for_names(
+            &[
+                "localhost".to_string(),
+                "127.0.0.1".to_string(),
+                "::1".to_string(),
+            ],
+            10,
+        )
+        .unwrap();
+
+        let check = check_certificate_pem(
+            "test".to_string(),
+            &certificate.certificate_pem,
+            Some("localhost"),
+            Utc::now(),
+            30,
+        );
+
+        assert!(check.dns_names == vec!["localhost".to_string()]);
+        assert!(!check.has_errors());
+    }
+
+    #[test]
+    fn check_expired_and_mismatching_certificate() {
+        let certificate = generate_self_signed_certificate("api.example.test", 10).unwrap();
+
+        let check = check_certificate_pem(
+            "test".to_string(),
+            &certificate.certificate_pem,
+            Some("other.example.test"),
+            Utc::now() + Duration::days(20),
+            30,
+        );
+
+        assert!(
+            check
+                .issues
+                .iter()
+                .filter(|issue| issue.severity == CertificateCheckSeverity::Error)
+                .count()
+                == 2
+        );
+    }
+
+    #[test]
+    fn check_invalid_pem() {
+        let check = check_certificate_pem("test".to_string(), "not a pem", None, Utc::now(), 30);
+        assert!(check.has_errors());
+    }
+}
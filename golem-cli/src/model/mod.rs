@@ -12,18 +12,27 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod api_definition;
+pub mod api_route;
 pub mod app;
 pub mod app_raw;
+pub mod certificate;
 pub mod component;
 pub mod deploy;
 pub mod invoke_result_view;
+pub mod openapi;
 pub mod plugin_manifest;
 pub mod project;
 pub mod template;
 pub mod text;
 pub mod to_cloud;
 pub mod to_oss;
+pub mod trace_context;
+pub mod wasm_inspect;
 pub mod wave;
+pub mod wit_compat;
+pub mod wit_deps;
+pub mod wit_lint;
 pub mod worker;
 
 use crate::cloud::{AccountId, ProjectId};
@@ -622,6 +631,8 @@ impl From<golem_cloud_client::model::ApiDeployment> for ApiDeployment {
 pub struct ApiSecurityScheme {
     #[serde(rename = "schemeIdentifier")]
     pub scheme_identifier: String,
+    #[serde(rename = "providerType")]
+    pub provider_type: String,
     #[serde(rename = "clientId")]
     pub client_id: String,
     #[serde(rename = "clientSecret")]
@@ -635,6 +646,7 @@ impl From<golem_client::model::SecuritySchemeData> for ApiSecurityScheme {
     fn from(value: golem_client::model::SecuritySchemeData) -> Self {
         ApiSecurityScheme {
             scheme_identifier: value.scheme_identifier,
+            provider_type: value.provider_type.to_string(),
             client_id: value.client_id,
             client_secret: value.client_secret,
             redirect_url: value.redirect_url,
@@ -647,6 +659,7 @@ impl From<golem_cloud_client::model::SecuritySchemeData> for ApiSecurityScheme {
     fn from(value: golem_cloud_client::model::SecuritySchemeData) -> Self {
         ApiSecurityScheme {
             scheme_identifier: value.scheme_identifier,
+            provider_type: value.provider_type.to_string(),
             client_id: value.client_id,
             client_secret: value.client_secret,
             redirect_url: value.redirect_url,
@@ -655,6 +668,14 @@ impl From<golem_cloud_client::model::SecuritySchemeData> for ApiSecurityScheme {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApiSecuritySchemeUsage {
+    #[serde(flatten)]
+    pub scheme: ApiSecurityScheme,
+    #[serde(rename = "usedBy")]
+    pub used_by: Vec<ApiDefinitionInfo>,
+}
+
 pub trait HasVerbosity {
     fn verbosity(&self) -> Verbosity;
 }
@@ -737,6 +758,7 @@ impl ProfileView {
     }
 }
 
+#[derive(Debug, Clone)]
 pub struct ProjectNameAndId {
     pub project_name: ProjectName,
     pub project_id: ProjectId,
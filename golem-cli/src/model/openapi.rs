@@ -0,0 +1,527 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::model::component::Component;
+use crate::model::ApiSecurityScheme;
+use golem_client::model::{HttpApiDefinitionResponseData, RouteResponseData};
+use golem_wasm_ast::analysis::{AnalysedExport, AnalysedFunction, AnalysedType, NameTypePair};
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+#[clap(rename_all = "kebab_case")]
+pub enum ApiDefinitionExportFormat {
+    OpenapiYaml,
+    OpenapiJson,
+}
+
+/// Components bound by the routes of an API definition, keyed by component name and version
+pub type BoundComponents = BTreeMap<(String, u64), Component>;
+
+pub fn api_definition_to_openapi(
+    definition: &HttpApiDefinitionResponseData,
+    bound_components: &BoundComponents,
+    security_schemes: &BTreeMap<String, ApiSecurityScheme>,
+) -> Value {
+    // Schemes of unknown providers have no valid OpenAPI representation, so they are left out,
+    // together with the route security requirements referring to them
+    let security_scheme_objects = security_schemes
+        .iter()
+        .filter_map(|(name, scheme)| {
+            security_scheme_object(scheme).map(|object| (name.clone(), object))
+        })
+        .collect::<Map<_, _>>();
+
+    let mut paths = BTreeMap::<String, Map<String, Value>>::new();
+
+    for route in &definition.routes {
+        let (path, query_params) = openapi_path(&route.path);
+        let method = route.method.to_string().to_lowercase();
+        paths.entry(path).or_default().insert(
+            method,
+            route_operation(
+                route,
+                &query_params,
+                bound_components,
+                &security_scheme_objects,
+            ),
+        );
+    }
+
+    let mut document = json!({
+        "openapi": "3.1.0",
+        "info": {
+            "title": definition.id,
+            "version": definition.version,
+        },
+        "paths": paths,
+    });
+
+    if !security_scheme_objects.is_empty() {
+        document["components"] = json!({ "securitySchemes": security_scheme_objects });
+    }
+
+    document
+}
+
+pub fn analysed_type_to_json_schema(typ: &AnalysedType) -> Value {
+    match typ {
+        AnalysedType::Bool(_) => json!({ "type": "boolean" }),
+        AnalysedType::S8(_) | AnalysedType::S16(_) | AnalysedType::S32(_) => {
+            json!({ "type": "integer", "format": "int32" })
+        }
+        AnalysedType::U8(_) | AnalysedType::U16(_) | AnalysedType::U32(_) => {
+            json!({ "type": "integer", "format": "int32", "minimum": 0 })
+        }
+        AnalysedType::S64(_) => json!({ "type": "integer", "format": "int64" }),
+        AnalysedType::U64(_) => json!({ "type": "integer", "format": "int64", "minimum": 0 }),
+        AnalysedType::F32(_) => json!({ "type": "number", "format": "float" }),
+        AnalysedType::F64(_) => json!({ "type": "number", "format": "double" }),
+        AnalysedType::Chr(_) => json!({ "type": "string", "minLength": 1, "maxLength": 1 }),
+        AnalysedType::Str(_) => json!({ "type": "string" }),
+        AnalysedType::List(list) => {
+            json!({ "type": "array", "items": analysed_type_to_json_schema(&list.inner) })
+        }
+        AnalysedType::Tuple(tuple) => json!({
+            "type": "array",
+            "prefixItems": tuple.items.iter().map(analysed_type_to_json_schema).collect::<Vec<_>>(),
+            "items": false,
+            "minItems": tuple.items.len(),
+        }),
+        AnalysedType::Record(record) => record_schema(&record.fields),
+        AnalysedType::Option(option) => json!({
+            "anyOf": [analysed_type_to_json_schema(&option.inner), { "type": "null" }]
+        }),
+        AnalysedType::Enum(enum_type) => json!({ "type": "string", "enum": enum_type.cases }),
+        AnalysedType::Flags(flags) => json!({
+            "type": "array",
+            "items": { "type": "string", "enum": flags.names },
+            "uniqueItems": true,
+        }),
+        AnalysedType::Variant(variant) => json!({
+            "oneOf": variant
+                .cases
+                .iter()
+                .map(|case| match &case.typ {
+                    Some(typ) => json!({
+                        "type": "object",
+                        "properties": { case.name.clone(): analysed_type_to_json_schema(typ) },
+                        "required": [case.name],
+                    }),
+                    None => json!({ "type": "string", "enum": [case.name] }),
+                })
+                .collect::<Vec<_>>()
+        }),
+        AnalysedType::Result(result) => {
+            let case_schema = |typ: &Option<Box<AnalysedType>>| match typ {
+                Some(typ) => analysed_type_to_json_schema(typ),
+                None => json!({ "type": "null" }),
+            };
+            json!({
+                "oneOf": [
+                    {
+                        "type": "object",
+                        "properties": { "ok": case_schema(&result.ok) },
+                        "required": ["ok"],
+                    },
+                    {
+                        "type": "object",
+                        "properties": { "err": case_schema(&result.err) },
+                        "required": ["err"],
+                    },
+                ]
+            })
+        }
+        AnalysedType::Handle(_) => json!({ "type": "string", "description": "resource handle" }),
+    }
+}
+
+fn record_schema(fields: &[NameTypePair]) -> Value {
+    json!({
+        "type": "object",
+        "properties": fields
+            .iter()
+            .map(|field| (field.name.clone(), analysed_type_to_json_schema(&field.typ)))
+            .collect::<Map<_, _>>(),
+        "required": fields
+            .iter()
+            .filter(|field| !matches!(field.typ, AnalysedType::Option(_)))
+            .map(|field| field.name.clone())
+            .collect::<Vec<_>>(),
+    })
+}
+
+/// Splits a Golem route path into an OpenAPI path template and its query variables.
+///
+/// Golem uses `{+var}` for catch-all path variables and `?{var}&{var}` for query variables,
+/// while OpenAPI only supports plain `{var}` path templates.
+pub fn openapi_path(path: &str) -> (String, Vec<String>) {
+    let (path, query) = match path.split_once('?') {
+        Some((path, query)) => (path, Some(query)),
+        None => (path, None),
+    };
+
+    let query_params = query
+        .map(|query| {
+            query
+                .split('&')
+                .filter_map(|param| {
+                    param
+                        .trim()
+                        .strip_prefix('{')
+                        .and_then(|param| param.strip_suffix('}'))
+                        .map(|param| param.to_string())
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    (path.replace("{+", "{"), query_params)
+}
+
+fn path_params(path: &str) -> Vec<String> {
+    path.split('/')
+        .filter_map(|segment| {
+            segment
+                .strip_prefix('{')
+                .and_then(|segment| segment.strip_suffix('}'))
+                .map(|segment| segment.trim_start_matches('+').to_string())
+        })
+        .collect()
+}
+
+fn route_operation(
+    route: &RouteResponseData,
+    query_params: &[String],
+    bound_components: &BoundComponents,
+    security_schemes: &Map<String, Value>,
+) -> Value {
+    let mut parameters = Vec::<Value>::new();
+    for param in path_params(&openapi_path(&route.path).0) {
+        parameters.push(json!({
+            "name": param,
+            "in": "path",
+            "required": true,
+            "schema": { "type": "string" },
+        }));
+    }
+    for param in query_params {
+        parameters.push(json!({
+            "name": param,
+            "in": "query",
+            "required": false,
+            "schema": { "type": "string" },
+        }));
+    }
+
+    let bound_function = bound_function(route, bound_components);
+    let response = route.binding.response.as_deref().unwrap_or_default();
+
+    let body_schema = bound_function
+        .filter(|function| rib_response_body_is_call_of(response, &function.name))
+        .and_then(|function| match function.results.as_slice() {
+            [result] => Some(analysed_type_to_json_schema(&result.typ)),
+            _ => None,
+        })
+        .unwrap_or_else(|| json!({}));
+
+    let response_object = json!({
+        "description": "Response produced by the response mapping",
+        "content": {
+            "application/json": {
+                "schema": body_schema,
+            }
+        }
+    });
+    let responses = match rib_response_status(response) {
+        Some(status) => json!({
+            status.to_string(): response_object,
+            "default": {
+                "description": "Error response",
+            }
+        }),
+        None => json!({ "default": response_object }),
+    };
+
+    let mut operation = json!({
+        "parameters": parameters,
+        "responses": responses,
+    });
+
+    if let Some(component) = &route.binding.component {
+        operation["tags"] = json!([component.name]);
+    }
+
+    let method = route.method.to_string().to_lowercase();
+    if matches!(method.as_str(), "post" | "put" | "patch") {
+        let body_schema = bound_function
+            .filter(|function| !function.parameters.is_empty())
+            .map(|function| record_schema(&function.parameters))
+            .unwrap_or_else(|| json!({}));
+        operation["requestBody"] = json!({
+            "content": {
+                "application/json": {
+                    "schema": body_schema,
+                }
+            }
+        });
+    }
+
+    if let Some(security) = route
+        .security
+        .as_ref()
+        .filter(|security| security_schemes.contains_key(*security))
+    {
+        operation["security"] = json!([{ security.clone(): [] }]);
+    }
+
+    operation
+}
+
+/// Finds the single exported function called from the route's Rib response mapping.
+///
+/// Returns None if the binding has no component, or if the function cannot be unambiguously
+/// identified, in which case the generated schemas are left open.
+fn bound_function<'a>(
+    route: &RouteResponseData,
+    bound_components: &'a BoundComponents,
+) -> Option<&'a AnalysedFunction> {
+    let component = route.binding.component.as_ref()?;
+    let response = route.binding.response.as_ref()?;
+    let component = bound_components.get(&(component.name.clone(), component.version))?;
+
+    let mut functions = component
+        .metadata
+        .exports
+        .iter()
+        .flat_map(|export| match export {
+            AnalysedExport::Instance(instance) => instance.functions.iter().collect::<Vec<_>>(),
+            AnalysedExport::Function(function) => vec![function],
+        })
+        .filter(|function| {
+            response.contains(&format!("{{{}}}(", function.name))
+                || response.contains(&format!(".{}(", function.name))
+                || response.contains(&format!(" {}(", function.name))
+        });
+
+    match (functions.next(), functions.next()) {
+        (Some(function), None) => Some(function),
+        _ => None,
+    }
+}
+
+/// Returns the value expression of a field of the record literal produced by a Rib response
+/// mapping, e.g. `200u64` for `status` in `{status: 200u64, body: result}`.
+fn rib_response_field<'a>(response: &'a str, field_name: &str) -> Option<&'a str> {
+    let is_ident_char = |c: char| c.is_ascii_alphanumeric() || c == '-' || c == '_';
+    response
+        .match_indices(field_name)
+        .filter(|(idx, _)| {
+            !response[..*idx]
+                .chars()
+                .next_back()
+                .is_some_and(is_ident_char)
+        })
+        .filter_map(|(idx, _)| {
+            response[idx + field_name.len()..]
+                .trim_start()
+                .strip_prefix(':')
+        })
+        .last()
+        .map(|value| value.trim_start())
+}
+
+/// Status code of the response mapping, if it is an integer literal, e.g. `200` or `200u64`
+fn rib_response_status(response: &str) -> Option<u16> {
+    let literal = rib_response_field(response, "status")?
+        .split([',', '}'])
+        .next()
+        .unwrap_or_default()
+        .trim();
+    let digits_len = literal
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(literal.len());
+    let (digits, suffix) = literal.split_at(digits_len);
+    let valid_suffix = suffix.is_empty()
+        || ["u8", "u16", "u32", "u64", "s8", "s16", "s32", "s64"].contains(&suffix);
+    valid_suffix.then(|| digits.parse().ok()).flatten()
+}
+
+/// Checks whether the body of the response mapping is exactly the result of calling the given
+/// function, either directly, or through a variable bound by `let`. Any other body (e.g. a
+/// record built from the result) has a shape that cannot be derived from the function alone.
+fn rib_response_body_is_call_of(response: &str, function_name: &str) -> bool {
+    let is_call = |expr: &str| {
+        let expr = expr.trim();
+        [
+            format!("{{{}}}(", function_name),
+            format!(".{}(", function_name),
+        ]
+        .iter()
+        .any(|call| expr.contains(call.as_str()))
+            || expr.starts_with(&format!("{}(", function_name))
+    };
+
+    let Some(body) = rib_response_field(response, "body") else {
+        return false;
+    };
+    let ident = body
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_')
+        .collect::<String>();
+    let after_ident = body[ident.len()..].trim_start();
+
+    if !ident.is_empty() && (after_ident.is_empty() || after_ident.starts_with([',', '}'])) {
+        response.split(';').any(|statement| {
+            statement
+                .trim()
+                .strip_prefix("let ")
+                .and_then(|binding| binding.split_once('='))
+                .is_some_and(|(name, expr)| {
+                    name.split(':').next().unwrap_or_default().trim() == ident && is_call(expr)
+                })
+        })
+    } else {
+        let call_end = body.find(')').map(|idx| idx + 1).unwrap_or(body.len());
+        is_call(&body[..call_end]) && body[call_end..].trim_start().starts_with([',', '}'])
+    }
+}
+
+fn security_scheme_object(scheme: &ApiSecurityScheme) -> Option<Value> {
+    let open_id_connect_url = match scheme.provider_type.to_lowercase().as_str() {
+        "google" => Some("https://accounts.google.com/.well-known/openid-configuration"),
+        "microsoft" => {
+            Some("https://login.microsoftonline.com/common/v2.0/.well-known/openid-configuration")
+        }
+        "gitlab" => Some("https://gitlab.com/.well-known/openid-configuration"),
+        "facebook" => Some("https://www.facebook.com/.well-known/openid-configuration"),
+        _ => None,
+    };
+
+    open_id_connect_url.map(|url| {
+        json!({
+            "type": "openIdConnect",
+            "openIdConnectUrl": url,
+            "description": format!(
+                "{} (client ID: {}, scopes: {})",
+                scheme.provider_type,
+                scheme.client_id,
+                scheme.scopes.join(", ")
+            ),
+        })
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::model::openapi::{
+        analysed_type_to_json_schema, openapi_path, rib_response_body_is_call_of,
+        rib_response_status, security_scheme_object,
+    };
+    use crate::model::ApiSecurityScheme;
+    use assert2::assert;
+    use golem_wasm_ast::analysis::analysed_type::{field, list, option, record, str, tuple, u32};
+    use serde_json::json;
+    use test_r::test;
+
+    #[test]
+    fn openapi_path_converts_catch_all_and_query_vars() {
+        let (path, query) = openapi_path("/users/{user-id}/files/{+path}?{limit}&{offset}");
+        assert!(path == "/users/{user-id}/files/{path}");
+        assert!(query == vec!["limit".to_string(), "offset".to_string()]);
+    }
+
+    #[test]
+    fn openapi_path_without_query() {
+        let (path, query) = openapi_path("/health");
+        assert!(path == "/health");
+        assert!(query.is_empty());
+    }
+
+    #[test]
+    fn record_schema_lists_required_fields() {
+        let schema = analysed_type_to_json_schema(&record(vec![
+            field("name", str()),
+            field("count", u32()),
+            field("tags", list(str())),
+            field("note", option(str())),
+        ]));
+
+        assert!(schema["type"] == json!("object"));
+        assert!(schema["required"] == json!(["name", "count", "tags"]));
+        assert!(schema["properties"]["tags"]["type"] == json!("array"));
+        assert!(
+            schema["properties"]["note"]
+                == json!({ "anyOf": [{ "type": "string" }, { "type": "null" }] })
+        );
+    }
+
+    #[test]
+    fn tuple_schema_keeps_element_positions() {
+        let schema = analysed_type_to_json_schema(&tuple(vec![str(), u32()]));
+
+        assert!(
+            schema
+                == json!({
+                    "type": "array",
+                    "prefixItems": [
+                        { "type": "string" },
+                        { "type": "integer", "format": "int32", "minimum": 0 },
+                    ],
+                    "items": false,
+                    "minItems": 2,
+                })
+        );
+    }
+
+    #[test]
+    fn rib_response_status_and_body() {
+        let response = r#"
+            let result = golem:it/api.{get-cart}(user-id);
+            {status: 200u64, body: result}
+        "#;
+        assert!(rib_response_status(response) == Some(200));
+        assert!(rib_response_body_is_call_of(response, "get-cart"));
+        assert!(!rib_response_body_is_call_of(response, "add-item"));
+
+        let response = r#"
+            let worker = instance("cart");
+            let result = worker.get-cart();
+            {status: 201, body: {items: result}}
+        "#;
+        assert!(rib_response_status(response) == Some(201));
+        assert!(!rib_response_body_is_call_of(response, "get-cart"));
+
+        let response = r#"{status: code, body: golem:it/api.{get-cart}(user-id)}"#;
+        assert!(rib_response_status(response) == None);
+        assert!(rib_response_body_is_call_of(response, "get-cart"));
+    }
+
+    #[test]
+    fn unknown_security_provider_is_left_out() {
+        let scheme = |provider_type: &str| ApiSecurityScheme {
+            scheme_identifier: "scheme".to_string(),
+            provider_type: provider_type.to_string(),
+            client_id: "client".to_string(),
+            client_secret: "secret".to_string(),
+            redirect_url: "http://localhost/callback".to_string(),
+            scopes: vec!["openid".to_string()],
+        };
+
+        assert!(
+            security_scheme_object(&scheme("Google")).unwrap()["type"] == json!("openIdConnect")
+        );
+        assert!(security_scheme_object(&scheme("custom")).is_none());
+    }
+}
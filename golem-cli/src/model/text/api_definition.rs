@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::log::{logln, LogColorize, LogIndent};
+use crate::model::api_definition::ApiDefinitionDiff;
 use crate::model::text::fmt::*;
 use crate::model::ComponentName;
 use cli_table::{format::Justify, Table};
@@ -156,3 +158,62 @@ impl TextView for Vec<HttpApiDefinitionResponseData> {
         log_table::<_, HttpApiDefinitionTableView>(self);
     }
 }
+
+impl TextView for ApiDefinitionDiff {
+    fn log(&self) {
+        if self.is_empty() {
+            logln(format!(
+                "No route changes between versions {} and {} of API definition {}",
+                format_message_highlight(&self.from_version),
+                format_message_highlight(&self.to_version),
+                format_message_highlight(&self.id),
+            ));
+            return;
+        }
+
+        logln(format!(
+            "Route changes between versions {} and {} of API definition {}",
+            format_message_highlight(&self.from_version),
+            format_message_highlight(&self.to_version),
+            format_message_highlight(&self.id),
+        ));
+        logln("");
+
+        for route in &self.added {
+            logln(
+                format!("+ {} {}", route.method, route.path)
+                    .log_color_ok_highlight()
+                    .to_string(),
+            );
+        }
+        for route in &self.removed {
+            logln(
+                format!("- {} {}", route.method, route.path)
+                    .log_color_error_highlight()
+                    .to_string(),
+            );
+        }
+        for route in &self.changed {
+            logln(
+                format!("~ {} {}", route.route.method, route.route.path)
+                    .log_color_warn()
+                    .to_string(),
+            );
+            let _indent = LogIndent::new();
+            for change in &route.changes {
+                logln(format!("{}:", change.field.log_color_highlight()));
+                let _indent = LogIndent::new();
+                if let Some(from) = &change.from {
+                    for line in from.lines() {
+                        logln(format!("- {}", line).log_color_error().to_string());
+                    }
+                }
+                if let Some(to) = &change.to {
+                    for line in to.lines() {
+                        logln(format!("+ {}", line).log_color_ok_highlight().to_string());
+                    }
+                }
+            }
+        }
+    }
+}
@@ -13,18 +13,19 @@
 // limitations under the License.
 
 use crate::model::text::fmt::*;
-use crate::model::ApiSecurityScheme;
+use crate::model::{ApiSecurityScheme, ApiSecuritySchemeUsage};
 use cli_table::Table;
-use golem_client::model::SecuritySchemeData;
 use indoc::printdoc;
+use itertools::Itertools;
 
 impl TextView for ApiSecurityScheme {
     fn log(&self) {
         printdoc!(
                     "
-                    API Security Scheme: ID: {}, scopes: {}, client ID: {}, client secret: {}, redirect URL: {}
+                    API Security Scheme: ID: {}, provider: {}, scopes: {}, client ID: {}, client secret: {}, redirect URL: {}
                     ",
                     format_message_highlight(&self.scheme_identifier),
+                    &self.provider_type,
                     &self.scopes.join(", "),
                     format_message_highlight(&self.client_id),
                     format_message_highlight(&self.client_secret),
@@ -41,20 +42,30 @@ struct ApiSecuritySchemeTableView {
     pub provider: String,
     #[table(title = "Client ID")]
     pub client_id: String,
-    #[table(title = "Client Secret")]
-    pub client_secret: String,
     #[table(title = "Redirect URL")]
     pub redirect_url: String,
+    #[table(title = "Used by")]
+    pub used_by: String,
 }
 
-impl From<&SecuritySchemeData> for ApiSecuritySchemeTableView {
-    fn from(value: &SecuritySchemeData) -> Self {
+impl From<&ApiSecuritySchemeUsage> for ApiSecuritySchemeTableView {
+    fn from(value: &ApiSecuritySchemeUsage) -> Self {
         Self {
-            id: value.scheme_identifier.clone(),
-            provider: value.provider_type.to_string(),
-            client_id: value.client_id.clone(),
-            client_secret: value.client_secret.clone(),
-            redirect_url: value.redirect_url.clone(),
+            id: value.scheme.scheme_identifier.clone(),
+            provider: value.scheme.provider_type.clone(),
+            client_id: value.scheme.client_id.clone(),
+            redirect_url: value.scheme.redirect_url.clone(),
+            used_by: value
+                .used_by
+                .iter()
+                .map(|def| format!("{}/{}", def.id, def.version))
+                .join("\n"),
         }
     }
 }
+
+impl TextView for Vec<ApiSecuritySchemeUsage> {
+    fn log(&self) {
+        log_table::<_, ApiSecuritySchemeTableView>(self);
+    }
+}
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::log::{logln, LogColorize, LogIndent};
+use crate::model::certificate::{CertificateCheck, CertificateCheckSeverity};
 use crate::model::text::fmt::*;
 use cli_table::Table;
 use golem_cloud_client::model::Certificate;
@@ -74,3 +76,45 @@ impl TextView for CertificateListView {
         log_table::<_, CertificateTableView>(&self.0);
     }
 }
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CertificateCheckView(pub Vec<CertificateCheck>);
+
+impl TextView for CertificateCheckView {
+    fn log(&self) {
+        for check in &self.0 {
+            let status = if check.has_errors() {
+                "ERROR".log_color_error_highlight()
+            } else if check.has_warnings() {
+                "WARNING".log_color_warn()
+            } else {
+                "OK".log_color_ok_highlight()
+            };
+            logln(format!("{} {}", status, check.source.log_color_highlight()));
+
+            let _indent = LogIndent::new();
+            if let Some(subject) = &check.subject {
+                logln(format!("Subject:     {}", subject));
+            }
+            if !check.dns_names.is_empty() {
+                logln(format!("DNS names:   {}", check.dns_names.join(", ")));
+            }
+            if let Some(not_after) = &check.not_after {
+                logln(format!("Not after:   {}", not_after));
+            }
+            logln(format!("Chain:       {}", check.chain_length));
+            for issue in &check.issues {
+                match issue.severity {
+                    CertificateCheckSeverity::Warning => {
+                        logln(format!("{} {}", "warning:".log_color_warn(), issue.message))
+                    }
+                    CertificateCheckSeverity::Error => logln(format!(
+                        "{} {}",
+                        "error:".log_color_error_highlight(),
+                        issue.message
+                    )),
+                }
+            }
+        }
+    }
+}
@@ -12,10 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::log::{logln, LogColorize, LogIndent};
 use crate::model::component::ComponentView;
 use crate::model::text::fmt::*;
+use crate::model::wasm_inspect::{WasmEncoding, WasmInspection};
+use crate::model::wit_compat::{WitChangeKind, WitCompatReport};
 use crate::model::ComponentName;
+use crate::wasm_rpc_stubgen::commands::composition::CompositionExplanation;
 use cli_table::{format::Justify, Table};
+use itertools::Itertools;
 
 use serde::{Deserialize, Serialize};
 
@@ -154,3 +159,178 @@ impl MessageWithFields for ComponentReplStartedView {
         true
     }
 }
+
+impl TextView for Vec<WitCompatReport> {
+    fn log(&self) {
+        for (idx, report) in self.iter().enumerate() {
+            if idx > 0 {
+                logln("");
+            }
+
+            if report.changes.is_empty() {
+                logln(format!(
+                    "No export changes for component {} compared to deployed version {}",
+                    format_message_highlight(&report.component_name),
+                    format_message_highlight(&report.deployed_version),
+                ));
+                continue;
+            }
+
+            logln(format!(
+                "Export changes for component {} compared to deployed version {}{}",
+                format_message_highlight(&report.component_name),
+                format_message_highlight(&report.deployed_version),
+                if report.is_breaking() {
+                    format!(" ({})", "breaking".log_color_error_highlight())
+                } else {
+                    "".to_string()
+                }
+            ));
+            let _indent = LogIndent::new();
+            for change in &report.changes {
+                match change.kind {
+                    WitChangeKind::Compatible => logln(format!(
+                        "{} {}",
+                        format!("+ {}:", change.path).log_color_ok_highlight(),
+                        change.description
+                    )),
+                    WitChangeKind::Breaking => logln(format!(
+                        "{} {}",
+                        format!("! {}:", change.path).log_color_error_highlight(),
+                        change.description
+                    )),
+                }
+            }
+        }
+    }
+}
+
+impl MessageWithFields for WasmInspection {
+    fn message(&self) -> String {
+        format!(
+            "Inspected {} {}",
+            match self.encoding {
+                WasmEncoding::Component => "component",
+                WasmEncoding::Module => "module",
+            },
+            format_message_highlight(&self.source)
+        )
+    }
+
+    fn fields(&self) -> Vec<(String, String)> {
+        let mut fields = FieldsBuilder::new();
+
+        fields
+            .fmt_field_option("Name", &self.name, format_main_id)
+            .fmt_field_option("Version", &self.version, format_id)
+            .fmt_field("Size", &(self.size as u64), format_binary_size)
+            .fmt_field("Core modules", &self.core_modules, |count| {
+                count.to_string()
+            })
+            .fmt_field(
+                "Data segments size",
+                &(self.data_size as u64),
+                format_binary_size,
+            )
+            .fmt_field_optional(
+                "Unresolved client imports",
+                self.unresolved_client_imports.as_slice(),
+                !self.unresolved_client_imports.is_empty(),
+                |imports| format_warn(&imports.join("\n")),
+            )
+            .fmt_field_optional(
+                "Imports",
+                self.imports.as_slice(),
+                !self.imports.is_empty(),
+                |imports| imports.join("\n"),
+            )
+            .fmt_field_optional(
+                "Exports",
+                self.exports.as_slice(),
+                !self.exports.is_empty(),
+                format_exports,
+            )
+            .fmt_field_optional(
+                "Custom sections",
+                self.custom_sections.as_slice(),
+                !self.custom_sections.is_empty(),
+                |sections| {
+                    sections
+                        .iter()
+                        .map(|section| {
+                            format!(
+                                "{} ({})",
+                                section.name,
+                                format_binary_size(&(section.size as u64))
+                            )
+                        })
+                        .join("\n")
+                },
+            );
+
+        fields.build()
+    }
+}
+
+impl TextView for Vec<CompositionExplanation> {
+    fn log(&self) {
+        for (idx, explanation) in self.iter().enumerate() {
+            if idx > 0 {
+                logln("");
+            }
+
+            logln(format!(
+                "Link plan for component {}",
+                format_message_highlight(&explanation.component)
+            ));
+            let _indent = LogIndent::new();
+
+            if explanation.imports.is_empty() {
+                logln("No imports");
+            }
+            for import in &explanation.imports {
+                match &import.plugged_by {
+                    Some(plug) => {
+                        logln(format!(
+                            "{} {} <- {}",
+                            "+".log_color_ok_highlight(),
+                            import.name.log_color_highlight(),
+                            plug
+                        ));
+                        let shadowed_candidates = import.shadowed_candidates();
+                        if !shadowed_candidates.is_empty() {
+                            let _indent = LogIndent::new();
+                            logln(format!(
+                                "{} also provided by, but not used: {}",
+                                "!".log_color_warn(),
+                                shadowed_candidates.join(", ")
+                            ));
+                        }
+                    }
+                    None => logln(format!(
+                        "{} {} <- not plugged, must be provided by the host",
+                        "-".log_color_warn(),
+                        import.name.log_color_highlight()
+                    )),
+                }
+            }
+
+            for import in &explanation.plug_imports {
+                logln(format!(
+                    "{} {} <- imported by {}, must be provided by the host",
+                    "-".log_color_warn(),
+                    import.name.log_color_highlight(),
+                    import.plugs.join(", ")
+                ));
+            }
+
+            if !explanation.unused_plugs.is_empty() {
+                logln(format!(
+                    "{} unused plugs: {}",
+                    "!".log_color_warn(),
+                    explanation.unused_plugs.join(", ")
+                ));
+            }
+        }
+    }
+}
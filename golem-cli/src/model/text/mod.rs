@@ -26,4 +26,6 @@ pub mod profile;
 pub mod project;
 pub mod template;
 pub mod token;
+pub mod wit_deps;
+pub mod wit_lint;
 pub mod worker;
@@ -0,0 +1,80 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::log::{logln, LogColorize, LogIndent};
+use crate::model::text::fmt::*;
+use crate::model::wit_deps::{WitDepStatus, WitDepView};
+use cli_table::Table;
+
+#[derive(Table)]
+struct WitDepTableView {
+    #[table(title = "Package")]
+    pub package: String,
+    #[table(title = "Version")]
+    pub version: String,
+    #[table(title = "Dir")]
+    pub dir: String,
+    #[table(title = "Status")]
+    pub status: String,
+    #[table(title = "Used by")]
+    pub used_by: String,
+}
+
+impl From<&WitDepView> for WitDepTableView {
+    fn from(value: &WitDepView) -> Self {
+        Self {
+            package: value.package.clone(),
+            version: value.version.clone().unwrap_or_default(),
+            dir: value.dir.clone(),
+            status: match value.status {
+                WitDepStatus::Locked => "locked".log_color_ok_highlight().to_string(),
+                WitDepStatus::Modified => "modified".log_color_warn().to_string(),
+                WitDepStatus::Missing => "missing".log_color_error_highlight().to_string(),
+                WitDepStatus::Unlocked => "unlocked".log_color_warn().to_string(),
+            },
+            used_by: value.used_by.join("\n"),
+        }
+    }
+}
+
+impl TextView for Vec<WitDepView> {
+    fn log(&self) {
+        if self.is_empty() {
+            logln("No packages found in the shared wit deps directory");
+            return;
+        }
+
+        log_table::<_, WitDepTableView>(self.as_slice());
+
+        let out_of_sync = self
+            .iter()
+            .filter(|view| !view.out_of_sync_copies.is_empty())
+            .collect::<Vec<_>>();
+        if !out_of_sync.is_empty() {
+            logln("");
+            logln(format!(
+                "Component copies which differ from the shared copy, use {} to sync them:",
+                "app wit-deps update".log_color_highlight()
+            ));
+            let _indent = LogIndent::new();
+            for view in out_of_sync {
+                logln(format!("{}:", view.package.log_color_highlight()));
+                let _indent = LogIndent::new();
+                for copy in &view.out_of_sync_copies {
+                    logln(format!("- {}", copy));
+                }
+            }
+        }
+    }
+}
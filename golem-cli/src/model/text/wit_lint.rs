@@ -0,0 +1,49 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::log::{logln, LogColorize, LogIndent};
+use crate::model::text::fmt::*;
+use crate::model::wit_lint::WitLintFinding;
+use itertools::Itertools;
+
+pub fn log_wit_lint_finding(finding: &WitLintFinding) {
+    let location = finding
+        .location
+        .as_ref()
+        .map(|location| format!("{}: ", location.to_string().log_color_highlight()))
+        .unwrap_or_default();
+    log_warn(format!(
+        "{}{} [{}]",
+        location,
+        finding.message,
+        finding.kind.to_string().log_color_help_group()
+    ));
+}
+
+impl TextView for Vec<WitLintFinding> {
+    fn log(&self) {
+        if self.is_empty() {
+            logln("No WIT lint issues found");
+            return;
+        }
+
+        for (component, findings) in &self.iter().chunk_by(|finding| &finding.component) {
+            logln(format!("{}:", component.log_color_highlight()));
+            let _indent = LogIndent::new();
+            for finding in findings {
+                log_wit_lint_finding(finding);
+            }
+        }
+    }
+}
@@ -0,0 +1,151 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// W3C trace context header sent with every request of the CLI
+pub const TRACEPARENT_HEADER: &str = "traceparent";
+
+/// Environment variable for continuing an existing trace, e.g. the one of a CI job
+pub const TRACEPARENT_ENV_VAR: &str = "TRACEPARENT";
+
+/// W3C trace context shared by all requests of a single CLI command, so they can be followed
+/// as one trace across the services
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceContext {
+    pub trace_id: String,
+    pub parent_id: String,
+    pub sampled: bool,
+}
+
+impl TraceContext {
+    pub fn new() -> Self {
+        Self {
+            trace_id: Uuid::new_v4().simple().to_string(),
+            parent_id: Uuid::new_v4().simple().to_string()[..16].to_string(),
+            sampled: true,
+        }
+    }
+
+    /// Uses the trace context of the environment when provided and valid, otherwise starts a new trace
+    pub fn from_env_or_new() -> Self {
+        std::env::var(TRACEPARENT_ENV_VAR)
+            .ok()
+            .and_then(|traceparent| traceparent.parse().ok())
+            .unwrap_or_default()
+    }
+}
+
+impl Default for TraceContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Display for TraceContext {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "00-{}-{}-{}",
+            self.trace_id,
+            self.parent_id,
+            if self.sampled { "01" } else { "00" }
+        )
+    }
+}
+
+impl FromStr for TraceContext {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts = s.trim().split('-').collect::<Vec<_>>();
+        let [version, trace_id, parent_id, flags] = parts.as_slice() else {
+            return Err(format!("Invalid traceparent: {}", s));
+        };
+
+        let is_hex = |value: &str, len: usize| {
+            value.len() == len
+                && value
+                    .chars()
+                    .all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c))
+        };
+        let is_zero = |value: &str| value.chars().all(|c| c == '0');
+
+        if !is_hex(version, 2) || *version == "ff" {
+            return Err(format!("Unsupported traceparent version: {}", version));
+        }
+        if !is_hex(trace_id, 32) || is_zero(trace_id) {
+            return Err(format!("Invalid trace id in traceparent: {}", trace_id));
+        }
+        if !is_hex(parent_id, 16) || is_zero(parent_id) {
+            return Err(format!("Invalid parent id in traceparent: {}", parent_id));
+        }
+        if !is_hex(flags, 2) {
+            return Err(format!("Invalid trace flags in traceparent: {}", flags));
+        }
+
+        Ok(Self {
+            trace_id: trace_id.to_string(),
+            parent_id: parent_id.to_string(),
+            sampled: u8::from_str_radix(flags, 16).unwrap_or(0) & 1 == 1,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::model::trace_context::TraceContext;
+    use assert2::assert;
+    use test_r::test;
+
+    #[test]
+    fn new_trace_context_round_trips() {
+        let trace_context = TraceContext::new();
+        let traceparent = trace_context.to_string();
+
+        assert!(traceparent.len() == 55);
+        assert!(traceparent.parse::<TraceContext>().unwrap() == trace_context);
+    }
+
+    #[test]
+    fn parse_traceparent() {
+        let trace_context = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-00"
+            .parse::<TraceContext>()
+            .unwrap();
+        assert!(trace_context.trace_id == "4bf92f3577b34da6a3ce929d0e0e4736");
+        assert!(trace_context.parent_id == "00f067aa0ba902b7");
+        assert!(!trace_context.sampled);
+    }
+
+    #[test]
+    fn reject_invalid_traceparent() {
+        for traceparent in [
+            "",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7",
+            "ff-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+            "00-00000000000000000000000000000000-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-0000000000000000-01",
+            "00-4BF92F3577B34DA6A3CE929D0E0E4736-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e473-00f067aa0ba902b7-01",
+        ] {
+            assert!(
+                traceparent.parse::<TraceContext>().is_err(),
+                "{}",
+                traceparent
+            );
+        }
+    }
+}
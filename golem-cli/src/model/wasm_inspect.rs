@@ -0,0 +1,202 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::model::component::show_exported_functions;
+use crate::model::wit_compat::component_exports;
+use anyhow::{anyhow, Context};
+use serde::{Deserialize, Serialize};
+use wasmparser::{Encoding, Parser, Payload};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WasmEncoding {
+    Component,
+    Module,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WasmCustomSection {
+    pub name: String,
+    pub size: usize,
+}
+
+/// Summary of a WASM binary, e.g. of a linked component before deploying it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WasmInspection {
+    /// Local path or component name and version the WASM was loaded from
+    pub source: String,
+    pub encoding: WasmEncoding,
+    pub size: usize,
+    /// Name metadata, as added during the add-metadata build step
+    pub name: Option<String>,
+    /// Version metadata, as added during the add-metadata build step
+    pub version: Option<String>,
+    /// Top level imports
+    pub imports: Vec<String>,
+    /// Top level exports, for components rendered with their signatures
+    pub exports: Vec<String>,
+    /// Top level custom sections
+    pub custom_sections: Vec<WasmCustomSection>,
+    /// Number of core modules, including nested ones
+    pub core_modules: usize,
+    /// Total size of the data segments of all core modules
+    pub data_size: usize,
+    /// Imported WASM RPC client interfaces, which should have been plugged in during linking
+    pub unresolved_client_imports: Vec<String>,
+}
+
+impl WasmInspection {
+    pub fn new(source: String, wasm: &[u8]) -> anyhow::Result<Self> {
+        let mut encoding = None;
+        let mut depth = 0usize;
+        let mut imports = Vec::new();
+        let mut module_exports = Vec::new();
+        let mut custom_sections = Vec::new();
+        let mut core_modules = 0;
+        let mut data_size = 0;
+
+        for payload in Parser::new(0).parse_all(wasm) {
+            match payload.context("Failed to parse WASM")? {
+                Payload::Version {
+                    encoding: payload_encoding,
+                    ..
+                } => {
+                    depth += 1;
+                    if depth == 1 {
+                        encoding = Some(payload_encoding);
+                    }
+                    if payload_encoding == Encoding::Module {
+                        core_modules += 1;
+                    }
+                }
+                Payload::End(_) => {
+                    depth = depth.saturating_sub(1);
+                }
+                Payload::ComponentImportSection(reader) if depth == 1 => {
+                    for import in reader {
+                        imports.push(import?.name.0.to_string());
+                    }
+                }
+                Payload::ImportSection(reader) if depth == 1 => {
+                    for import in reader {
+                        let import = import?;
+                        imports.push(format!("{}/{}", import.module, import.name));
+                    }
+                }
+                Payload::ExportSection(reader) if depth == 1 => {
+                    for export in reader {
+                        module_exports.push(export?.name.to_string());
+                    }
+                }
+                Payload::CustomSection(reader) if depth == 1 => {
+                    custom_sections.push(WasmCustomSection {
+                        name: reader.name().to_string(),
+                        size: reader.data().len(),
+                    });
+                }
+                Payload::DataSection(reader) => {
+                    for data in reader {
+                        data_size += data?.data.len();
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let encoding = match encoding {
+            Some(Encoding::Component) => WasmEncoding::Component,
+            Some(Encoding::Module) => WasmEncoding::Module,
+            None => return Err(anyhow!("Empty WASM binary")),
+        };
+
+        let exports = match encoding {
+            WasmEncoding::Component => show_exported_functions(&component_exports(wasm)?, true),
+            WasmEncoding::Module => module_exports,
+        };
+
+        let metadata =
+            wasm_metadata::Payload::from_binary(wasm).context("Failed to read WASM metadata")?;
+        let metadata = metadata.metadata();
+
+        let unresolved_client_imports = imports
+            .iter()
+            .filter(|import| is_client_interface(import))
+            .cloned()
+            .collect();
+
+        Ok(Self {
+            source,
+            encoding,
+            size: wasm.len(),
+            name: metadata.name.clone(),
+            version: metadata.version.as_ref().map(|version| version.to_string()),
+            imports,
+            exports,
+            custom_sections,
+            core_modules,
+            data_size,
+            unresolved_client_imports,
+        })
+    }
+}
+
+/// Client interfaces are defined in packages named `<namespace>:<name>-client`
+fn is_client_interface(import: &str) -> bool {
+    import
+        .split_once('/')
+        .and_then(|(package, _)| package.split_once(':'))
+        .is_some_and(|(_, name)| name.ends_with("-client"))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::model::wasm_inspect::{is_client_interface, WasmEncoding, WasmInspection};
+    use assert2::assert;
+    use test_r::test;
+    use wasm_metadata::AddMetadata;
+
+    #[test]
+    fn inspect_metadata_of_empty_component() {
+        let empty_component = [0x00, 0x61, 0x73, 0x6d, 0x0d, 0x00, 0x01, 0x00];
+        let wasm = AddMetadata {
+            name: Some("app:component".to_string()),
+            version: Some(wasm_metadata::Version::new("0.1.0")),
+            ..Default::default()
+        }
+        .to_wasm(&empty_component)
+        .unwrap();
+
+        let inspection = WasmInspection::new("test.wasm".to_string(), &wasm).unwrap();
+
+        assert!(inspection.encoding == WasmEncoding::Component);
+        assert!(inspection.size == wasm.len());
+        assert!(inspection.name == Some("app:component".to_string()));
+        assert!(inspection.version == Some("0.1.0".to_string()));
+        assert!(inspection.imports.is_empty());
+        assert!(inspection.exports.is_empty());
+        assert!(inspection.core_modules == 0);
+        assert!(!inspection.custom_sections.is_empty());
+    }
+
+    #[test]
+    fn detect_client_interfaces() {
+        assert!(is_client_interface("app:callee-client/callee-client"));
+        assert!(is_client_interface("app:callee-client/callee-client@0.1.0"));
+        assert!(!is_client_interface("golem:rpc/types@0.2.0"));
+        assert!(!is_client_interface("wasi:io/streams@0.2.0"));
+        assert!(!is_client_interface("env/memory"));
+    }
+}
@@ -0,0 +1,514 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::model::component::{format_function_name, render_type};
+use anyhow::anyhow;
+use golem_wasm_ast::analysis::{
+    AnalysedExport, AnalysedFunction, AnalysedType, AnalysisContext, NameOptionTypePair,
+};
+use golem_wasm_ast::component::Component;
+use golem_wasm_ast::IgnoreAllButMetadata;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::mem::discriminant;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WitChangeKind {
+    /// Existing invocations and workers keep working, e.g. added functions or cases
+    Compatible,
+    /// Existing invocations or workers can fail, e.g. removed functions or changed parameters
+    Breaking,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WitChange {
+    pub kind: WitChangeKind,
+    /// Function, or function parameter or result type path the change belongs to
+    pub path: String,
+    pub description: String,
+}
+
+/// Changes of the exported interfaces of a component, compared to its latest deployed version
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WitCompatReport {
+    pub component_name: String,
+    pub deployed_version: u64,
+    pub changes: Vec<WitChange>,
+}
+
+impl WitCompatReport {
+    pub fn new(
+        component_name: String,
+        deployed_version: u64,
+        deployed: &[AnalysedExport],
+        local: &[AnalysedExport],
+    ) -> Self {
+        Self {
+            component_name,
+            deployed_version,
+            changes: compare_exports(deployed, local),
+        }
+    }
+
+    pub fn is_breaking(&self) -> bool {
+        self.changes
+            .iter()
+            .any(|change| change.kind == WitChangeKind::Breaking)
+    }
+}
+
+/// Analyses the exports of a component binary, e.g. of the linked WASM before deploying it
+pub fn component_exports(wasm: &[u8]) -> anyhow::Result<Vec<AnalysedExport>> {
+    let component = Component::<IgnoreAllButMetadata>::from_bytes(wasm)
+        .map_err(|err| anyhow!("Failed to parse component: {}", err))?;
+    AnalysisContext::new(component)
+        .get_top_level_exports()
+        .map_err(|err| anyhow!("Failed to analyse component exports: {:?}", err))
+}
+
+pub fn compare_exports(deployed: &[AnalysedExport], local: &[AnalysedExport]) -> Vec<WitChange> {
+    let deployed = functions_by_interface(deployed);
+    let local = functions_by_interface(local);
+    let mut changes = Vec::new();
+
+    for (interface, deployed_functions) in &deployed {
+        let Some(local_functions) = local.get(interface) else {
+            if let Some(interface) = interface {
+                changes.push(breaking(interface, "interface removed"));
+            } else {
+                for name in deployed_functions.keys() {
+                    changes.push(breaking(
+                        &format_function_name(None, name),
+                        "function removed",
+                    ));
+                }
+            }
+            continue;
+        };
+
+        for (name, deployed_function) in deployed_functions {
+            let path = format_function_name(interface.as_deref(), name);
+            match local_functions.get(name) {
+                Some(local_function) => {
+                    compare_functions(&path, deployed_function, local_function, &mut changes)
+                }
+                None => changes.push(breaking(&path, "function removed")),
+            }
+        }
+        for name in local_functions.keys() {
+            if !deployed_functions.contains_key(name) {
+                changes.push(compatible(
+                    &format_function_name(interface.as_deref(), name),
+                    "function added",
+                ));
+            }
+        }
+    }
+
+    for (interface, local_functions) in &local {
+        if deployed.contains_key(interface) {
+            continue;
+        }
+        if let Some(interface) = interface {
+            changes.push(compatible(interface, "interface added"));
+        } else {
+            for name in local_functions.keys() {
+                changes.push(compatible(
+                    &format_function_name(None, name),
+                    "function added",
+                ));
+            }
+        }
+    }
+
+    changes
+}
+
+fn functions_by_interface(
+    exports: &[AnalysedExport],
+) -> BTreeMap<Option<String>, BTreeMap<String, &AnalysedFunction>> {
+    let mut result = BTreeMap::<Option<String>, BTreeMap<String, &AnalysedFunction>>::new();
+    for export in exports {
+        match export {
+            AnalysedExport::Instance(instance) => {
+                let functions = result.entry(Some(instance.name.clone())).or_default();
+                for function in &instance.functions {
+                    functions.insert(function.name.clone(), function);
+                }
+            }
+            AnalysedExport::Function(function) => {
+                result
+                    .entry(None)
+                    .or_default()
+                    .insert(function.name.clone(), function);
+            }
+        }
+    }
+    result
+}
+
+fn compare_functions(
+    path: &str,
+    deployed: &AnalysedFunction,
+    local: &AnalysedFunction,
+    changes: &mut Vec<WitChange>,
+) {
+    if deployed.parameters.len() != local.parameters.len() {
+        changes.push(breaking(
+            path,
+            format!(
+                "parameter count changed from {} to {}",
+                deployed.parameters.len(),
+                local.parameters.len()
+            ),
+        ));
+    } else {
+        for (deployed, local) in deployed.parameters.iter().zip(&local.parameters) {
+            if deployed.name != local.name {
+                changes.push(compatible(
+                    path,
+                    format!("parameter {} renamed to {}", deployed.name, local.name),
+                ));
+            }
+            compare_types(
+                &format!("{} parameter {}", path, local.name),
+                &deployed.typ,
+                &local.typ,
+                changes,
+            );
+        }
+    }
+
+    if deployed.results.len() != local.results.len() {
+        changes.push(breaking(
+            path,
+            format!(
+                "result count changed from {} to {}",
+                deployed.results.len(),
+                local.results.len()
+            ),
+        ));
+    } else {
+        let single_result = local.results.len() == 1;
+        for (idx, (deployed, local)) in deployed.results.iter().zip(&local.results).enumerate() {
+            let result_path = match &local.name {
+                Some(name) => format!("{} result {}", path, name),
+                None if single_result => format!("{} result", path),
+                None => format!("{} result {}", path, idx),
+            };
+            compare_types(&result_path, &deployed.typ, &local.typ, changes);
+        }
+    }
+}
+
+fn compare_types(
+    path: &str,
+    deployed: &AnalysedType,
+    local: &AnalysedType,
+    changes: &mut Vec<WitChange>,
+) {
+    match (deployed, local) {
+        (AnalysedType::Record(deployed), AnalysedType::Record(local)) => {
+            let deployed_fields = deployed
+                .fields
+                .iter()
+                .map(|field| field.name.as_str())
+                .collect::<Vec<_>>();
+            let local_fields = local
+                .fields
+                .iter()
+                .map(|field| field.name.as_str())
+                .collect::<Vec<_>>();
+            if deployed_fields != local_fields {
+                changes.push(breaking(
+                    path,
+                    format!(
+                        "record fields changed from ({}) to ({})",
+                        deployed_fields.join(", "),
+                        local_fields.join(", ")
+                    ),
+                ));
+            } else {
+                for (deployed, local) in deployed.fields.iter().zip(&local.fields) {
+                    compare_types(
+                        &format!("{}.{}", path, local.name),
+                        &deployed.typ,
+                        &local.typ,
+                        changes,
+                    );
+                }
+            }
+        }
+        (AnalysedType::Variant(deployed), AnalysedType::Variant(local)) => {
+            compare_cases(path, "case", &deployed.cases, &local.cases, changes)
+        }
+        (AnalysedType::Enum(deployed), AnalysedType::Enum(local)) => {
+            compare_names(path, "case", &deployed.cases, &local.cases, changes)
+        }
+        (AnalysedType::Flags(deployed), AnalysedType::Flags(local)) => {
+            compare_names(path, "flag", &deployed.names, &local.names, changes)
+        }
+        (AnalysedType::Option(deployed), AnalysedType::Option(local)) => {
+            compare_types(path, &deployed.inner, &local.inner, changes)
+        }
+        (AnalysedType::List(deployed), AnalysedType::List(local)) => {
+            compare_types(path, &deployed.inner, &local.inner, changes)
+        }
+        (AnalysedType::Tuple(deployed_tuple), AnalysedType::Tuple(local_tuple)) => {
+            if deployed_tuple.items.len() != local_tuple.items.len() {
+                type_changed(path, deployed, local, changes);
+            } else {
+                for (idx, (deployed, local)) in deployed_tuple
+                    .items
+                    .iter()
+                    .zip(&local_tuple.items)
+                    .enumerate()
+                {
+                    compare_types(&format!("{}.{}", path, idx), deployed, local, changes);
+                }
+            }
+        }
+        (AnalysedType::Result(deployed), AnalysedType::Result(local)) => {
+            for (name, deployed, local) in [
+                ("ok", &deployed.ok, &local.ok),
+                ("err", &deployed.err, &local.err),
+            ] {
+                match (deployed, local) {
+                    (Some(deployed), Some(local)) => {
+                        compare_types(&format!("{}.{}", path, name), deployed, local, changes)
+                    }
+                    (None, None) => {}
+                    _ => changes.push(breaking(path, format!("{} type of result changed", name))),
+                }
+            }
+        }
+        (AnalysedType::Handle(deployed), AnalysedType::Handle(local)) => {
+            if deployed.mode != local.mode {
+                changes.push(breaking(path, "resource handle mode changed"));
+            }
+        }
+        (deployed, local) => {
+            if discriminant(deployed) != discriminant(local) {
+                type_changed(path, deployed, local, changes);
+            }
+        }
+    }
+}
+
+/// Cases are matched by name, but they are also encoded by their index, so only appending
+/// new cases is compatible
+fn compare_cases(
+    path: &str,
+    kind: &str,
+    deployed: &[NameOptionTypePair],
+    local: &[NameOptionTypePair],
+    changes: &mut Vec<WitChange>,
+) {
+    let deployed_names = deployed
+        .iter()
+        .map(|case| case.name.clone())
+        .collect::<Vec<_>>();
+    let local_names = local
+        .iter()
+        .map(|case| case.name.clone())
+        .collect::<Vec<_>>();
+    compare_names(path, kind, &deployed_names, &local_names, changes);
+
+    for deployed_case in deployed {
+        let Some(local_case) = local.iter().find(|case| case.name == deployed_case.name) else {
+            continue;
+        };
+        let case_path = format!("{}.{}", path, local_case.name);
+        match (&deployed_case.typ, &local_case.typ) {
+            (Some(deployed), Some(local)) => compare_types(&case_path, deployed, local, changes),
+            (None, None) => {}
+            _ => changes.push(breaking(&case_path, "case payload changed")),
+        }
+    }
+}
+
+fn compare_names(
+    path: &str,
+    kind: &str,
+    deployed: &[String],
+    local: &[String],
+    changes: &mut Vec<WitChange>,
+) {
+    for (idx, name) in deployed.iter().enumerate() {
+        match local.iter().position(|local| local == name) {
+            Some(local_idx) if local_idx == idx => {}
+            Some(_) => changes.push(breaking(path, format!("{} {} moved", kind, name))),
+            None => changes.push(breaking(path, format!("{} {} removed", kind, name))),
+        }
+    }
+    for (idx, name) in local.iter().enumerate() {
+        if !deployed.contains(name) {
+            if idx < deployed.len() {
+                changes.push(breaking(
+                    path,
+                    format!("{} {} inserted before existing {}s", kind, name, kind),
+                ));
+            } else {
+                changes.push(compatible(path, format!("{} {} added", kind, name)));
+            }
+        }
+    }
+}
+
+fn type_changed(
+    path: &str,
+    deployed: &AnalysedType,
+    local: &AnalysedType,
+    changes: &mut Vec<WitChange>,
+) {
+    changes.push(breaking(
+        path,
+        format!(
+            "type changed from {} to {}",
+            render_type(deployed),
+            render_type(local)
+        ),
+    ));
+}
+
+fn compatible(path: &str, description: impl Into<String>) -> WitChange {
+    WitChange {
+        kind: WitChangeKind::Compatible,
+        path: path.to_string(),
+        description: description.into(),
+    }
+}
+
+fn breaking(path: &str, description: impl Into<String>) -> WitChange {
+    WitChange {
+        kind: WitChangeKind::Breaking,
+        path: path.to_string(),
+        description: description.into(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::model::wit_compat::{compare_exports, WitChange, WitChangeKind};
+    use assert2::assert;
+    use golem_wasm_ast::analysis::analysed_type::{
+        case, field, r#enum, record, str, u32, u64, unit_case, variant,
+    };
+    use golem_wasm_ast::analysis::{
+        AnalysedExport, AnalysedFunction, AnalysedFunctionParameter, AnalysedFunctionResult,
+        AnalysedInstance, AnalysedType,
+    };
+    use test_r::test;
+
+    fn function(name: &str, parameters: Vec<(&str, AnalysedType)>) -> AnalysedFunction {
+        AnalysedFunction {
+            name: name.to_string(),
+            parameters: parameters
+                .into_iter()
+                .map(|(name, typ)| AnalysedFunctionParameter {
+                    name: name.to_string(),
+                    typ,
+                })
+                .collect(),
+            results: vec![AnalysedFunctionResult {
+                name: None,
+                typ: variant(vec![case("ok", u32()), unit_case("failed")]),
+            }],
+        }
+    }
+
+    fn api(functions: Vec<AnalysedFunction>) -> Vec<AnalysedExport> {
+        vec![AnalysedExport::Instance(AnalysedInstance {
+            name: "golem:it/api".to_string(),
+            functions,
+        })]
+    }
+
+    fn breaking_changes(changes: &[WitChange]) -> Vec<String> {
+        changes
+            .iter()
+            .filter(|change| change.kind == WitChangeKind::Breaking)
+            .map(|change| format!("{}: {}", change.path, change.description))
+            .collect()
+    }
+
+    #[test]
+    fn unchanged_exports_have_no_changes() {
+        let exports = api(vec![function("add", vec![("item", str())])]);
+        assert!(compare_exports(&exports, &exports).is_empty());
+    }
+
+    #[test]
+    fn added_functions_and_appended_cases_are_compatible() {
+        let deployed = api(vec![function("add", vec![("status", r#enum(&["open"]))])]);
+        let local = api(vec![
+            function("add", vec![("status", r#enum(&["open", "closed"]))]),
+            function("remove", vec![]),
+        ]);
+
+        let changes = compare_exports(&deployed, &local);
+        assert!(breaking_changes(&changes).is_empty());
+        assert!(changes.len() == 2);
+    }
+
+    #[test]
+    fn removed_functions_and_changed_parameters_are_breaking() {
+        let deployed = api(vec![
+            function("add", vec![("count", u32())]),
+            function("remove", vec![]),
+        ]);
+        let local = api(vec![function("add", vec![("count", u64())])]);
+
+        assert!(
+            breaking_changes(&compare_exports(&deployed, &local))
+                == vec![
+                    "golem:it/api.{add} parameter count: type changed from u32 to u64",
+                    "golem:it/api.{remove}: function removed",
+                ]
+        );
+    }
+
+    #[test]
+    fn record_field_changes_and_moved_cases_are_breaking() {
+        let deployed = api(vec![function(
+            "add",
+            vec![
+                ("item", record(vec![field("name", str())])),
+                ("status", r#enum(&["open", "closed"])),
+            ],
+        )]);
+        let local = api(vec![function(
+            "add",
+            vec![
+                (
+                    "item",
+                    record(vec![field("name", str()), field("count", u32())]),
+                ),
+                ("status", r#enum(&["closed", "open"])),
+            ],
+        )]);
+
+        assert!(
+            breaking_changes(&compare_exports(&deployed, &local))
+                == vec![
+                    "golem:it/api.{add} parameter item: record fields changed from (name) to (name, count)",
+                    "golem:it/api.{add} parameter status: case open moved",
+                    "golem:it/api.{add} parameter status: case closed moved",
+                ]
+        );
+    }
+}
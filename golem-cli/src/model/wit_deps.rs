@@ -0,0 +1,117 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::fs;
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+pub static WIT_DEPS_LOCK_FILE_NAME: &str = "wit-deps.lock.yaml";
+
+/// Pins the WIT packages of the shared wit deps dir, keyed by unversioned package name
+/// (e.g. wasi:io), so every component copy can be kept on the same version
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WitDepsLock {
+    #[serde(default)]
+    pub packages: BTreeMap<String, WitDepsLockEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WitDepsLockEntry {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// Directory of the package in the shared wit deps dir
+    pub dir: String,
+    /// Local directory or archive the package was added from, relative to the lock file if possible
+    pub source: String,
+    /// Package directory inside the source, used for transitive dependencies
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_dir: Option<String>,
+    /// Blake3 hash of the package files
+    pub hash: String,
+}
+
+impl WitDepsLock {
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        serde_yaml::from_str(&fs::read_to_string(path)?)
+            .with_context(|| format!("Failed to parse wit deps lock file {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        fs::write_str(path, serde_yaml::to_string(self)?)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WitDepStatus {
+    /// The shared copy matches the locked hash
+    Locked,
+    /// The shared copy was changed since it was locked
+    Modified,
+    /// The package is locked, but missing from the shared wit deps dir
+    Missing,
+    /// The package is in the shared wit deps dir, but not locked
+    Unlocked,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WitDepView {
+    pub package: String,
+    pub version: Option<String>,
+    pub dir: String,
+    pub source: Option<String>,
+    pub status: WitDepStatus,
+    /// Components using interfaces of the package
+    pub used_by: Vec<String>,
+    /// Component copies of the package which differ from the shared copy
+    pub out_of_sync_copies: Vec<String>,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::model::wit_deps::{WitDepsLock, WitDepsLockEntry};
+    use assert2::assert;
+    use test_r::test;
+
+    #[test]
+    fn lock_roundtrip() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("wit-deps.lock.yaml");
+
+        assert!(WitDepsLock::load(&path).unwrap() == WitDepsLock::default());
+
+        let mut lock = WitDepsLock::default();
+        lock.packages.insert(
+            "wasi:io".to_string(),
+            WitDepsLockEntry {
+                version: Some("0.2.0".to_string()),
+                dir: "io".to_string(),
+                source: "vendor/wasi-http.zip".to_string(),
+                source_dir: Some("deps/io".to_string()),
+                hash: "abcd".to_string(),
+            },
+        );
+        lock.save(&path).unwrap();
+
+        assert!(WitDepsLock::load(&path).unwrap() == lock);
+    }
+}
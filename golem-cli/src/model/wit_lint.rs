@@ -0,0 +1,70 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WitLintKind {
+    /// The same interface name is used by multiple packages of the RPC dependencies of a component,
+    /// which collide in the generated clients
+    DuplicateInterfaceName,
+    /// A type imported with `use` is not used in the interface or world
+    UnusedImport,
+    /// An exported resource has no constructor, so it cannot be created through RPC
+    ResourceWithoutConstructor,
+    /// An exported function uses types which cannot be represented in WAVE
+    WaveIncompatibleType,
+    /// The main package has no version
+    UnversionedPackage,
+}
+
+impl Display for WitLintKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let kind = match self {
+            WitLintKind::DuplicateInterfaceName => "duplicate-interface-name",
+            WitLintKind::UnusedImport => "unused-import",
+            WitLintKind::ResourceWithoutConstructor => "resource-without-constructor",
+            WitLintKind::WaveIncompatibleType => "wave-incompatible-type",
+            WitLintKind::UnversionedPackage => "unversioned-package",
+        };
+        write!(f, "{}", kind)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WitSourceLocation {
+    pub file: PathBuf,
+    /// 1-based line number
+    pub line: usize,
+}
+
+impl Display for WitSourceLocation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.file.display(), self.line)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WitLintFinding {
+    pub component: String,
+    pub kind: WitLintKind,
+    pub message: String,
+    /// Location of the offending item in the source WIT, if it could be found
+    pub location: Option<WitSourceLocation>,
+}
@@ -173,25 +173,10 @@ pub fn generate_client_cargo_toml(def: &StubDefinition) -> anyhow::Result<()> {
     };
     manifest.lib = Some(lib);
 
-    manifest.profile = Profiles {
-        release: Some(Profile {
-            lto: Some(LtoSetting::Fat),
-            opt_level: Some(Value::String("s".to_string())),
-            debug: None,
-            split_debuginfo: None,
-            rpath: None,
-            debug_assertions: None,
-            codegen_units: None,
-            panic: None,
-            incremental: None,
-            overflow_checks: None,
-            strip: Some(StripSetting::Symbols),
-            package: BTreeMap::new(),
-            build_override: None,
-            inherits: None,
-        }),
-        ..Default::default()
-    };
+    // Profiles are only applied from the workspace root
+    if !def.config.client_workspace_member {
+        manifest.profile = client_profiles();
+    }
 
     let dep_wit_bindgen = Dependency::Detailed(Box::new(DependencyDetail {
         version: Some(WIT_BINDGEN_VERSION.to_string()),
@@ -242,6 +227,68 @@ pub fn generate_client_cargo_toml(def: &StubDefinition) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Generates the root Cargo.toml of a workspace for building multiple clients with a single cargo invocation,
+/// so the dependencies are only built once, and the target directory is shared
+pub fn generate_client_workspace_cargo_toml(
+    workspace_root: &Path,
+    member_dirs: &[PathBuf],
+) -> anyhow::Result<()> {
+    let mut members = Vec::with_capacity(member_dirs.len());
+    for member_dir in member_dirs {
+        let member = member_dir.strip_prefix(workspace_root).with_context(|| {
+            anyhow!(
+                "Client crate {} is not inside the client workspace {}",
+                member_dir.log_color_highlight(),
+                workspace_root.log_color_highlight()
+            )
+        })?;
+        members.push(member.to_string_lossy().replace('\\', "/"));
+    }
+
+    let manifest: Manifest = Manifest {
+        workspace: Some(Workspace {
+            members,
+            resolver: Some(cargo_toml::Resolver::V2),
+            ..Default::default()
+        }),
+        profile: client_profiles(),
+        ..Default::default()
+    };
+
+    let cargo_toml_path = workspace_root.join("Cargo.toml");
+    log_action(
+        "Generating",
+        format!(
+            "client workspace Cargo.toml to {}",
+            cargo_toml_path.log_color_highlight()
+        ),
+    );
+    fs::write(cargo_toml_path, toml::to_string(&manifest)?)?;
+    Ok(())
+}
+
+fn client_profiles() -> Profiles {
+    Profiles {
+        release: Some(Profile {
+            lto: Some(LtoSetting::Fat),
+            opt_level: Some(Value::String("s".to_string())),
+            debug: None,
+            split_debuginfo: None,
+            rpath: None,
+            debug_assertions: None,
+            codegen_units: None,
+            panic: None,
+            incremental: None,
+            overflow_checks: None,
+            strip: Some(StripSetting::Symbols),
+            package: BTreeMap::new(),
+            build_override: None,
+            inherits: None,
+        }),
+        ..Default::default()
+    }
+}
+
 pub fn is_cargo_component_toml(path: &Path) -> anyhow::Result<bool> {
     let manifest: Manifest<MetadataRoot> = Manifest::from_path_with_metadata(path)?;
 
@@ -2,6 +2,7 @@ use crate::fs;
 use crate::fs::PathExtra;
 use crate::log::{log_warn_action, LogColorize};
 use anyhow::Context;
+use serde::{Deserialize, Serialize};
 use std::collections::{BTreeMap, BTreeSet};
 use std::path::{Path, PathBuf};
 use wac_graph::types::{Package, SubtypeChecker};
@@ -48,7 +49,8 @@ pub async fn compose(
 }
 
 // Based on https://github.com/bytecodealliance/wac/blob/release-0.6.0/crates/wac-graph/src/plug.rs#L23
-// but instead of returning NoPlugError, it logs skipped instantiations
+// but instead of returning NoPlugError, it logs skipped instantiations.
+// When multiple plugs export the same instance, the first one is used, see [explain_composition].
 fn plug(
     graph: &mut CompositionGraph,
     plugs: Vec<(String, PackageId)>,
@@ -62,23 +64,23 @@ fn plug(
     for (plug_name, plug) in plugs {
         requested_plugs.insert(plug_name.clone());
 
-        let mut plug_exports = Vec::new();
-        let mut cache = Default::default();
-        let mut checker = SubtypeChecker::new(&mut cache);
-        for (name, plug_ty) in &graph.types()[graph[plug].ty()].exports {
-            if let Some(socket_ty) = graph.types()[graph[socket].ty()].imports.get(name) {
-                if checker
-                    .is_subtype(*plug_ty, graph.types(), *socket_ty, graph.types())
-                    .is_ok()
-                {
-                    plug_exports.push(name.clone());
-                }
-            }
-        }
+        let plug_exports = matching_plug_exports(graph, plug, socket);
 
         // Instantiate the plug component
         let mut plug_instantiation = None;
         for plug_export_name in plug_exports {
+            if let Some(used_plug_name) = plug_exports_to_plug.get(&plug_export_name) {
+                log_warn_action(
+                    "Skipping",
+                    format!(
+                        "{} from {}, already plugged by {}",
+                        plug_export_name.log_color_highlight(),
+                        plug_name.log_color_highlight(),
+                        used_plug_name.log_color_highlight()
+                    ),
+                );
+                continue;
+            }
             plug_exports_to_plug.insert(plug_export_name.clone(), plug_name.clone());
 
             let plug_instantiation =
@@ -129,3 +131,309 @@ fn plug(
 
     Ok(())
 }
+
+/// Exports of the plug which can satisfy imports of the socket
+fn matching_plug_exports(
+    graph: &CompositionGraph,
+    plug: PackageId,
+    socket: PackageId,
+) -> Vec<String> {
+    let mut plug_exports = Vec::new();
+    let mut cache = Default::default();
+    let mut checker = SubtypeChecker::new(&mut cache);
+    for (name, plug_ty) in &graph.types()[graph[plug].ty()].exports {
+        if let Some(socket_ty) = graph.types()[graph[socket].ty()].imports.get(name) {
+            if checker
+                .is_subtype(*plug_ty, graph.types(), *socket_ty, graph.types())
+                .is_ok()
+            {
+                plug_exports.push(name.clone());
+            }
+        }
+    }
+    plug_exports
+}
+
+/// Import / export graph of a composition, as it would be performed by [compose]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompositionExplanation {
+    pub component: String,
+    pub imports: Vec<CompositionImport>,
+    /// Imports of the used plugs, which have to be provided by the host
+    pub plug_imports: Vec<CompositionPlugImport>,
+    pub unused_plugs: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompositionImport {
+    pub name: String,
+    /// The plug satisfying the import, unsatisfied imports have to be provided by the host
+    pub plugged_by: Option<String>,
+    /// All plugs exporting a compatible instance in plug order, the first one is used
+    pub candidates: Vec<String>,
+}
+
+impl CompositionImport {
+    /// Plugs exporting a compatible instance, which are not used for this import
+    pub fn shadowed_candidates(&self) -> &[String] {
+        self.candidates.get(1..).unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompositionPlugImport {
+    pub name: String,
+    /// The used plugs importing it
+    pub plugs: Vec<String>,
+}
+
+/// Explains which imports of the socket would be satisfied by which plug, without composing
+pub fn explain_composition(
+    component: &str,
+    source_wasm: &Path,
+    plug_wasms: &[(String, PathBuf)],
+) -> anyhow::Result<CompositionExplanation> {
+    let mut graph = CompositionGraph::new();
+
+    let socket = fs::read(source_wasm).context("Failed to read socket component")?;
+    let socket = Package::from_bytes("socket", None, socket, graph.types_mut())?;
+    let socket = graph.register_package(socket)?;
+
+    let mut candidates = BTreeMap::<String, Vec<String>>::new();
+    let mut plugs = BTreeMap::<String, PackageId>::new();
+    let mut unused_plugs = Vec::new();
+    for (plug_name, plug_wasm) in plug_wasms {
+        let plug_package = Package::from_file(
+            &plug_wasm.to_string_lossy(),
+            None,
+            plug_wasm,
+            graph.types_mut(),
+        )?;
+        let plug = graph.register_package(plug_package)?;
+        plugs.insert(plug_name.clone(), plug);
+
+        let plug_exports = matching_plug_exports(&graph, plug, socket);
+        if plug_exports.is_empty() {
+            unused_plugs.push(plug_name.clone());
+        }
+        for plug_export_name in plug_exports {
+            candidates
+                .entry(plug_export_name)
+                .or_default()
+                .push(plug_name.clone());
+        }
+    }
+
+    let imports = graph.types()[graph[socket].ty()]
+        .imports
+        .keys()
+        .map(|name| {
+            let candidates = candidates.remove(name).unwrap_or_default();
+            CompositionImport {
+                name: name.clone(),
+                plugged_by: candidates.first().cloned(),
+                candidates,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // Plugs whose exports are all shadowed by earlier plugs are not instantiated
+    let used_plugs = imports
+        .iter()
+        .filter_map(|import| import.plugged_by.as_ref())
+        .collect::<BTreeSet<_>>();
+    for (plug_name, _) in plug_wasms {
+        if !used_plugs.contains(plug_name) && !unused_plugs.contains(plug_name) {
+            unused_plugs.push(plug_name.clone());
+        }
+    }
+
+    let mut plug_imports = BTreeMap::<String, Vec<String>>::new();
+    for (plug_name, _) in plug_wasms {
+        if !used_plugs.contains(plug_name) {
+            continue;
+        }
+        let plug = plugs[plug_name];
+        for name in graph.types()[graph[plug].ty()].imports.keys() {
+            plug_imports
+                .entry(name.clone())
+                .or_default()
+                .push(plug_name.clone());
+        }
+    }
+
+    Ok(CompositionExplanation {
+        component: component.to_string(),
+        imports,
+        plug_imports: plug_imports
+            .into_iter()
+            .map(|(name, plugs)| CompositionPlugImport { name, plugs })
+            .collect(),
+        unused_plugs,
+    })
+}
+
+impl CompositionExplanation {
+    /// Renders the graph in Graphviz DOT format, edges point from plugs to the plugged component,
+    /// unsatisfied imports point from the host
+    pub fn to_dot(&self) -> String {
+        fn quote(value: &str) -> String {
+            format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+        }
+
+        let mut dot = String::new();
+        dot.push_str(&format!("digraph {} {{\n", quote(&self.component)));
+        dot.push_str("  rankdir=LR;\n");
+        dot.push_str("  node [shape=box];\n");
+        dot.push_str(&format!("  {} [style=bold];\n", quote(&self.component)));
+
+        let mut plugs = BTreeSet::new();
+        let mut has_host_imports = !self.plug_imports.is_empty();
+        for import in &self.imports {
+            match &import.plugged_by {
+                Some(plug) => {
+                    plugs.insert(plug.as_str());
+                }
+                None => has_host_imports = true,
+            }
+        }
+        for plug in &plugs {
+            dot.push_str(&format!("  {};\n", quote(plug)));
+        }
+        if has_host_imports {
+            dot.push_str("  \"<host>\" [style=dashed];\n");
+        }
+        for plug in &self.unused_plugs {
+            dot.push_str(&format!("  {} [style=dotted];\n", quote(plug)));
+        }
+
+        for import in &self.imports {
+            match &import.plugged_by {
+                Some(plug) => dot.push_str(&format!(
+                    "  {} -> {} [label={}];\n",
+                    quote(plug),
+                    quote(&self.component),
+                    quote(&import.name)
+                )),
+                None => dot.push_str(&format!(
+                    "  \"<host>\" -> {} [label={}, style=dashed];\n",
+                    quote(&self.component),
+                    quote(&import.name)
+                )),
+            }
+        }
+        for import in &self.plug_imports {
+            for plug in &import.plugs {
+                dot.push_str(&format!(
+                    "  \"<host>\" -> {} [label={}, style=dashed];\n",
+                    quote(plug),
+                    quote(&import.name)
+                ));
+            }
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::wasm_rpc_stubgen::commands::composition::{
+        CompositionExplanation, CompositionImport, CompositionPlugImport,
+    };
+    use assert2::assert;
+    use indoc::indoc;
+    use serde_json::json;
+    use test_r::test;
+
+    fn explanation() -> CompositionExplanation {
+        CompositionExplanation {
+            component: "app:caller".to_string(),
+            imports: vec![
+                CompositionImport {
+                    name: "app:callee-client/callee-client".to_string(),
+                    plugged_by: Some("app:callee (client)".to_string()),
+                    candidates: vec![
+                        "app:callee (client)".to_string(),
+                        "app:callee-copy (client)".to_string(),
+                    ],
+                },
+                CompositionImport {
+                    name: "wasi:io/streams@0.2.0".to_string(),
+                    plugged_by: None,
+                    candidates: vec![],
+                },
+            ],
+            plug_imports: vec![CompositionPlugImport {
+                name: "golem:rpc/types@0.2.0".to_string(),
+                plugs: vec!["app:callee (client)".to_string()],
+            }],
+            unused_plugs: vec![
+                "app:callee-copy (client)".to_string(),
+                "app:lib (library)".to_string(),
+            ],
+        }
+    }
+
+    #[test]
+    fn explanation_shadowed_candidates() {
+        let explanation = explanation();
+
+        assert!(explanation.imports[0].shadowed_candidates() == ["app:callee-copy (client)"]);
+        assert!(explanation.imports[1].shadowed_candidates().is_empty());
+    }
+
+    #[test]
+    fn explanation_to_json() {
+        assert!(
+            serde_json::to_value(explanation()).unwrap()
+                == json!({
+                    "component": "app:caller",
+                    "imports": [
+                        {
+                            "name": "app:callee-client/callee-client",
+                            "pluggedBy": "app:callee (client)",
+                            "candidates": ["app:callee (client)", "app:callee-copy (client)"]
+                        },
+                        {
+                            "name": "wasi:io/streams@0.2.0",
+                            "pluggedBy": null,
+                            "candidates": []
+                        }
+                    ],
+                    "plugImports": [
+                        {
+                            "name": "golem:rpc/types@0.2.0",
+                            "plugs": ["app:callee (client)"]
+                        }
+                    ],
+                    "unusedPlugs": ["app:callee-copy (client)", "app:lib (library)"]
+                })
+        );
+    }
+
+    #[test]
+    fn explanation_to_dot() {
+        assert!(
+            explanation().to_dot()
+                == indoc! {r#"
+                    digraph "app:caller" {
+                      rankdir=LR;
+                      node [shape=box];
+                      "app:caller" [style=bold];
+                      "app:callee (client)";
+                      "<host>" [style=dashed];
+                      "app:callee-copy (client)" [style=dotted];
+                      "app:lib (library)" [style=dotted];
+                      "app:callee (client)" -> "app:caller" [label="app:callee-client/callee-client"];
+                      "<host>" -> "app:caller" [label="wasi:io/streams@0.2.0", style=dashed];
+                      "<host>" -> "app:callee (client)" [label="golem:rpc/types@0.2.0", style=dashed];
+                    }
+                "#}
+        );
+    }
+}
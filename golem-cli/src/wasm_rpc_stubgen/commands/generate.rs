@@ -14,16 +14,19 @@
 
 use crate::fs;
 use crate::log::{log_action, LogColorize, LogIndent};
-use crate::wasm_rpc_stubgen::cargo::generate_client_cargo_toml;
+use crate::wasm_rpc_stubgen::cargo::{
+    generate_client_cargo_toml, generate_client_workspace_cargo_toml,
+};
 use crate::wasm_rpc_stubgen::compilation::compile;
 use crate::wasm_rpc_stubgen::naming;
 use crate::wasm_rpc_stubgen::rust::generate_stub_source;
 use crate::wasm_rpc_stubgen::stub::StubDefinition;
+use crate::wasm_rpc_stubgen::typescript::{generate_client_wrapper_sources, ClientWrapperLanguage};
 use crate::wasm_rpc_stubgen::wit_generate::{
     add_dependencies_to_stub_wit_dir, generate_client_wit_to_target,
 };
 use crate::wasm_rpc_stubgen::wit_resolve::ResolvedWitDir;
-use anyhow::{anyhow, Context};
+use anyhow::{anyhow, bail, Context};
 use fs_extra::dir::CopyOptions;
 use heck::ToSnakeCase;
 use std::path::{Path, PathBuf};
@@ -42,12 +45,93 @@ pub async fn build(
     offline: bool,
 ) -> anyhow::Result<()> {
     let wasm_path = generate_and_build_client(stub_def, offline).await?;
+    copy_client_outputs(
+        &stub_def.config.client_root,
+        &wasm_path,
+        dest_wasm,
+        dest_wit_root,
+    )
+}
+
+/// Client crate generated with [generate_client], to be built in a client workspace
+#[derive(Clone)]
+pub struct ClientWorkspaceMember {
+    pub client_root: PathBuf,
+    pub client_crate_name: String,
+}
+
+impl ClientWorkspaceMember {
+    pub fn new(stub_def: &StubDefinition) -> Self {
+        Self {
+            client_root: stub_def.config.client_root.clone(),
+            client_crate_name: stub_def.client_crate_name(),
+        }
+    }
+
+    pub fn wasm_path(&self, workspace_root: &Path) -> PathBuf {
+        client_wasm_path(workspace_root, &self.client_crate_name)
+    }
 
+    /// Copies the built client WASM and its WIT files to the destination
+    pub fn copy_outputs(
+        &self,
+        workspace_root: &Path,
+        dest_wasm: &Path,
+        dest_wit_root: &Path,
+    ) -> anyhow::Result<()> {
+        copy_client_outputs(
+            &self.client_root,
+            &self.wasm_path(workspace_root),
+            dest_wasm,
+            dest_wit_root,
+        )
+    }
+}
+
+/// Builds all the generated client crates as members of a single Cargo workspace,
+/// the client crates have to be inside the workspace root
+pub async fn build_client_workspace(
+    workspace_root: &Path,
+    members: &[ClientWorkspaceMember],
+    offline: bool,
+) -> anyhow::Result<()> {
+    fs::create_dir_all(workspace_root)?;
+    generate_client_workspace_cargo_toml(
+        workspace_root,
+        &members
+            .iter()
+            .map(|member| member.client_root.clone())
+            .collect::<Vec<_>>(),
+    )
+    .context("Failed to generate the client workspace Cargo.toml file")?;
+
+    compile(
+        &workspace_root
+            .canonicalize()
+            .with_context(|| {
+                anyhow!(
+                    "Failed to canonicalize client workspace root {}",
+                    workspace_root.log_color_error_highlight()
+                )
+            })?
+            .join("Cargo.toml"),
+        offline,
+    )
+    .await
+    .context("Failed to compile the generated clients")
+}
+
+fn copy_client_outputs(
+    client_root: &Path,
+    wasm_path: &Path,
+    dest_wasm: &Path,
+    dest_wit_root: &Path,
+) -> anyhow::Result<()> {
     fs::copy(wasm_path, dest_wasm).context("Failed to copy the WASM file to the destination")?;
     fs::create_dir_all(dest_wit_root).context("Failed to create the target WIT root directory")?;
 
     fs_extra::dir::copy(
-        stub_def.config.client_root.join(naming::wit::WIT_DIR),
+        client_root.join(naming::wit::WIT_DIR),
         dest_wit_root,
         &CopyOptions::new().content_only(true).overwrite(true),
     )
@@ -56,6 +140,14 @@ pub async fn build(
     Ok(())
 }
 
+fn client_wasm_path(target_root: &Path, client_crate_name: &str) -> PathBuf {
+    target_root
+        .join("target")
+        .join("wasm32-wasip1")
+        .join("release")
+        .join(format!("{}.wasm", client_crate_name.to_snake_case()))
+}
+
 pub fn generate_and_copy_client_wit(
     stub_def: &StubDefinition,
     dest_wit_root: &Path,
@@ -71,6 +163,33 @@ pub fn generate_and_copy_client_wit(
     Ok(())
 }
 
+/// Writes the TypeScript or JavaScript wrapper modules of the client to the target dir, the
+/// client WIT itself is added to the generated WIT of the caller separately
+pub fn generate_client_wrappers(
+    stub_def: &StubDefinition,
+    language: ClientWrapperLanguage,
+    target_dir: &Path,
+) -> anyhow::Result<()> {
+    log_action(
+        "Generating",
+        format!(
+            "client wrapper for {} to {}",
+            stub_def
+                .source_package_name
+                .to_string()
+                .log_color_highlight(),
+            target_dir.log_color_highlight()
+        ),
+    );
+    fs::create_dir_all(target_dir)?;
+    for file in generate_client_wrapper_sources(stub_def, language)
+        .context("Failed to generate the client wrapper sources")?
+    {
+        fs::write_str(target_dir.join(file.file_name), file.content)?;
+    }
+    Ok(())
+}
+
 pub async fn generate_and_build_client(
     stub_def: &StubDefinition,
     offline: bool,
@@ -89,23 +208,85 @@ pub async fn generate_and_build_client(
                     "Failed to canonicalize client target root {}",
                     stub_def.config.client_root.log_color_error_highlight()
                 )
-            })?,
+            })?
+            .join("Cargo.toml"),
         offline,
     )
     .await
     .context("Failed to compile the generated client")?;
 
-    let wasm_path = stub_def
-        .config
-        .client_root
-        .join("target")
-        .join("wasm32-wasip1")
-        .join("release")
-        .join(format!(
-            "{}.wasm",
-            stub_def.client_crate_name().to_snake_case()
-        ));
-    Ok(wasm_path)
+    Ok(client_wasm_path(
+        &stub_def.config.client_root,
+        &stub_def.client_crate_name(),
+    ))
+}
+
+/// Marks directories written by [export_client], so later exports can replace their content
+pub const EXPORTED_CLIENT_MARKER_FILE: &str = ".golem-client-export";
+
+/// Writes a self-contained client package to the client root: the client WIT with its deps,
+/// the Rust stub crate, and optionally the prebuilt client WASM. Returns the path of the WASM
+/// if it was built.
+///
+/// The client root must be new or empty, or contain a previous export.
+pub async fn export_client(
+    stub_def: &StubDefinition,
+    build_wasm: bool,
+    offline: bool,
+) -> anyhow::Result<Option<PathBuf>> {
+    if !stub_def.config.seal_cargo_workspace {
+        bail!("Exported client crates must be generated with their own Cargo workspace");
+    }
+
+    prepare_client_export_dir(&stub_def.config.client_root)?;
+
+    // Cleaning previously exported sources, so removed interfaces do not linger in the package
+    fs::remove(stub_def.config.client_root.join(naming::wit::WIT_DIR))?;
+    fs::remove(stub_def.config.client_root.join("src"))?;
+
+    if !build_wasm {
+        generate_client(stub_def)?;
+        return Ok(None);
+    }
+
+    let wasm_path = generate_and_build_client(stub_def, offline).await?;
+    let dest_wasm = stub_def.config.client_root.join(format!(
+        "{}.wasm",
+        stub_def.client_crate_name().to_snake_case()
+    ));
+    fs::copy(&wasm_path, &dest_wasm).context("Failed to copy the client WASM to the package")?;
+    fs::remove(stub_def.config.client_root.join("target"))?;
+
+    Ok(Some(dest_wasm))
+}
+
+fn prepare_client_export_dir(client_root: &Path) -> anyhow::Result<()> {
+    let marker = client_root.join(EXPORTED_CLIENT_MARKER_FILE);
+    if client_root.exists() {
+        if !client_root.is_dir() {
+            bail!(
+                "Client output path {} is not a directory",
+                client_root.log_color_error_highlight()
+            );
+        }
+        let is_empty = client_root
+            .read_dir()
+            .with_context(|| anyhow!("Failed to read {}", client_root.log_color_highlight()))?
+            .next()
+            .is_none();
+        if !is_empty && !marker.exists() {
+            bail!(
+                "Client output directory {} is not empty and does not contain a previously exported client, use a new or empty directory",
+                client_root.log_color_error_highlight()
+            );
+        }
+    }
+
+    fs::create_dir_all(client_root)?;
+    fs::write_str(
+        &marker,
+        "Exported client package, the wit, src and target directories are replaced on every export\n",
+    )
 }
 
 pub fn generate_client_wit_dir(stub_def: &StubDefinition) -> anyhow::Result<ResolvedWitDir> {
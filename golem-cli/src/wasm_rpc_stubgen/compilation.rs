@@ -12,18 +12,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use anyhow::anyhow;
 use cargo_component::config::{CargoArguments, Config};
 use cargo_component::{load_component_metadata, load_metadata, run_cargo_command};
 use cargo_component_core::terminal::{Color, Terminal, Verbosity};
 use std::path::Path;
 
-pub async fn compile(root: &Path, offline: bool) -> anyhow::Result<()> {
-    let current_dir = std::env::current_dir()?;
-    std::env::set_current_dir(root)?;
+/// Builds the Cargo package or workspace of the given manifest with cargo-component.
+///
+/// The manifest path and the target dir are passed explicitly to cargo, so the current
+/// working directory of the process is neither used nor changed, and concurrent builds
+/// of different manifests are safe.
+pub async fn compile(manifest_path: &Path, offline: bool) -> anyhow::Result<()> {
+    let target_dir = manifest_path
+        .parent()
+        .ok_or_else(|| anyhow!("Invalid manifest path: {}", manifest_path.display()))?
+        .join("target");
 
     let cargo_args = CargoArguments {
         release: true,
-        manifest_path: Some(root.join("Cargo.toml")),
+        manifest_path: Some(manifest_path.to_path_buf()),
         offline,
         ..Default::default()
     };
@@ -35,7 +43,14 @@ pub async fn compile(root: &Path, offline: bool) -> anyhow::Result<()> {
     let packages =
         load_component_metadata(&metadata, cargo_args.packages.iter(), cargo_args.workspace)?;
 
-    let mut spawn_args = vec!["build".to_string(), "--release".to_string()];
+    let mut spawn_args = vec![
+        "build".to_string(),
+        "--release".to_string(),
+        "--manifest-path".to_string(),
+        manifest_path.to_string_lossy().to_string(),
+        "--target-dir".to_string(),
+        target_dir.to_string_lossy().to_string(),
+    ];
     if offline {
         spawn_args.push("--offline".to_string());
     }
@@ -51,6 +66,5 @@ pub async fn compile(root: &Path, offline: bool) -> anyhow::Result<()> {
     )
     .await?;
 
-    std::env::set_current_dir(current_dir)?;
     Ok(())
 }
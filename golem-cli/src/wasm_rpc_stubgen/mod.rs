@@ -18,6 +18,7 @@ pub mod compilation;
 pub mod naming;
 pub mod rust;
 pub mod stub;
+pub mod typescript;
 pub mod wit_encode;
 pub mod wit_generate;
 pub mod wit_resolve;
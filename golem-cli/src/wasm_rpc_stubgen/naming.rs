@@ -200,3 +200,106 @@ pub mod rust {
         )
     }
 }
+
+pub mod typescript {
+    use heck::{ToLowerCamelCase, ToUpperCamelCase};
+
+    pub static GENERATED_CLIENTS_DIR: &str = "src/generated-clients";
+
+    pub static RESERVED_WORDS: &[&str] = &[
+        "arguments",
+        "await",
+        "break",
+        "case",
+        "catch",
+        "class",
+        "const",
+        "continue",
+        "debugger",
+        "default",
+        "delete",
+        "do",
+        "else",
+        "enum",
+        "eval",
+        "export",
+        "extends",
+        "false",
+        "finally",
+        "for",
+        "function",
+        "if",
+        "implements",
+        "import",
+        "in",
+        "instanceof",
+        "interface",
+        "let",
+        "new",
+        "null",
+        "package",
+        "private",
+        "protected",
+        "public",
+        "return",
+        "static",
+        "super",
+        "switch",
+        "this",
+        "throw",
+        "true",
+        "try",
+        "typeof",
+        "var",
+        "void",
+        "while",
+        "with",
+        "yield",
+    ];
+
+    /// Module name of the wrapper generated for the client of a component package, e.g.
+    /// `app-callee-client` for `app:callee`
+    pub fn client_module_name(source_package_name: &wit_parser::PackageName) -> String {
+        format!(
+            "{}-{}-client",
+            source_package_name.namespace, source_package_name.name
+        )
+    }
+
+    /// Import specifier of the client interface, as used by jco
+    pub fn client_import_specifier(
+        client_package_name: &wit_parser::PackageName,
+        client_interface_name: &str,
+    ) -> String {
+        format!(
+            "{}:{}/{}{}",
+            client_package_name.namespace,
+            client_package_name.name,
+            client_interface_name,
+            client_package_name
+                .version
+                .as_ref()
+                .map(|version| format!("@{}", version))
+                .unwrap_or_default()
+        )
+    }
+
+    /// Class and type names, following the jco conventions
+    pub fn type_name(wit_name: &str) -> String {
+        wit_name.to_upper_camel_case()
+    }
+
+    /// Function and method names, following the jco conventions
+    pub fn function_name(wit_name: &str) -> String {
+        wit_name.to_lower_camel_case()
+    }
+
+    pub fn param_name(wit_name: &str) -> String {
+        let name = wit_name.to_lower_camel_case();
+        if RESERVED_WORDS.contains(&name.as_str()) {
+            format!("{}_", name)
+        } else {
+            name
+        }
+    }
+}
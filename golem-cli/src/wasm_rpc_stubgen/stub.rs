@@ -39,6 +39,9 @@ pub struct StubConfig {
     pub golem_rust_override: RustDependencyOverride,
     pub extract_source_exports_package: bool,
     pub seal_cargo_workspace: bool,
+    /// The client crate is built as a member of a shared client workspace, so the release profile
+    /// is only defined in the workspace root
+    pub client_workspace_member: bool,
     pub component_name: AppComponentName,
     pub is_ephemeral: bool,
 }
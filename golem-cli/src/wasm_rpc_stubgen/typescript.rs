@@ -0,0 +1,450 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::wasm_rpc_stubgen::naming;
+use crate::wasm_rpc_stubgen::stub::{
+    FunctionParamStub, FunctionResultStub, FunctionStub, InterfaceStub, StubDefinition,
+};
+use anyhow::{anyhow, bail};
+use std::fmt::Write;
+use wit_parser::{Handle, Type, TypeDefKind};
+
+/// Language of the caller component, TypeScript callers get a single typed module, JavaScript
+/// callers get the wrapper module with a separate declaration file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClientWrapperLanguage {
+    TypeScript,
+    JavaScript,
+}
+
+pub struct ClientWrapperFile {
+    pub file_name: String,
+    pub content: String,
+}
+
+/// Generates wrapper modules over the jco bindings of the client interface: one class for each
+/// stubbed interface and resource, with blocking, async and scheduled variants of the functions
+pub fn generate_client_wrapper_sources(
+    def: &StubDefinition,
+    language: ClientWrapperLanguage,
+) -> anyhow::Result<Vec<ClientWrapperFile>> {
+    let module_name = naming::typescript::client_module_name(&def.source_package_name);
+    let import_specifier = naming::typescript::client_import_specifier(
+        &def.client_parser_package_name(),
+        &def.client_interface_name(),
+    );
+    let classes = def
+        .stub_imported_interfaces()
+        .iter()
+        .map(|interface| wrapper_class(def, interface))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let header = format!(
+        "// Generated WASM RPC client wrapper for {}, do not edit\n\n",
+        def.source_package_name
+    );
+
+    match language {
+        ClientWrapperLanguage::TypeScript => {
+            let mut content = header;
+            writeln!(content, "import * as Client from \"{}\";", import_specifier)?;
+            for class in &classes {
+                content.push('\n');
+                render_class(&mut content, class, Render::TypeScript)?;
+            }
+            Ok(vec![ClientWrapperFile {
+                file_name: format!("{}.ts", module_name),
+                content,
+            }])
+        }
+        ClientWrapperLanguage::JavaScript => {
+            let mut source = header.clone();
+            writeln!(source, "import * as Client from \"{}\";", import_specifier)?;
+            let mut declarations = header;
+            writeln!(
+                declarations,
+                "import type * as Client from \"{}\";",
+                import_specifier
+            )?;
+            for class in &classes {
+                source.push('\n');
+                render_class(&mut source, class, Render::JavaScript)?;
+                declarations.push('\n');
+                render_class(&mut declarations, class, Render::Declaration)?;
+            }
+            Ok(vec![
+                ClientWrapperFile {
+                    file_name: format!("{}.js", module_name),
+                    content: source,
+                },
+                ClientWrapperFile {
+                    file_name: format!("{}.d.ts", module_name),
+                    content: declarations,
+                },
+            ])
+        }
+    }
+}
+
+struct WrapperClass {
+    name: String,
+    client_type: String,
+    members: Vec<WrapperMember>,
+}
+
+struct WrapperMember {
+    doc: String,
+    is_static: bool,
+    name: String,
+    params: Vec<(String, String)>,
+    result: Option<String>,
+    call: String,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Render {
+    TypeScript,
+    JavaScript,
+    Declaration,
+}
+
+fn wrapper_class(def: &StubDefinition, interface: &InterfaceStub) -> anyhow::Result<WrapperClass> {
+    let name = naming::typescript::type_name(&interface.name);
+    let client_type = client_type(&interface.name);
+    let mut members = Vec::new();
+
+    let constructor_params = match &interface.constructor_params {
+        Some(params) => wrapper_params(def, params)?,
+        None => vec![],
+    };
+    let constructor_args = constructor_params
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect::<Vec<_>>();
+
+    {
+        let mut params = Vec::new();
+        if !def.config.is_ephemeral {
+            params.push(("workerName".to_string(), "string".to_string()));
+        }
+        params.extend(constructor_params.iter().cloned());
+        members.push(WrapperMember {
+            doc: if def.config.is_ephemeral {
+                "Creates a client for a new ephemeral worker".to_string()
+            } else {
+                "Creates a client for the worker with the given name".to_string()
+            },
+            is_static: true,
+            name: "create".to_string(),
+            call: format!(
+                "new {}(new {}({}))",
+                name,
+                client_type,
+                params
+                    .iter()
+                    .map(|(name, _)| name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            params,
+            result: Some(name.clone()),
+        });
+    }
+
+    {
+        let (target_param, target_type, doc) = if def.config.is_ephemeral {
+            (
+                "componentId",
+                "GolemRpcComponentId",
+                "Creates a client for a new ephemeral worker of the given component",
+            )
+        } else {
+            (
+                "workerId",
+                "GolemRpcWorkerId",
+                "Creates a client for the worker with the given id",
+            )
+        };
+        let mut params = vec![(target_param.to_string(), format!("Client.{}", target_type))];
+        params.extend(constructor_params.iter().cloned());
+        let mut args = vec![target_param];
+        args.extend(constructor_args.iter().copied());
+        members.push(WrapperMember {
+            doc: doc.to_string(),
+            is_static: true,
+            name: "custom".to_string(),
+            params,
+            result: Some(name.clone()),
+            call: format!("new {}({}.custom({}))", name, client_type, args.join(", ")),
+        });
+    }
+
+    members.push(WrapperMember {
+        doc: "Wraps an existing client resource handle, e.g. one returned by another remote call"
+            .to_string(),
+        is_static: true,
+        name: "fromRemoteHandle".to_string(),
+        params: vec![("remote".to_string(), client_type.clone())],
+        result: Some(name.clone()),
+        call: format!("new {}(remote)", name),
+    });
+
+    for (function, is_static) in interface.all_functions() {
+        let params = wrapper_params(def, &function.params)?;
+        let args = params
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect::<Vec<_>>();
+        let target = if is_static {
+            client_type.clone()
+        } else {
+            "this.remote".to_string()
+        };
+        let function_name = naming::typescript::function_name(&function.name);
+
+        members.push(WrapperMember {
+            doc: format!("Calls `{}` and waits for its result", function.name),
+            is_static,
+            name: function_name.clone(),
+            params: params.clone(),
+            result: blocking_result_type(def, function)?,
+            call: format!(
+                "{}.{}({})",
+                target,
+                naming::typescript::function_name(&naming::wit::blocking_function_name(function)),
+                args.join(", ")
+            ),
+        });
+
+        members.push(WrapperMember {
+            doc: if function.results.is_empty() {
+                format!("Triggers `{}` without waiting for it to complete", function.name)
+            } else {
+                format!(
+                    "Calls `{}` without waiting for its result, the returned future can be polled for it",
+                    function.name
+                )
+            },
+            is_static,
+            name: format!("{}Async", function_name),
+            params: params.clone(),
+            result: (!function.results.is_empty())
+                .then(|| client_type(&function.async_result_type(interface))),
+            call: format!("{}.{}({})", target, function_name, args.join(", ")),
+        });
+
+        let mut schedule_params = params.clone();
+        schedule_params.push((
+            "scheduledFor".to_string(),
+            "Client.WasiClocksDatetime".to_string(),
+        ));
+        let mut schedule_args = args.clone();
+        schedule_args.push("scheduledFor");
+        members.push(WrapperMember {
+            doc: format!(
+                "Schedules `{}` to be called at the given time, the returned token can be used to cancel it",
+                function.name
+            ),
+            is_static,
+            name: naming::typescript::function_name(&naming::wit::schedule_function_name(
+                function,
+            )),
+            params: schedule_params,
+            result: Some("Client.GolemRpcCancellationToken".to_string()),
+            call: format!(
+                "{}.{}({})",
+                target,
+                naming::typescript::function_name(&naming::wit::schedule_function_name(function)),
+                schedule_args.join(", ")
+            ),
+        });
+    }
+
+    Ok(WrapperClass {
+        name,
+        client_type,
+        members,
+    })
+}
+
+fn render_class(out: &mut String, class: &WrapperClass, render: Render) -> anyhow::Result<()> {
+    match render {
+        Render::TypeScript => {
+            writeln!(out, "export class {} {{", class.name)?;
+            writeln!(
+                out,
+                "  private constructor(readonly remote: {}) {{}}",
+                class.client_type
+            )?;
+        }
+        Render::JavaScript => {
+            writeln!(out, "export class {} {{", class.name)?;
+            writeln!(out, "  constructor(remote) {{")?;
+            writeln!(out, "    this.remote = remote;")?;
+            writeln!(out, "  }}")?;
+        }
+        Render::Declaration => {
+            writeln!(out, "export declare class {} {{", class.name)?;
+            writeln!(out, "  private constructor();")?;
+            writeln!(out, "  readonly remote: {};", class.client_type)?;
+        }
+    }
+
+    for member in &class.members {
+        writeln!(out)?;
+        writeln!(out, "  /** {} */", member.doc)?;
+
+        let params = member
+            .params
+            .iter()
+            .map(|(name, typ)| match render {
+                Render::TypeScript | Render::Declaration => format!("{}: {}", name, typ),
+                Render::JavaScript => name.clone(),
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let signature = format!(
+            "{}{}({})",
+            if member.is_static { "static " } else { "" },
+            member.name,
+            params
+        );
+        let result = member.result.as_deref().unwrap_or("void");
+
+        match render {
+            Render::TypeScript => writeln!(out, "  {}: {} {{", signature, result)?,
+            Render::JavaScript => writeln!(out, "  {} {{", signature)?,
+            Render::Declaration => {
+                writeln!(out, "  {}: {};", signature, result)?;
+                continue;
+            }
+        }
+        if member.result.is_some() {
+            writeln!(out, "    return {};", member.call)?;
+        } else {
+            writeln!(out, "    {};", member.call)?;
+        }
+        writeln!(out, "  }}")?;
+    }
+
+    writeln!(out, "}}")?;
+    Ok(())
+}
+
+fn wrapper_params(
+    def: &StubDefinition,
+    params: &[FunctionParamStub],
+) -> anyhow::Result<Vec<(String, String)>> {
+    params
+        .iter()
+        .map(|param| {
+            Ok((
+                naming::typescript::param_name(&param.name),
+                ts_type(def, &param.typ)?,
+            ))
+        })
+        .collect()
+}
+
+/// jco returns the ok value of top level result types, and throws the error value
+fn blocking_result_type(
+    def: &StubDefinition,
+    function: &FunctionStub,
+) -> anyhow::Result<Option<String>> {
+    match &function.results {
+        FunctionResultStub::Unit => Ok(None),
+        FunctionResultStub::SelfType => Err(anyhow!("Unexpected self return type")),
+        FunctionResultStub::Anon(typ) => {
+            if let Type::Id(type_id) = typ {
+                let type_def = def.get_type_def(*type_id)?;
+                if let (None, TypeDefKind::Result(result)) = (&type_def.name, &type_def.kind) {
+                    return result.ok.as_ref().map(|ok| ts_type(def, ok)).transpose();
+                }
+            }
+            Ok(Some(ts_type(def, typ)?))
+        }
+    }
+}
+
+fn ts_type(def: &StubDefinition, typ: &Type) -> anyhow::Result<String> {
+    match typ {
+        Type::Bool => Ok("boolean".to_string()),
+        Type::U8
+        | Type::U16
+        | Type::U32
+        | Type::S8
+        | Type::S16
+        | Type::S32
+        | Type::F32
+        | Type::F64 => Ok("number".to_string()),
+        Type::U64 | Type::S64 => Ok("bigint".to_string()),
+        Type::Char | Type::String => Ok("string".to_string()),
+        Type::Id(type_id) => {
+            let type_def = def.get_type_def(*type_id)?;
+
+            if let TypeDefKind::Handle(Handle::Own(resource_id) | Handle::Borrow(resource_id)) =
+                &type_def.kind
+            {
+                let resource = def.get_type_def(*resource_id)?;
+                let resource_name = resource
+                    .name
+                    .as_ref()
+                    .ok_or_else(|| anyhow!("resource has no name"))?;
+                return Ok(client_type(resource_name));
+            }
+
+            if let Some(name) = &type_def.name {
+                return Ok(client_type(
+                    def.get_stub_used_type_alias(*type_id).unwrap_or(name),
+                ));
+            }
+
+            match &type_def.kind {
+                TypeDefKind::Option(inner) => Ok(format!("{} | undefined", ts_type(def, inner)?)),
+                TypeDefKind::List(Type::U8) => Ok("Uint8Array".to_string()),
+                TypeDefKind::List(inner) => Ok(format!("Array<{}>", ts_type(def, inner)?)),
+                TypeDefKind::Tuple(tuple) => Ok(format!(
+                    "[{}]",
+                    tuple
+                        .types
+                        .iter()
+                        .map(|typ| ts_type(def, typ))
+                        .collect::<anyhow::Result<Vec<_>>>()?
+                        .join(", ")
+                )),
+                TypeDefKind::Result(result) => {
+                    let ok = match &result.ok {
+                        Some(ok) => ts_type(def, ok)?,
+                        None => "void".to_string(),
+                    };
+                    let err = match &result.err {
+                        Some(err) => ts_type(def, err)?,
+                        None => "void".to_string(),
+                    };
+                    Ok(format!(
+                        "{{ tag: 'ok', val: {} }} | {{ tag: 'err', val: {} }}",
+                        ok, err
+                    ))
+                }
+                TypeDefKind::Type(inner) => ts_type(def, inner),
+                kind => bail!("Unsupported anonymous type in client wrapper: {:?}", kind),
+            }
+        }
+        Type::ErrorContext => Err(anyhow!("ErrorContext is not supported yet")),
+    }
+}
+
+fn client_type(wit_name: &str) -> String {
+    format!("Client.{}", naming::typescript::type_name(wit_name))
+}
@@ -577,6 +577,7 @@ fn init_stub(name: &str) -> (TempDir, TempDir) {
         golem_rust_override: RustDependencyOverride::default(),
         extract_source_exports_package: true,
         seal_cargo_workspace: false,
+        client_workspace_member: false,
         component_name: AppComponentName::from("test:component"),
         is_ephemeral: false,
     })
@@ -594,6 +595,7 @@ fn regenerate_stub(stub_dir: &Path, source_wit_root: &Path) {
         golem_rust_override: RustDependencyOverride::default(),
         extract_source_exports_package: true,
         seal_cargo_workspace: false,
+        client_workspace_member: false,
         component_name: AppComponentName::from("test:component"),
         is_ephemeral: false,
     })
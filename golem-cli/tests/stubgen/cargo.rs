@@ -1,8 +1,14 @@
-use crate::stubgen::{cargo_component_build, test_data_path};
+use crate::stubgen::{cargo_component_build, golem_rust_override, test_data_path};
 use assert2::check;
 use fs_extra::dir::CopyOptions;
 use golem_cli::fs;
-use golem_cli::wasm_rpc_stubgen::cargo::regenerate_cargo_package_component;
+use golem_cli::model::app::AppComponentName;
+use golem_cli::wasm_rpc_stubgen::cargo::{
+    generate_client_cargo_toml, generate_client_workspace_cargo_toml,
+    regenerate_cargo_package_component,
+};
+use golem_cli::wasm_rpc_stubgen::commands::generate::{export_client, EXPORTED_CLIENT_MARKER_FILE};
+use golem_cli::wasm_rpc_stubgen::stub::{StubConfig, StubDefinition};
 use tempfile::TempDir;
 use test_r::test;
 
@@ -128,3 +134,156 @@ generate_unused_types = true
     check!(cargo_toml.contains("\"test:sub\" = { path = \"wit/deps/sub\" }"));
     check!(cargo_toml.contains("[package.metadata.component.bindings]\nderives = [\"serde::Serialize\", \"serde::Deserialize\"]\ngenerate_unused_types = true"));
 }
+
+#[test]
+fn generate_client_workspace_cargo_toml_with_members() {
+    let workspace_dir = TempDir::new().unwrap();
+    let members = vec![
+        workspace_dir.path().join("comp-a").join("temp-build"),
+        workspace_dir.path().join("comp-b").join("temp-build"),
+    ];
+
+    generate_client_workspace_cargo_toml(workspace_dir.path(), &members).unwrap();
+
+    let cargo_toml = fs::read_to_string(workspace_dir.path().join("Cargo.toml")).unwrap();
+    println!(">\n{}", cargo_toml);
+    let manifest: toml::Table = toml::from_str(&cargo_toml).unwrap();
+    check!(
+        manifest["workspace"]["members"]
+            == toml::Value::Array(vec![
+                toml::Value::String("comp-a/temp-build".to_string()),
+                toml::Value::String("comp-b/temp-build".to_string()),
+            ])
+    );
+    check!(manifest["workspace"]["resolver"] == toml::Value::String("2".to_string()));
+    check!(manifest["profile"]["release"]["opt-level"] == toml::Value::String("s".to_string()));
+    check!(manifest.get("package").is_none());
+
+    check!(generate_client_workspace_cargo_toml(
+        workspace_dir.path(),
+        &[TempDir::new().unwrap().path().to_path_buf()]
+    )
+    .is_err());
+}
+
+#[test]
+fn generate_client_cargo_toml_profiles() {
+    let source_wit_root = test_data_path().join("wit").join("all-wit-types");
+
+    let client_manifest = |client_workspace_member: bool| {
+        let client_root = TempDir::new().unwrap();
+        let def = StubDefinition::new(StubConfig {
+            source_wit_root: source_wit_root.clone(),
+            client_root: client_root.path().to_path_buf(),
+            selected_world: None,
+            stub_crate_version: "1.0.0".to_string(),
+            golem_rust_override: golem_rust_override(),
+            extract_source_exports_package: true,
+            seal_cargo_workspace: false,
+            client_workspace_member,
+            component_name: AppComponentName::from("test:component"),
+            is_ephemeral: false,
+        })
+        .unwrap();
+
+        generate_client_cargo_toml(&def).unwrap();
+
+        let cargo_toml = fs::read_to_string(def.client_cargo_path()).unwrap();
+        println!(">\n{}", cargo_toml);
+        toml::from_str::<toml::Table>(&cargo_toml).unwrap()
+    };
+
+    // Standalone client crates keep the release profile, even if not sealed
+    let manifest = client_manifest(false);
+    check!(manifest.get("workspace").is_none());
+    check!(manifest["profile"]["release"]["opt-level"] == toml::Value::String("s".to_string()));
+    check!(manifest["profile"]["release"].get("lto").is_some());
+    check!(manifest["profile"]["release"].get("strip").is_some());
+
+    // Members of the client workspace get the profile from the workspace root
+    let manifest = client_manifest(true);
+    check!(manifest.get("profile").is_none());
+}
+
+#[test]
+async fn export_client_package() {
+    let source_wit_root = TempDir::new().unwrap();
+    fs_extra::dir::copy(
+        test_data_path().join("wit").join("all-wit-types"),
+        source_wit_root.path(),
+        &CopyOptions::new().content_only(true),
+    )
+    .unwrap();
+
+    let client_root = TempDir::new().unwrap();
+    let client_root = client_root.path().canonicalize().unwrap();
+    // Leftover from a previous export, must not be kept
+    fs::write_str(client_root.join(EXPORTED_CLIENT_MARKER_FILE), "").unwrap();
+    fs::write_str(client_root.join("wit").join("removed.wit"), "").unwrap();
+
+    let def = StubDefinition::new(StubConfig {
+        source_wit_root: source_wit_root.path().to_path_buf(),
+        client_root: client_root.clone(),
+        selected_world: None,
+        stub_crate_version: "0.2.0".to_string(),
+        golem_rust_override: golem_rust_override(),
+        extract_source_exports_package: true,
+        seal_cargo_workspace: true,
+        client_workspace_member: false,
+        component_name: AppComponentName::from("test:component"),
+        is_ephemeral: false,
+    })
+    .unwrap();
+
+    let wasm_path = export_client(&def, false, false).await.unwrap();
+    check!(wasm_path.is_none());
+
+    check!(!client_root.join("wit").join("removed.wit").exists());
+    check!(client_root.join("wit").join("deps").is_dir());
+    check!(client_root.join("src").join("lib.rs").is_file());
+
+    let cargo_toml = fs::read_to_string(client_root.join("Cargo.toml")).unwrap();
+    println!(">\n{}", cargo_toml);
+    let manifest: toml::Table = toml::from_str(&cargo_toml).unwrap();
+    check!(manifest.contains_key("workspace"));
+    check!(manifest["package"]["name"] == toml::Value::String(def.client_crate_name()));
+    check!(manifest["package"]["version"] == toml::Value::String("0.2.0".to_string()));
+}
+
+#[test]
+async fn export_client_keeps_unrelated_dir() {
+    let source_wit_root = TempDir::new().unwrap();
+    fs_extra::dir::copy(
+        test_data_path().join("wit").join("all-wit-types"),
+        source_wit_root.path(),
+        &CopyOptions::new().content_only(true),
+    )
+    .unwrap();
+
+    let client_root = TempDir::new().unwrap();
+    let client_root = client_root.path().canonicalize().unwrap();
+    fs::write_str(client_root.join("notes.txt"), "notes").unwrap();
+    fs::write_str(client_root.join("src").join("main.rs"), "fn main() {}").unwrap();
+
+    let def = StubDefinition::new(StubConfig {
+        source_wit_root: source_wit_root.path().to_path_buf(),
+        client_root: client_root.clone(),
+        selected_world: None,
+        stub_crate_version: "0.2.0".to_string(),
+        golem_rust_override: golem_rust_override(),
+        extract_source_exports_package: true,
+        seal_cargo_workspace: true,
+        client_workspace_member: false,
+        component_name: AppComponentName::from("test:component"),
+        is_ephemeral: false,
+    })
+    .unwrap();
+
+    check!(export_client(&def, false, false).await.is_err());
+
+    check!(fs::read_to_string(client_root.join("notes.txt")).unwrap() == "notes");
+    check!(fs::read_to_string(client_root.join("src").join("main.rs")).unwrap() == "fn main() {}");
+    check!(!client_root.join(EXPORTED_CLIENT_MARKER_FILE).exists());
+    check!(!client_root.join("wit").exists());
+    check!(!client_root.join("Cargo.toml").exists());
+}
@@ -19,7 +19,7 @@
 use crate::stubgen::{cargo_component_build, golem_rust_override, test_data_path};
 use fs_extra::dir::CopyOptions;
 use golem_cli::model::app::AppComponentName;
-use golem_cli::wasm_rpc_stubgen::commands::composition::compose;
+use golem_cli::wasm_rpc_stubgen::commands::composition::{compose, explain_composition};
 use golem_cli::wasm_rpc_stubgen::commands::dependencies::add_stub_dependency;
 use golem_cli::wasm_rpc_stubgen::commands::generate::generate_and_build_client;
 use golem_cli::wasm_rpc_stubgen::stub::{StubConfig, StubDefinition};
@@ -60,6 +60,65 @@ async fn compose_with_single_stub() {
         .unwrap();
 }
 
+#[test]
+async fn compose_with_two_candidate_stubs() {
+    let (_source_dir, stub_dir, stub_wasm) = init_stub("all-wit-types").await;
+    let caller_dir = init_caller("caller-no-dep-importstub");
+
+    add_stub_dependency(
+        &stub_dir.path().join("wit"),
+        &caller_dir.path().join("wit"),
+        UpdateCargoToml::Update,
+    )
+    .unwrap();
+
+    cargo_component_build(caller_dir.path());
+
+    let component_wasm = caller_dir
+        .path()
+        .join("target")
+        .join("wasm32-wasip1")
+        .join("debug")
+        .join("caller_no_dep.wasm");
+
+    // Same client twice, so both plugs export the same instance
+    let second_stub_wasm = stub_dir.path().join("second-stub.wasm");
+    std::fs::copy(&stub_wasm, &second_stub_wasm).unwrap();
+
+    let explanation = explain_composition(
+        "test:caller",
+        &component_wasm,
+        &[
+            ("first".to_string(), stub_wasm.clone()),
+            ("second".to_string(), second_stub_wasm.clone()),
+        ],
+    )
+    .unwrap();
+
+    let plugged_imports = explanation
+        .imports
+        .iter()
+        .filter(|import| import.plugged_by.is_some())
+        .collect::<Vec<_>>();
+    assert!(!plugged_imports.is_empty());
+    for import in plugged_imports {
+        assert_eq!(import.plugged_by.as_deref(), Some("first"));
+        assert_eq!(import.candidates, vec!["first", "second"]);
+    }
+    assert_eq!(explanation.unused_plugs, vec!["second"]);
+    // The client imports the RPC host interfaces, which are left to the host
+    assert!(explanation
+        .plug_imports
+        .iter()
+        .any(|import| import.name.starts_with("golem:rpc/") && import.plugs == vec!["first"]));
+
+    let dest_wasm = caller_dir.path().join("target/result.wasm");
+    compose(&component_wasm, &[stub_wasm, second_stub_wasm], &dest_wasm)
+        .await
+        .unwrap();
+    assert_is_component(&dest_wasm);
+}
+
 async fn init_stub(name: &str) -> (TempDir, TempDir, PathBuf) {
     let source_dir = TempDir::new().unwrap();
     let source_wit_root = source_dir.path().canonicalize().unwrap();
@@ -82,6 +141,7 @@ async fn init_stub(name: &str) -> (TempDir, TempDir, PathBuf) {
         golem_rust_override: golem_rust_override(),
         extract_source_exports_package: true,
         seal_cargo_workspace: true,
+        client_workspace_member: false,
         component_name: AppComponentName::from("test:component"),
         is_ephemeral: false,
     })
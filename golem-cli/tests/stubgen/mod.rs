@@ -20,6 +20,7 @@ mod add_dep;
 mod cargo;
 mod compose;
 mod stub_wasm;
+mod typescript;
 mod wit;
 
 tag_suite!(cargo, uses_cargo);
@@ -53,6 +53,7 @@ async fn all_wit_types() {
         golem_rust_override: golem_rust_override(),
         extract_source_exports_package: true,
         seal_cargo_workspace: false,
+        client_workspace_member: false,
         component_name: AppComponentName::from("test:component"),
         is_ephemeral: false,
     })
@@ -364,6 +365,7 @@ async fn resource() {
         golem_rust_override: golem_rust_override(),
         extract_source_exports_package: true,
         seal_cargo_workspace: false,
+        client_workspace_member: false,
         component_name: AppComponentName::from("test:component"),
         is_ephemeral: false,
     })
@@ -392,6 +394,53 @@ async fn resource() {
     // TODO: asserts for "normal" resource
 }
 
+#[test]
+async fn concurrent_builds() {
+    let current_dir = std::env::current_dir().unwrap();
+
+    let source_wit_roots = [tempdir().unwrap(), tempdir().unwrap()];
+    let target_roots = [tempdir().unwrap(), tempdir().unwrap()];
+
+    let defs = source_wit_roots
+        .iter()
+        .zip(target_roots.iter())
+        .map(|(source_wit_root, target_root)| {
+            fs_extra::dir::copy(
+                test_data_path().join("wit/resources"),
+                source_wit_root.path(),
+                &CopyOptions::new().content_only(true),
+            )
+            .unwrap();
+
+            StubDefinition::new(StubConfig {
+                source_wit_root: source_wit_root.path().to_path_buf(),
+                client_root: target_root.path().canonicalize().unwrap(),
+                selected_world: None,
+                stub_crate_version: "1.0.0".to_string(),
+                golem_rust_override: golem_rust_override(),
+                extract_source_exports_package: true,
+                seal_cargo_workspace: false,
+                client_workspace_member: false,
+                component_name: AppComponentName::from("test:component"),
+                is_ephemeral: false,
+            })
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    let wasm_paths = futures_util::future::try_join_all(
+        defs.iter().map(|def| generate_and_build_client(def, false)),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(std::env::current_dir().unwrap(), current_dir);
+    for (wasm_path, target_root) in wasm_paths.iter().zip(target_roots.iter()) {
+        assert!(wasm_path.starts_with(target_root.path().canonicalize().unwrap()));
+        assert!(wasm_path.exists());
+    }
+}
+
 fn assert_has_rpc_resource_constructor(exported_interface: &AnalysedInstance, name: &str) {
     let fun = exported_interface
         .functions
@@ -0,0 +1,110 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests in this module are verifying the TypeScript and JavaScript client wrappers created by the stub generator
+
+use crate::stubgen::test_data_path;
+use assert2::check;
+use fs_extra::dir::CopyOptions;
+use golem_cli::model::app::AppComponentName;
+use golem_cli::wasm_rpc_stubgen::stub::{RustDependencyOverride, StubConfig, StubDefinition};
+use golem_cli::wasm_rpc_stubgen::typescript::{
+    generate_client_wrapper_sources, ClientWrapperLanguage,
+};
+use tempfile::{tempdir, TempDir};
+use test_r::test;
+
+#[test]
+fn typescript_resource_wrapper() {
+    let (_source_wit_root, _target_root, def) = init_stub_def("resources", false);
+
+    let files = generate_client_wrapper_sources(&def, ClientWrapperLanguage::TypeScript).unwrap();
+    check!(files.len() == 1);
+    check!(files[0].file_name == "test-main-client.ts");
+
+    let source = &files[0].content;
+    println!("{}", source);
+    check!(source.contains("import * as Client from \"test:main-client/api-client\";"));
+    check!(source.contains("export class Resource1 {"));
+    check!(source.contains("  private constructor(readonly remote: Client.Resource1) {}"));
+    check!(source.contains("  static create(workerName: string, name: string): Resource1 {"));
+    check!(source.contains("    return new Resource1(new Client.Resource1(workerName, name));"));
+    check!(source
+        .contains("  static custom(workerId: Client.GolemRpcWorkerId, name: string): Resource1 {"));
+    check!(source.contains("  static fromRemoteHandle(remote: Client.Resource1): Resource1 {"));
+    check!(source.contains("  func1(): void {\n    this.remote.blockingFunc1();"));
+    check!(
+        source.contains("  func2(a: number): number {\n    return this.remote.blockingFunc2(a);")
+    );
+    check!(source.contains(
+        "  func3Async(b: number): Client.FutureResource1Func3Result {\n    return this.remote.func3(b);"
+    ));
+    check!(source.contains("  func4(c: number | undefined): number | undefined {"));
+    check!(source.contains(
+        "  scheduleFunc1(scheduledFor: Client.WasiClocksDatetime): Client.GolemRpcCancellationToken {"
+    ));
+}
+
+#[test]
+fn javascript_wrapper_with_declarations() {
+    let (_source_wit_root, _target_root, def) = init_stub_def("resources", true);
+
+    let files = generate_client_wrapper_sources(&def, ClientWrapperLanguage::JavaScript).unwrap();
+    check!(files.len() == 2);
+    check!(files[0].file_name == "test-main-client.js");
+    check!(files[1].file_name == "test-main-client.d.ts");
+
+    let source = &files[0].content;
+    println!("{}", source);
+    check!(source.contains("import * as Client from \"test:main-client/api-client\";"));
+    check!(source.contains("  static create(name) {"));
+    check!(source.contains("  static custom(componentId, name) {"));
+    check!(source.contains("  func2(a) {\n    return this.remote.blockingFunc2(a);"));
+    check!(!source.contains(": number"));
+
+    let declarations = &files[1].content;
+    println!("{}", declarations);
+    check!(declarations.contains("import type * as Client from \"test:main-client/api-client\";"));
+    check!(declarations.contains("export declare class Resource1 {"));
+    check!(declarations.contains("  static create(name: string): Resource1;"));
+    check!(declarations.contains("  func2(a: number): number;"));
+    check!(!declarations.contains("return"));
+}
+
+fn init_stub_def(name: &str, is_ephemeral: bool) -> (TempDir, TempDir, StubDefinition) {
+    let source_wit_root = tempdir().unwrap();
+    fs_extra::dir::copy(
+        test_data_path().join("wit").join(name),
+        source_wit_root.path(),
+        &CopyOptions::new().content_only(true).overwrite(true),
+    )
+    .unwrap();
+    let target_root = tempdir().unwrap();
+
+    let def = StubDefinition::new(StubConfig {
+        source_wit_root: source_wit_root.path().to_path_buf(),
+        client_root: target_root.path().to_path_buf(),
+        selected_world: None,
+        stub_crate_version: "1.0.0".to_string(),
+        golem_rust_override: RustDependencyOverride::default(),
+        extract_source_exports_package: true,
+        seal_cargo_workspace: false,
+        client_workspace_member: false,
+        component_name: AppComponentName::from("test:component"),
+        is_ephemeral,
+    })
+    .unwrap();
+
+    (source_wit_root, target_root, def)
+}
@@ -39,6 +39,7 @@ fn all_wit_types() {
         golem_rust_override: RustDependencyOverride::default(),
         extract_source_exports_package: true,
         seal_cargo_workspace: false,
+        client_workspace_member: false,
         component_name: AppComponentName::from("test:component"),
         is_ephemeral: false,
     })
@@ -144,6 +145,7 @@ fn many_ways_to_export() {
         golem_rust_override: RustDependencyOverride::default(),
         extract_source_exports_package: true,
         seal_cargo_workspace: false,
+        client_workspace_member: false,
         component_name: AppComponentName::from("test:component"),
         is_ephemeral: false,
     })
@@ -12,74 +12,623 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::config::{default_data_dir, OtlpProtocol, ServerConfig};
+use crate::daemon;
+use crate::daemon::{DaemonGuard, ServerFiles};
+use crate::data_dir;
+use crate::executors::{ExecutorStatus, ExecutorStatusList, EXECUTORS_API_PATH};
+use crate::health::HealthReport;
+use crate::journal;
+use crate::journal::RequestFilter;
 use crate::launch::{launch_golem_services, LaunchArgs};
-use anyhow::anyhow;
+use crate::listener::connect_host;
+use crate::seed::Seed;
+use crate::snapshot;
+use crate::snapshot::SnapshotLayout;
+use crate::telemetry;
+use anyhow::{anyhow, bail, Context as AnyhowContext};
 use clap_verbosity_flag::Verbosity;
-use golem_cli::command::server::ServerSubcommand;
-use golem_cli::command_handler::CommandHandlerHooks;
+use figment::providers::Serialized;
+use figment::Figment;
+use golem_cli::command::server::config::ServerConfigSubcommand;
+use golem_cli::command::server::data::ServerDataSubcommand;
+use golem_cli::command::server::executor::ServerExecutorSubcommand;
+use golem_cli::command::server::snapshot::{ServerDataArgs, ServerSnapshotSubcommand};
+use golem_cli::command::server::{
+    ServerAddressArgs, ServerOtlpProtocol, ServerRunArgs, ServerSubcommand,
+};
+use golem_cli::command_handler::{CommandHandlerHooks, Handlers};
 use golem_cli::context::Context;
+use golem_cli::error::NonSuccessfulExit;
+use golem_cli::log::{log_action, log_warn_action, logln, LogColorize, LogIndent};
+use golem_cli::model::text::fmt::{log_error, log_text_view, log_warn};
+use golem_cli::TracingLayer;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use std::ffi::OsString;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+const SEED_READY_TIMEOUT: Duration = Duration::from_secs(120);
 
 pub struct ServerCommandHandler;
 
 impl CommandHandlerHooks for ServerCommandHandler {
     async fn handler_server_commands(
         &self,
-        _ctx: Arc<Context>,
+        ctx: Arc<Context>,
         subcommand: ServerSubcommand,
     ) -> anyhow::Result<()> {
         match subcommand {
-            ServerSubcommand::Run {
-                router_addr,
-                router_port,
-                custom_request_port,
-                data_dir,
-                clean,
-            } => {
-                let data_dir = match data_dir {
-                    Some(data_dir) => data_dir,
-                    None => default_data_dir()?,
-                };
-                if clean && tokio::fs::metadata(&data_dir).await.is_ok() {
+            ServerSubcommand::Run { args } => {
+                let config = server_config(&args)?;
+                let data_dir = config.data_dir()?;
+                if args.clean && tokio::fs::metadata(&data_dir).await.is_ok() {
                     clean_data_dir(&data_dir).await?;
                 };
+                data_dir::prepare(&data_dir, args.migrate).await?;
+                let _daemon_guard = daemon::is_daemon()
+                    .then(|| DaemonGuard::acquire(data_dir.clone()))
+                    .transpose()?;
+                let launch_args = LaunchArgs::new(&config)?;
 
-                launch_golem_services(&LaunchArgs {
-                    router_addr,
-                    router_port,
-                    custom_request_port,
-                    data_dir,
-                })
-                .await?;
+                // Loaded before launching, so seed file errors are reported early
+                let seed = Seed::from_args(args.app.as_deref(), args.seed.as_deref())?;
+                let result = match seed {
+                    Some(seed) => {
+                        launch_with_seeding(&launch_args, seed, &config, args.fail_on_seed_error)
+                            .await
+                    }
+                    None => launch_golem_services(&launch_args).await,
+                };
 
-                Ok(())
+                telemetry::shutdown();
+                result
+            }
+            ServerSubcommand::Start { args } => start_server(&args).await,
+            ServerSubcommand::Stop { data_dir } => {
+                stop_server(&ServerFiles::new(server_data_dir(data_dir)?)).await
+            }
+            ServerSubcommand::Restart { args } => {
+                stop_server(&ServerFiles::new(server_config(&args)?.data_dir()?)).await?;
+                start_server(&args).await
+            }
+            ServerSubcommand::Logs {
+                data_dir,
+                follow,
+                service,
+            } => {
+                daemon::print_logs(
+                    &ServerFiles::new(server_data_dir(data_dir)?),
+                    follow,
+                    service.map(|service| service.span_name()),
+                )
+                .await
             }
             ServerSubcommand::Clean => clean_data_dir(&default_data_dir()?).await,
+            ServerSubcommand::Status {
+                server,
+                wait,
+                wait_timeout,
+            } => {
+                let report = server_status(
+                    &server.router_addr,
+                    server.router_port,
+                    server.tls,
+                    wait.then(|| Duration::from_secs(wait_timeout)),
+                )
+                .await;
+
+                ctx.log_handler().log_view(&report);
+
+                if report.healthy {
+                    Ok(())
+                } else {
+                    Err(anyhow!(NonSuccessfulExit))
+                }
+            }
+            ServerSubcommand::Config { subcommand } => match subcommand {
+                ServerConfigSubcommand::Print { config } => {
+                    let config = ServerConfig::load(config.as_deref())?.resolved()?;
+                    ctx.log_handler().log_view(&config);
+                    Ok(())
+                }
+            },
+            ServerSubcommand::Executor { subcommand } => match subcommand {
+                ServerExecutorSubcommand::List { server } => {
+                    let executors: ExecutorStatusList =
+                        executor_request(&server, Method::GET, "").await?;
+                    ctx.log_handler().log_view(&executors);
+                    Ok(())
+                }
+                ServerExecutorSubcommand::Kill { index, server } => {
+                    let executor: ExecutorStatus =
+                        executor_request(&server, Method::POST, &format!("/{}/kill", index))
+                            .await?;
+                    ctx.log_handler().log_view(&executor);
+                    Ok(())
+                }
+                ServerExecutorSubcommand::Restart { index, server } => {
+                    let executor: ExecutorStatus =
+                        executor_request(&server, Method::POST, &format!("/{}/restart", index))
+                            .await?;
+                    ctx.log_handler().log_view(&executor);
+                    Ok(())
+                }
+            },
+            ServerSubcommand::Data { subcommand } => match subcommand {
+                ServerDataSubcommand::Check { data } => {
+                    let (_, layout) = snapshot_layout(&data)?;
+                    let report = data_dir::check(&layout.data_dir).await?;
+                    ctx.log_handler().log_view(&report);
+                    if report.is_compatible() {
+                        Ok(())
+                    } else {
+                        Err(anyhow!(NonSuccessfulExit))
+                    }
+                }
+            },
+            ServerSubcommand::Requests {
+                data,
+                follow,
+                filter,
+            } => {
+                let filters = filter
+                    .iter()
+                    .map(|filter| filter.parse::<RequestFilter>())
+                    .collect::<anyhow::Result<Vec<_>>>()?;
+                let mut config = ServerConfig::load(data.config.as_deref())?;
+                if let Some(data_dir) = data.data_dir {
+                    config.data_dir = Some(data_dir);
+                }
+                journal::read_journal(
+                    &config.data_dir()?,
+                    config.request_journal.max_files(),
+                    &filters,
+                    follow,
+                    |entry| ctx.log_handler().log_view(&entry),
+                )
+                .await
+            }
+            ServerSubcommand::Snapshot { subcommand } => match subcommand {
+                ServerSnapshotSubcommand::Save { name, data, force } => {
+                    let (config, layout) = snapshot_layout(&data)?;
+                    ensure_server_stopped(&config, &layout.data_dir).await?;
+                    let snapshot_path = snapshot::save(&layout, &name, force).await?;
+                    log_action(
+                        "Saved",
+                        format!(
+                            "snapshot {}",
+                            snapshot_path.display().to_string().log_color_highlight()
+                        ),
+                    );
+                    Ok(())
+                }
+                ServerSnapshotSubcommand::Restore { name, data } => {
+                    let (config, layout) = snapshot_layout(&data)?;
+                    ensure_server_stopped(&config, &layout.data_dir).await?;
+                    let snapshot_path = snapshot::restore(&layout, &name).await?;
+                    log_action(
+                        "Restored",
+                        format!(
+                            "snapshot {}",
+                            snapshot_path.display().to_string().log_color_highlight()
+                        ),
+                    );
+                    Ok(())
+                }
+                ServerSnapshotSubcommand::List { data } => {
+                    let (_, layout) = snapshot_layout(&data)?;
+                    ctx.log_handler().log_view(&snapshot::list(&layout).await?);
+                    Ok(())
+                }
+            },
         }
     }
 
-    fn override_verbosity(verbosity: Verbosity) -> Verbosity {
+    fn override_verbosity(verbosity: Verbosity, subcommand: &ServerSubcommand) -> Verbosity {
         if verbosity.is_present() {
-            verbosity
-        } else {
-            Verbosity::new(2, 0)
+            return verbosity;
         }
+
+        // Config errors are ignored here, as they are reported when running the command
+        let config_verbosity = match subcommand {
+            ServerSubcommand::Run { args }
+            | ServerSubcommand::Start { args }
+            | ServerSubcommand::Restart { args } => ServerConfig::load(args.config.as_deref())
+                .ok()
+                .and_then(|config| config.verbosity()),
+            _ => None,
+        };
+
+        config_verbosity.unwrap_or_else(|| Verbosity::new(2, 0))
     }
 
     fn override_pretty_mode() -> bool {
-        true
+        // Background servers log single lines, so the log files can be filtered by service
+        !daemon::is_daemon()
+    }
+
+    fn tracing_layers(subcommand: &ServerSubcommand) -> Vec<TracingLayer> {
+        let ServerSubcommand::Run { args } = subcommand else {
+            return vec![];
+        };
+
+        // Config errors are ignored here, as they are reported when running the command
+        let Ok(config) = server_config(args) else {
+            return vec![];
+        };
+        match telemetry::otlp_tracing_layer(&config.otlp) {
+            Ok(layer) => layer.into_iter().collect(),
+            Err(err) => {
+                log_warn_action("Skipping", format!("OTLP trace export: {:#}", err));
+                vec![]
+            }
+        }
     }
 }
 
-fn default_data_dir() -> anyhow::Result<PathBuf> {
-    Ok(dirs::data_local_dir()
-        .ok_or_else(|| anyhow!("Failed to get data local dir"))?
-        .join("golem"))
+/// Loads the server config and applies the command line overrides
+fn server_config(args: &ServerRunArgs) -> anyhow::Result<ServerConfig> {
+    ServerConfig::load_with_overrides(args.config.as_deref(), server_config_overrides(args))
+}
+
+/// Command line overrides of the server config, as the last config layer
+fn server_config_overrides(args: &ServerRunArgs) -> Figment {
+    let mut overrides = Figment::new();
+    if let Some(router_addr) = &args.router_addr {
+        overrides = overrides.merge(Serialized::default("router_addr", router_addr));
+    }
+    if let Some(router_port) = args.router_port {
+        overrides = overrides.merge(Serialized::default("router_port", router_port));
+    }
+    if let Some(custom_request_port) = args.custom_request_port {
+        overrides = overrides.merge(Serialized::default(
+            "custom_request_port",
+            custom_request_port,
+        ));
+    }
+    if let Some(data_dir) = &args.data_dir {
+        overrides = overrides.merge(Serialized::default("data_dir", data_dir));
+    }
+    if args.tls {
+        overrides = overrides.merge(Serialized::default("tls.enabled", true));
+    }
+    if let Some(executors) = args.executors {
+        overrides = overrides.merge(Serialized::default("worker_executor.instances", executors));
+    }
+    if args.request_journal {
+        overrides = overrides.merge(Serialized::default("request_journal.enabled", true));
+    }
+    if let Some(otlp_endpoint) = &args.otlp_endpoint {
+        overrides = overrides
+            .merge(Serialized::default("otlp.enabled", true))
+            .merge(Serialized::default("otlp.endpoint", otlp_endpoint));
+    }
+    if let Some(otlp_protocol) = args.otlp_protocol {
+        let otlp_protocol = match otlp_protocol {
+            ServerOtlpProtocol::Grpc => OtlpProtocol::Grpc,
+            ServerOtlpProtocol::Http => OtlpProtocol::Http,
+        };
+        overrides = overrides
+            .merge(Serialized::default("otlp.enabled", true))
+            .merge(Serialized::default("otlp.protocol", otlp_protocol));
+    }
+    if let (Some(tls_cert), Some(tls_key)) = (&args.tls_cert, &args.tls_key) {
+        overrides = overrides
+            .merge(Serialized::default("tls.cert", tls_cert))
+            .merge(Serialized::default("tls.key", tls_key));
+    }
+    overrides
+}
+
+fn server_data_dir(data_dir: Option<PathBuf>) -> anyhow::Result<PathBuf> {
+    match data_dir {
+        Some(data_dir) => Ok(data_dir),
+        None => ServerConfig::load(None)?.data_dir(),
+    }
+}
+
+async fn start_server(args: &ServerRunArgs) -> anyhow::Result<()> {
+    let config = server_config(args)?;
+    let data_dir = std::path::absolute(config.data_dir()?)?;
+
+    // Cleaning is done before starting, as the log file of the server is also in the data dir
+    let files = ServerFiles::new(data_dir.clone());
+    if args.clean && tokio::fs::metadata(&data_dir).await.is_ok() {
+        clean_data_dir(&data_dir).await?;
+    }
+    // Checked here as well, so incompatible data dirs are reported directly instead of in the logs
+    if tokio::fs::metadata(&data_dir).await.is_ok() {
+        data_dir::verify(&data_dir, args.migrate).await?;
+    }
+
+    let mut run_args: Vec<OsString> = vec!["server".into(), "run".into()];
+    run_args.push("--data-dir".into());
+    run_args.push(data_dir.into_os_string());
+    if let Some(router_addr) = &args.router_addr {
+        run_args.push("--router-addr".into());
+        run_args.push(router_addr.into());
+    }
+    if let Some(router_port) = args.router_port {
+        run_args.push("--router-port".into());
+        run_args.push(router_port.to_string().into());
+    }
+    if let Some(custom_request_port) = args.custom_request_port {
+        run_args.push("--custom-request-port".into());
+        run_args.push(custom_request_port.to_string().into());
+    }
+    if let Some(config) = &args.config {
+        run_args.push("--config".into());
+        run_args.push(std::path::absolute(config)?.into_os_string());
+    }
+
+    if args.tls {
+        run_args.push("--tls".into());
+    }
+    if let (Some(tls_cert), Some(tls_key)) = (&args.tls_cert, &args.tls_key) {
+        run_args.push("--tls-cert".into());
+        run_args.push(std::path::absolute(tls_cert)?.into_os_string());
+        run_args.push("--tls-key".into());
+        run_args.push(std::path::absolute(tls_key)?.into_os_string());
+    }
+    if let Some(executors) = args.executors {
+        run_args.push("--executors".into());
+        run_args.push(executors.to_string().into());
+    }
+    if args.request_journal {
+        run_args.push("--request-journal".into());
+    }
+    if args.migrate {
+        run_args.push("--migrate".into());
+    }
+    if let Some(otlp_endpoint) = &args.otlp_endpoint {
+        run_args.push("--otlp-endpoint".into());
+        run_args.push(otlp_endpoint.into());
+    }
+    if let Some(otlp_protocol) = args.otlp_protocol {
+        run_args.push("--otlp-protocol".into());
+        run_args.push(
+            match otlp_protocol {
+                ServerOtlpProtocol::Grpc => "grpc",
+                ServerOtlpProtocol::Http => "http",
+            }
+            .into(),
+        );
+    }
+    if let Some(app) = &args.app {
+        run_args.push("--app".into());
+        run_args.push(std::path::absolute(app)?.into_os_string());
+    }
+    if let Some(seed) = &args.seed {
+        run_args.push("--seed".into());
+        run_args.push(std::path::absolute(seed)?.into_os_string());
+    }
+    if args.fail_on_seed_error {
+        run_args.push("--fail-on-seed-error".into());
+    }
+
+    let pid = daemon::start(&files, run_args).await?;
+
+    log_action(
+        "Started",
+        format!(
+            "golem server in the background with pid {}",
+            pid.to_string().log_color_highlight()
+        ),
+    );
+    let _indent = LogIndent::new();
+    logln(format!(
+        "Logs: {}",
+        files.log_file().display().to_string().log_color_highlight()
+    ));
+    logln(format!(
+        "Use {} to wait until all services are ready",
+        "golem server status --wait".log_color_highlight()
+    ));
+
+    Ok(())
+}
+
+async fn stop_server(files: &ServerFiles) -> anyhow::Result<()> {
+    match daemon::stop(files).await? {
+        Some(pid) => log_action(
+            "Stopped",
+            format!(
+                "golem server with pid {}",
+                pid.to_string().log_color_highlight()
+            ),
+        ),
+        None => log_warn_action("Skipping", "stop, golem server is not running"),
+    }
+    Ok(())
+}
+
+fn snapshot_layout(args: &ServerDataArgs) -> anyhow::Result<(ServerConfig, SnapshotLayout)> {
+    let mut config = ServerConfig::load(args.config.as_deref())?;
+    if let Some(data_dir) = &args.data_dir {
+        config.data_dir = Some(data_dir.clone());
+    }
+    let data_dir = config.data_dir()?;
+    let layout = SnapshotLayout {
+        blob_storage_dir: config.blob_storage_dir(&data_dir),
+        data_dir,
+    };
+    Ok((config, layout))
+}
+
+/// Snapshots are only consistent if no service is writing the databases and the blob storage
+async fn ensure_server_stopped(config: &ServerConfig, data_dir: &Path) -> anyhow::Result<()> {
+    if let Some(pid) = ServerFiles::new(data_dir.to_path_buf()).running_pid()? {
+        bail!(
+            "Golem server is running in the background with pid {}, stop it first with {}",
+            pid,
+            "golem server stop".log_color_highlight()
+        );
+    }
+    if server_status(
+        &connect_host(&config.router_addr),
+        config.router_port,
+        config.tls.is_enabled(),
+        None,
+    )
+    .await
+    .error
+    .is_none()
+    {
+        bail!(
+            "Golem server is running on port {}, stop it first",
+            config.router_port
+        );
+    }
+    Ok(())
+}
+
+/// Calls the worker executor admin API of a running server
+async fn executor_request<T: DeserializeOwned>(
+    server: &ServerAddressArgs,
+    method: Method,
+    path: &str,
+) -> anyhow::Result<T> {
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(60))
+        .danger_accept_invalid_certs(server.tls)
+        .build()?;
+    let url = format!(
+        "{}://{}:{}{}{}",
+        if server.tls { "https" } else { "http" },
+        server.router_addr,
+        server.router_port,
+        EXECUTORS_API_PATH,
+        path
+    );
+
+    let response = client
+        .request(method, &url)
+        .send()
+        .await
+        .with_context(|| anyhow!("Failed to connect to the golem server at {}", url))?;
+    let status = response.status();
+    let body = response.text().await?;
+    if !status.is_success() {
+        bail!("{}", body);
+    }
+
+    serde_json::from_str(&body).with_context(|| anyhow!("Invalid response from {}", url))
+}
+
+/// Seeds the server in a separate task, so seeding failures do not stop a working server,
+/// unless requested with `fail_on_seed_error`
+async fn launch_with_seeding(
+    launch_args: &LaunchArgs,
+    seed: Seed,
+    config: &ServerConfig,
+    fail_on_seed_error: bool,
+) -> anyhow::Result<()> {
+    let router_host = connect_host(&config.router_addr);
+    let router_port = config.router_port;
+    let tls = config.tls.is_enabled();
+    let seeding =
+        tokio::spawn(async move { seed_server(&seed, &router_host, router_port, tls).await });
+
+    let launch = launch_golem_services(launch_args);
+    tokio::pin!(launch);
+
+    tokio::select! {
+        result = &mut launch => result,
+        seeded = seeding => {
+            let seed_error = match seeded {
+                Ok(Ok(())) => None,
+                Ok(Err(err)) => Some(err),
+                Err(err) => Some(anyhow!(err)),
+            };
+            match seed_error {
+                Some(err) if fail_on_seed_error => {
+                    Err(err.context("Seeding failed, stopping the server"))
+                }
+                Some(err) => {
+                    log_error(format!("Seeding failed: {:#}", err));
+                    log_warn("The server keeps running without the seeded state");
+                    launch.await
+                }
+                None => launch.await,
+            }
+        }
+    }
+}
+
+/// Waits for all services to become ready, then applies the seed
+async fn seed_server(
+    seed: &Seed,
+    router_host: &str,
+    router_port: u16,
+    tls: bool,
+) -> anyhow::Result<()> {
+    let report = server_status(router_host, router_port, tls, Some(SEED_READY_TIMEOUT)).await;
+    if !report.healthy {
+        log_text_view(&report);
+        bail!("Golem server did not become ready, skipping seeding");
+    }
+
+    seed.apply(router_host, router_port, tls).await
 }
 
 async fn clean_data_dir(data_dir: &Path) -> anyhow::Result<()> {
+    if tokio::fs::metadata(data_dir).await.is_err() {
+        return Ok(());
+    }
+    if let Some(pid) = ServerFiles::new(data_dir.to_path_buf()).running_pid()? {
+        bail!(
+            "Golem server is running in the background with pid {}, stop it first with {}",
+            pid,
+            "golem server stop".log_color_highlight()
+        );
+    }
+
     tokio::fs::remove_dir_all(&data_dir)
         .await
         .map_err(|err| anyhow!("Failed cleaning data dir ({}): {}", data_dir.display(), err))
 }
+
+/// Queries the aggregated healthcheck of the router, optionally polling until all services are healthy
+async fn server_status(
+    router_addr: &str,
+    router_port: u16,
+    tls: bool,
+    wait_timeout: Option<Duration>,
+) -> HealthReport {
+    // The local server uses a self-signed certificate by default
+    let client = match reqwest::Client::builder()
+        .timeout(Duration::from_secs(10))
+        .danger_accept_invalid_certs(tls)
+        .build()
+    {
+        Ok(client) => client,
+        Err(err) => {
+            return HealthReport::unreachable(format!("failed to create HTTP client: {}", err))
+        }
+    };
+    let scheme = if tls { "https" } else { "http" };
+    let url = format!("{}://{}:{}/healthcheck", scheme, router_addr, router_port);
+    let deadline = wait_timeout.map(|timeout| Instant::now() + timeout);
+
+    loop {
+        let report = match client.get(&url).send().await {
+            Ok(response) => match response.text().await {
+                Ok(body) => serde_json::from_str::<HealthReport>(&body).unwrap_or_else(|err| {
+                    HealthReport::unreachable(format!("invalid healthcheck response: {}", err))
+                }),
+                Err(err) => HealthReport::unreachable(err.to_string()),
+            },
+            Err(err) => HealthReport::unreachable(err.to_string()),
+        };
+
+        match deadline {
+            Some(deadline) if !report.healthy && Instant::now() < deadline => {
+                tokio::time::sleep(Duration::from_secs(1)).await;
+            }
+            _ => return report,
+        }
+    }
+}
@@ -0,0 +1,376 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::listener::dev_certificate_paths;
+use anyhow::{anyhow, Context};
+use clap_verbosity_flag::Verbosity;
+use figment::providers::{Env, Format, Serialized, Toml};
+use figment::Figment;
+use golem_cli::log::logln;
+use golem_cli::model::text::fmt::TextView;
+use golem_common::config::RetryConfig;
+use golem_worker_executor_base::services::golem_config::GolemConfig;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Prefix of the environment variables overriding the server config, nested keys are separated by `__`,
+/// e.g. `GOLEM_SERVER__WORKER_EXECUTOR__MAX_ACTIVE_WORKERS=64`
+pub const ENV_PREFIX: &str = "GOLEM_SERVER__";
+
+/// Log level used when neither the config nor the verbosity flags set one
+const DEFAULT_LOG_LEVEL: &str = "error";
+
+/// User settings of the single executable server, layered over the defaults of the services.
+///
+/// Every optional setting falls back to the default of the affected service when not provided,
+/// see [ServerConfig::resolved] for the effective values.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ServerConfig {
+    pub router_addr: String,
+    pub router_port: u16,
+    pub custom_request_port: u16,
+    pub data_dir: Option<PathBuf>,
+    pub tls: TlsServerConfig,
+    pub log: LogConfig,
+    pub blob_storage: BlobStorageServerConfig,
+    pub worker_executor: WorkerExecutorServerConfig,
+    pub request_journal: RequestJournalConfig,
+    pub otlp: OtlpConfig,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            router_addr: "0.0.0.0".to_string(),
+            router_port: 9881,
+            custom_request_port: 9006,
+            data_dir: None,
+            tls: TlsServerConfig::default(),
+            log: LogConfig::default(),
+            blob_storage: BlobStorageServerConfig::default(),
+            worker_executor: WorkerExecutorServerConfig::default(),
+            request_journal: RequestJournalConfig::default(),
+            otlp: OtlpConfig::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TlsServerConfig {
+    /// Serve the main API and the custom request port over HTTPS, using a generated
+    /// self-signed certificate when no certificate is provided
+    pub enabled: bool,
+    /// PEM encoded certificate chain
+    pub cert: Option<PathBuf>,
+    /// PEM encoded private key
+    pub key: Option<PathBuf>,
+}
+
+impl TlsServerConfig {
+    pub fn is_enabled(&self) -> bool {
+        self.enabled || self.cert.is_some()
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LogConfig {
+    /// One of: off, error, warn, info, debug, trace
+    pub level: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BlobStorageServerConfig {
+    /// Defaults to the `blobs` directory in the data dir
+    pub path: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WorkerExecutorServerConfig {
+    /// Number of worker executors registered with the shard manager, defaults to 1
+    pub instances: Option<usize>,
+    pub max_active_workers: Option<usize>,
+    /// Total memory available for workers in bytes, defaults to the system memory
+    pub system_memory_override: Option<u64>,
+    /// Ratio of the system memory which can be used by workers
+    pub worker_memory_ratio: Option<f64>,
+    /// Retry policy for failing worker invocations
+    pub retries: Option<RetryConfig>,
+    /// Maximum number of compiled components cached in memory by each worker executor
+    pub component_cache_capacity: Option<usize>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RequestJournalConfig {
+    /// Record the requests served by the router into `logs/requests.jsonl` in the data dir
+    pub enabled: bool,
+    /// Size of the journal file in bytes before it gets rotated, defaults to 10 MiB
+    pub max_file_size: Option<u64>,
+    /// Number of rotated journal files kept, defaults to 5
+    pub max_files: Option<usize>,
+}
+
+impl RequestJournalConfig {
+    pub fn max_file_size(&self) -> u64 {
+        self.max_file_size.unwrap_or(10 * 1024 * 1024)
+    }
+
+    pub fn max_files(&self) -> usize {
+        self.max_files.unwrap_or(5).max(1)
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OtlpConfig {
+    /// Export traces and metrics via OTLP, e.g. to a local collector or Jaeger
+    pub enabled: bool,
+    /// Collector endpoint, defaults to http://localhost:4317 for gRPC and http://localhost:4318 for HTTP
+    pub endpoint: Option<String>,
+    pub protocol: OtlpProtocol,
+    /// Service name of the exported telemetry, defaults to golem
+    pub service_name: Option<String>,
+}
+
+impl OtlpConfig {
+    pub fn endpoint(&self) -> String {
+        match &self.endpoint {
+            Some(endpoint) => endpoint.trim_end_matches('/').to_string(),
+            None => match self.protocol {
+                OtlpProtocol::Grpc => "http://localhost:4317".to_string(),
+                OtlpProtocol::Http => "http://localhost:4318".to_string(),
+            },
+        }
+    }
+
+    pub fn service_name(&self) -> String {
+        self.service_name
+            .clone()
+            .unwrap_or_else(|| "golem".to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OtlpProtocol {
+    #[default]
+    Grpc,
+    Http,
+}
+
+impl ServerConfig {
+    /// Loads the config by layering the optional config file and the environment over the defaults
+    pub fn load(config_file: Option<&Path>) -> anyhow::Result<Self> {
+        Self::load_with_overrides(config_file, Figment::new())
+    }
+
+    /// Loads the config by layering the optional config file, the environment and the given
+    /// overrides (e.g. the command line arguments) over the defaults
+    pub fn load_with_overrides(
+        config_file: Option<&Path>,
+        overrides: Figment,
+    ) -> anyhow::Result<Self> {
+        let mut figment = Figment::from(Serialized::defaults(ServerConfig::default()));
+
+        if let Some(config_file) = config_file {
+            if !config_file.exists() {
+                return Err(anyhow!(
+                    "Server config file not found: {}",
+                    config_file.display()
+                ));
+            }
+            figment = figment.merge(Toml::file(config_file));
+        }
+
+        figment
+            .merge(Env::prefixed(ENV_PREFIX).split("__"))
+            .merge(overrides)
+            .extract()
+            .with_context(|| match config_file {
+                Some(config_file) => {
+                    format!(
+                        "Failed to load server config from {}",
+                        config_file.display()
+                    )
+                }
+                None => "Failed to load server config".to_string(),
+            })
+    }
+
+    /// The effective config, where every unset setting is replaced by the default it falls back to.
+    ///
+    /// The system memory override is the only setting which stays unset by default, as in that
+    /// case the memory available for workers is detected on start.
+    pub fn resolved(&self) -> anyhow::Result<Self> {
+        let data_dir = self.data_dir()?;
+        let worker_executor_defaults = GolemConfig::default();
+
+        let mut config = self.clone();
+
+        config
+            .log
+            .level
+            .get_or_insert_with(|| DEFAULT_LOG_LEVEL.to_string());
+        config.blob_storage.path = Some(self.blob_storage_dir(&data_dir));
+        if config.tls.is_enabled() && config.tls.cert.is_none() && config.tls.key.is_none() {
+            let (cert, key) = dev_certificate_paths(&data_dir);
+            config.tls.cert = Some(cert);
+            config.tls.key = Some(key);
+        }
+
+        let worker_executor = &mut config.worker_executor;
+        worker_executor.instances.get_or_insert(1);
+        worker_executor
+            .max_active_workers
+            .get_or_insert(worker_executor_defaults.limits.max_active_workers);
+        worker_executor
+            .worker_memory_ratio
+            .get_or_insert(worker_executor_defaults.memory.worker_memory_ratio);
+        worker_executor
+            .retries
+            .get_or_insert_with(|| worker_executor_defaults.retry.clone());
+        worker_executor
+            .component_cache_capacity
+            .get_or_insert(worker_executor_defaults.component_cache.max_capacity);
+
+        config.request_journal.max_file_size = Some(self.request_journal.max_file_size());
+        config.request_journal.max_files = Some(self.request_journal.max_files());
+
+        config.otlp.endpoint = Some(self.otlp.endpoint());
+        config.otlp.service_name = Some(self.otlp.service_name());
+
+        config.data_dir = Some(data_dir);
+
+        Ok(config)
+    }
+
+    pub fn data_dir(&self) -> anyhow::Result<PathBuf> {
+        match &self.data_dir {
+            Some(data_dir) => Ok(data_dir.clone()),
+            None => default_data_dir(),
+        }
+    }
+
+    pub fn blob_storage_dir(&self, data_dir: &Path) -> PathBuf {
+        self.blob_storage
+            .path
+            .clone()
+            .unwrap_or_else(|| data_dir.join("blobs"))
+    }
+
+    /// Verbosity based on the configured log level, used when no verbosity flags are present
+    pub fn verbosity(&self) -> Option<Verbosity> {
+        let level = self.log.level.as_ref()?;
+        match level.to_lowercase().as_str() {
+            "off" => Some(Verbosity::new(0, 1)),
+            "error" => Some(Verbosity::new(0, 0)),
+            "warn" => Some(Verbosity::new(1, 0)),
+            "info" => Some(Verbosity::new(2, 0)),
+            "debug" => Some(Verbosity::new(3, 0)),
+            "trace" => Some(Verbosity::new(4, 0)),
+            _ => None,
+        }
+    }
+}
+
+impl TextView for ServerConfig {
+    fn log(&self) {
+        match toml::to_string_pretty(self) {
+            Ok(config) => logln(config),
+            Err(err) => logln(format!("Failed to serialize server config: {}", err)),
+        }
+    }
+}
+
+pub fn default_data_dir() -> anyhow::Result<PathBuf> {
+    Ok(dirs::data_local_dir()
+        .ok_or_else(|| anyhow!("Failed to get data local dir"))?
+        .join("golem"))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::config::{ServerConfig, ENV_PREFIX};
+    use assert2::assert;
+    use figment::providers::Serialized;
+    use figment::{Figment, Jail};
+    use std::path::Path;
+    use test_r::test;
+
+    #[test]
+    fn config_layers_defaults_file_env_and_overrides() {
+        Jail::expect_with(|jail| {
+            jail.create_file(
+                "golem-server.toml",
+                r#"
+                    router_port = 9000
+                    custom_request_port = 9100
+
+                    [worker_executor]
+                    max_active_workers = 16
+                    instances = 2
+                "#,
+            )?;
+            jail.set_env(format!("{ENV_PREFIX}CUSTOM_REQUEST_PORT"), "9200");
+            jail.set_env(format!("{ENV_PREFIX}WORKER_EXECUTOR__INSTANCES"), "3");
+
+            let config = ServerConfig::load_with_overrides(
+                Some(Path::new("golem-server.toml")),
+                Figment::new().merge(Serialized::default("worker_executor.instances", 4)),
+            )
+            .unwrap();
+
+            // default
+            assert!(config.router_addr == ServerConfig::default().router_addr);
+            // file
+            assert!(config.router_port == 9000);
+            assert!(config.worker_executor.max_active_workers == Some(16));
+            // env over file
+            assert!(config.custom_request_port == 9200);
+            // overrides over env
+            assert!(config.worker_executor.instances == Some(4));
+
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn resolved_config_contains_defaults() {
+        let config = ServerConfig {
+            data_dir: Some("data".into()),
+            ..ServerConfig::default()
+        }
+        .resolved()
+        .unwrap();
+
+        assert!(config.log.level.is_some());
+        assert!(config.blob_storage.path == Some(Path::new("data").join("blobs")));
+        assert!(config.worker_executor.instances == Some(1));
+        assert!(config.worker_executor.max_active_workers.is_some());
+        assert!(config.worker_executor.worker_memory_ratio.is_some());
+        assert!(config.worker_executor.retries.is_some());
+        assert!(config.worker_executor.component_cache_capacity.is_some());
+        assert!(config.request_journal.max_files == Some(5));
+        assert!(config.otlp.service_name == Some("golem".to_string()));
+
+        let printed = toml::to_string_pretty(&config).unwrap();
+        assert!(printed.contains("component_cache_capacity"));
+        assert!(printed.contains("max_active_workers"));
+    }
+}
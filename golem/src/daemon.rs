@@ -0,0 +1,499 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::{anyhow, bail, Context};
+use std::ffi::OsString;
+use std::fs::{File, OpenOptions, TryLockError};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncBufReadExt, AsyncSeekExt, BufReader};
+use tokio::task::JoinHandle;
+use tracing::warn;
+
+/// Set for the background server process, so it uses single line log output
+/// instead of the pretty multi-line one, which makes the log files filterable by service
+pub const DAEMON_ENV_VAR: &str = "GOLEM_SERVER_DAEMON";
+
+const PID_FILE: &str = "server.pid";
+const LOCK_FILE: &str = "server.lock";
+const LOGS_DIR: &str = "logs";
+const LOG_FILE: &str = "server.log";
+const MAX_ROTATED_LOG_FILES: usize = 5;
+const MAX_LOG_FILE_SIZE: u64 = 64 * 1024 * 1024;
+const LOG_ROTATION_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+const STOP_TIMEOUT: Duration = Duration::from_secs(10);
+
+pub fn is_daemon() -> bool {
+    std::env::var_os(DAEMON_ENV_VAR).is_some()
+}
+
+pub struct ServerFiles {
+    data_dir: PathBuf,
+}
+
+impl ServerFiles {
+    pub fn new(data_dir: PathBuf) -> Self {
+        Self { data_dir }
+    }
+
+    pub fn pid_file(&self) -> PathBuf {
+        self.data_dir.join(PID_FILE)
+    }
+
+    pub fn lock_file(&self) -> PathBuf {
+        self.data_dir.join(LOCK_FILE)
+    }
+
+    pub fn logs_dir(&self) -> PathBuf {
+        self.data_dir.join(LOGS_DIR)
+    }
+
+    pub fn log_file(&self) -> PathBuf {
+        self.logs_dir().join(LOG_FILE)
+    }
+
+    /// Returns the pid of the background server, removing the pidfile if the process is not running anymore.
+    ///
+    /// The server holds a lock on the lockfile while running, so a pid reused by another process
+    /// after the server died is never reported as the server.
+    pub fn running_pid(&self) -> anyhow::Result<Option<u32>> {
+        let pid_file = self.pid_file();
+        if !pid_file.exists() {
+            return Ok(None);
+        }
+
+        if !self.is_locked()? {
+            self.remove_pid_file()?;
+            return Ok(None);
+        }
+
+        let pid = std::fs::read_to_string(&pid_file)
+            .with_context(|| anyhow!("Failed to read pidfile: {}", pid_file.display()))?
+            .trim()
+            .parse::<u32>()
+            .with_context(|| anyhow!("Invalid pidfile: {}", pid_file.display()))?;
+
+        Ok(Some(pid))
+    }
+
+    fn is_locked(&self) -> anyhow::Result<bool> {
+        let lock_file = self.lock_file();
+        if !lock_file.exists() {
+            return Ok(false);
+        }
+
+        let file = File::open(&lock_file)
+            .with_context(|| anyhow!("Failed to open lockfile: {}", lock_file.display()))?;
+        match file.try_lock() {
+            Ok(()) => Ok(false),
+            Err(TryLockError::WouldBlock) => Ok(true),
+            Err(TryLockError::Error(err)) => Err(err)
+                .with_context(|| anyhow!("Failed to check lockfile: {}", lock_file.display())),
+        }
+    }
+
+    fn remove_pid_file(&self) -> anyhow::Result<()> {
+        let pid_file = self.pid_file();
+        if pid_file.exists() {
+            std::fs::remove_file(&pid_file)
+                .with_context(|| anyhow!("Failed to remove pidfile: {}", pid_file.display()))?;
+        }
+        Ok(())
+    }
+
+    fn rotate_logs(&self) -> anyhow::Result<()> {
+        std::fs::create_dir_all(self.logs_dir()).with_context(|| {
+            anyhow!(
+                "Failed to create logs directory: {}",
+                self.logs_dir().display()
+            )
+        })?;
+        rotate_file(&self.log_file(), MAX_ROTATED_LOG_FILES)
+    }
+
+    /// Rotates the log file of the running server once it grows over the size limit.
+    ///
+    /// The server keeps writing to its open log file, so it is copied and truncated instead of renamed.
+    /// Lines written between the copy and the truncation are lost.
+    fn rotate_growing_log(&self) -> anyhow::Result<()> {
+        let log_file = self.log_file();
+        let len = std::fs::metadata(&log_file)
+            .map(|metadata| metadata.len())
+            .unwrap_or(0);
+        if len < MAX_LOG_FILE_SIZE {
+            return Ok(());
+        }
+
+        shift_rotated_files(&log_file, MAX_ROTATED_LOG_FILES)?;
+        std::fs::copy(&log_file, rotated_file(&log_file, 1))?;
+        OpenOptions::new().write(true).open(&log_file)?.set_len(0)?;
+
+        Ok(())
+    }
+}
+
+/// Held by the background server process for its whole lifetime.
+///
+/// Keeps the lockfile locked, which identifies the running server for [ServerFiles::running_pid],
+/// and rotates the log file while the server is running.
+pub struct DaemonGuard {
+    files: ServerFiles,
+    _lock: File,
+    log_rotation: JoinHandle<()>,
+}
+
+impl DaemonGuard {
+    pub fn acquire(data_dir: PathBuf) -> anyhow::Result<Self> {
+        let files = ServerFiles::new(data_dir);
+
+        let lock_file = files.lock_file();
+        let lock = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(&lock_file)
+            .with_context(|| anyhow!("Failed to open lockfile: {}", lock_file.display()))?;
+        match lock.try_lock() {
+            Ok(()) => {}
+            Err(TryLockError::WouldBlock) => {
+                bail!("Another golem server is already running with the same data dir")
+            }
+            Err(TryLockError::Error(err)) => Err(err)
+                .with_context(|| anyhow!("Failed to lock lockfile: {}", lock_file.display()))?,
+        }
+
+        std::fs::write(files.pid_file(), std::process::id().to_string())
+            .with_context(|| anyhow!("Failed to write pidfile: {}", files.pid_file().display()))?;
+
+        let log_rotation = {
+            let files = ServerFiles::new(files.data_dir.clone());
+            tokio::spawn(async move {
+                loop {
+                    tokio::time::sleep(LOG_ROTATION_CHECK_INTERVAL).await;
+                    if let Err(err) = files.rotate_growing_log() {
+                        warn!("Failed to rotate the server log: {:#}", err);
+                    }
+                }
+            })
+        };
+
+        Ok(Self {
+            files,
+            _lock: lock,
+            log_rotation,
+        })
+    }
+}
+
+impl Drop for DaemonGuard {
+    fn drop(&mut self) {
+        self.log_rotation.abort();
+        let _ = self.files.remove_pid_file();
+    }
+}
+
+/// Shifts the file and its previous rotations to `<file>.1`, `<file>.2`, ..., keeping the last `max_rotated` ones
+pub fn rotate_file(path: &Path, max_rotated: usize) -> anyhow::Result<()> {
+    shift_rotated_files(path, max_rotated)?;
+    if path.exists() {
+        std::fs::rename(path, rotated_file(path, 1))?;
+    }
+
+    Ok(())
+}
+
+/// Shifts the previous rotations of the file, so `<file>.1` is free for the next one
+fn shift_rotated_files(path: &Path, max_rotated: usize) -> anyhow::Result<()> {
+    let rotated = |index: usize| rotated_file(path, index);
+
+    let oldest = rotated(max_rotated);
+    if oldest.exists() {
+        std::fs::remove_file(&oldest)?;
+    }
+    for index in (1..max_rotated).rev() {
+        let from = rotated(index);
+        if from.exists() {
+            std::fs::rename(&from, rotated(index + 1))?;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn rotated_file(path: &Path, index: usize) -> PathBuf {
+    let mut rotated = path.as_os_str().to_os_string();
+    rotated.push(format!(".{}", index));
+    PathBuf::from(rotated)
+}
+
+/// Starts a detached server process with the given arguments, returning its pid.
+///
+/// The pidfile is written by the server process itself, see [DaemonGuard].
+pub async fn start(files: &ServerFiles, args: Vec<OsString>) -> anyhow::Result<u32> {
+    if let Some(pid) = files.running_pid()? {
+        bail!("Golem server is already running with pid {}", pid);
+    }
+
+    files.rotate_logs()?;
+    let log_file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(files.log_file())
+        .with_context(|| anyhow!("Failed to open log file: {}", files.log_file().display()))?;
+
+    let mut command = Command::new(
+        std::env::current_exe().context("Failed to get the path of the current executable")?,
+    );
+    command
+        .args(args)
+        .env(DAEMON_ENV_VAR, "1")
+        .env("NO_COLOR", "1")
+        .stdin(Stdio::null())
+        .stdout(log_file.try_clone()?)
+        .stderr(log_file);
+    process::detach(&mut command);
+
+    let mut child = command
+        .spawn()
+        .context("Failed to start the golem server process")?;
+    let pid = child.id();
+
+    // Early failures (e.g. config errors or ports in use) are reported here instead of only in the logs
+    tokio::time::sleep(Duration::from_secs(1)).await;
+    if let Some(status) = child.try_wait()? {
+        bail!(
+            "Golem server exited with {}, see the logs in {}",
+            status,
+            files.log_file().display()
+        );
+    }
+
+    Ok(pid)
+}
+
+/// Stops the background server, returning the pid of the stopped process
+pub async fn stop(files: &ServerFiles) -> anyhow::Result<Option<u32>> {
+    let Some(pid) = files.running_pid()? else {
+        return Ok(None);
+    };
+
+    process::terminate(pid)?;
+
+    // The lock is released when the server exits, so the pid is only killed while it is still the server
+    let deadline = Instant::now() + STOP_TIMEOUT;
+    while files.is_locked()? && Instant::now() < deadline {
+        tokio::time::sleep(Duration::from_millis(200)).await;
+    }
+    if files.is_locked()? {
+        process::kill(pid)?;
+    }
+
+    files.remove_pid_file()?;
+
+    Ok(Some(pid))
+}
+
+/// Prints the server log, optionally filtered to the lines logged in the span of a service
+pub async fn print_logs(
+    files: &ServerFiles,
+    follow: bool,
+    service_span: Option<&str>,
+) -> anyhow::Result<()> {
+    let log_file = files.log_file();
+    if !log_file.exists() {
+        bail!("No server logs found at {}", log_file.display());
+    }
+
+    read_lines(&log_file, follow, |line| {
+        if is_service_line(line, service_span) {
+            println!("{}", line);
+        }
+    })
+    .await
+}
+
+/// Calls the handler for every line of the file, and when following, for every line appended later.
+///
+/// Following continues from the start of the file when it gets rotated.
+pub async fn read_lines<F>(path: &Path, follow: bool, mut handler: F) -> anyhow::Result<()>
+where
+    F: FnMut(&str),
+{
+    let mut reader = open_log(path).await?;
+    let mut position = 0u64;
+    let mut line = String::new();
+
+    loop {
+        let read = reader.read_line(&mut line).await?;
+        if read > 0 {
+            position += read as u64;
+            // Partial lines are completed on the next read
+            if line.ends_with('\n') {
+                handler(line.trim_end_matches(['\r', '\n']));
+                line.clear();
+            }
+            continue;
+        }
+
+        if !follow {
+            if !line.is_empty() {
+                handler(&line);
+            }
+            return Ok(());
+        }
+
+        tokio::time::sleep(Duration::from_millis(500)).await;
+
+        let len = tokio::fs::metadata(path)
+            .await
+            .map(|metadata| metadata.len())
+            .unwrap_or(0);
+        if len < position {
+            reader = open_log(path).await?;
+            position = 0;
+            line.clear();
+        }
+    }
+}
+
+async fn open_log(path: &Path) -> anyhow::Result<BufReader<tokio::fs::File>> {
+    let mut file = tokio::fs::File::open(path)
+        .await
+        .with_context(|| anyhow!("Failed to open log file: {}", path.display()))?;
+    file.rewind().await?;
+    Ok(BufReader::new(file))
+}
+
+fn is_service_line(line: &str, service_span: Option<&str>) -> bool {
+    match service_span {
+        Some(span) => line.contains(&format!("{}:", span)) || line.contains(&format!("{}{{", span)),
+        None => true,
+    }
+}
+
+#[cfg(unix)]
+mod process {
+    use anyhow::anyhow;
+    use nix::errno::Errno;
+    use nix::sys::signal::{kill as send_signal, Signal};
+    use nix::unistd::Pid;
+    use std::os::unix::process::CommandExt;
+    use std::process::Command;
+
+    pub fn detach(command: &mut Command) {
+        // Own process group, so the server does not receive the signals of the terminal
+        command.process_group(0);
+    }
+
+    pub fn terminate(pid: u32) -> anyhow::Result<()> {
+        send(pid, Signal::SIGTERM)
+    }
+
+    pub fn kill(pid: u32) -> anyhow::Result<()> {
+        send(pid, Signal::SIGKILL)
+    }
+
+    fn send(pid: u32, signal: Signal) -> anyhow::Result<()> {
+        let raw_pid = i32::try_from(pid).map_err(|_| anyhow!("Invalid pid: {}", pid))?;
+        match send_signal(Pid::from_raw(raw_pid), signal) {
+            // Already exited
+            Ok(()) | Err(Errno::ESRCH) => Ok(()),
+            Err(err) => Err(anyhow!(
+                "Failed to send {} to process {}: {}",
+                signal,
+                pid,
+                err
+            )),
+        }
+    }
+}
+
+#[cfg(windows)]
+mod process {
+    use anyhow::{bail, Context};
+    use std::os::windows::process::CommandExt;
+    use std::process::{Command, Stdio};
+
+    const DETACHED_PROCESS: u32 = 0x00000008;
+    const CREATE_NEW_PROCESS_GROUP: u32 = 0x00000200;
+
+    pub fn detach(command: &mut Command) {
+        command.creation_flags(DETACHED_PROCESS | CREATE_NEW_PROCESS_GROUP);
+    }
+
+    fn is_running(pid: u32) -> bool {
+        Command::new("tasklist")
+            .args(["/FI", &format!("PID eq {}", pid), "/NH"])
+            .stderr(Stdio::null())
+            .output()
+            .map(|output| String::from_utf8_lossy(&output.stdout).contains(&pid.to_string()))
+            .unwrap_or(false)
+    }
+
+    pub fn terminate(pid: u32) -> anyhow::Result<()> {
+        taskkill(pid, false)
+    }
+
+    pub fn kill(pid: u32) -> anyhow::Result<()> {
+        taskkill(pid, true)
+    }
+
+    fn taskkill(pid: u32, force: bool) -> anyhow::Result<()> {
+        let mut command = Command::new("taskkill");
+        command.args(["/PID", &pid.to_string()]);
+        if force {
+            command.arg("/F");
+        }
+        let status = command
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .context("Failed to execute taskkill")?;
+        if !status.success() && is_running(pid) {
+            bail!("Failed to stop process {}", pid);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::daemon::{DaemonGuard, ServerFiles};
+    use assert2::assert;
+    use test_r::test;
+
+    #[test]
+    fn stale_pid_file_is_not_reported_as_running() {
+        let data_dir = tempfile::tempdir().unwrap();
+        let files = ServerFiles::new(data_dir.path().to_path_buf());
+        std::fs::write(files.pid_file(), std::process::id().to_string()).unwrap();
+
+        assert!(files.running_pid().unwrap() == None);
+        assert!(!files.pid_file().exists());
+    }
+
+    #[test]
+    async fn locked_pid_file_is_reported_as_running() {
+        let data_dir = tempfile::tempdir().unwrap();
+        let files = ServerFiles::new(data_dir.path().to_path_buf());
+
+        let guard = DaemonGuard::acquire(data_dir.path().to_path_buf()).unwrap();
+        assert!(files.running_pid().unwrap() == Some(std::process::id()));
+        assert!(DaemonGuard::acquire(data_dir.path().to_path_buf()).is_err());
+
+        drop(guard);
+        assert!(files.running_pid().unwrap() == None);
+    }
+}
@@ -0,0 +1,500 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::migration::IncludedMigrationsDir;
+use anyhow::{anyhow, bail, Context};
+use chrono::{DateTime, Utc};
+use golem_cli::log::{logln, LogColorize, LogIndent};
+use golem_cli::model::text::fmt::TextView;
+use golem_component_service::ComponentService;
+use golem_service_base::migration::Migrations;
+use golem_worker_service::WorkerService;
+use include_dir::Dir;
+use serde::{Deserialize, Serialize};
+use sqlx::migrate::MigrationSource;
+use sqlx::sqlite::SqliteConnectOptions;
+use sqlx::{ConnectOptions, Connection};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+const MANIFEST_FILE: &str = "golem-data.toml";
+
+/// Version of the data dir layout, to be increased when files are added, moved or change format
+/// outside the database migrations
+pub const DATA_DIR_FORMAT_VERSION: u32 = 1;
+
+pub const COMPONENTS_DB: &str = "components.db";
+pub const WORKERS_DB: &str = "workers.db";
+pub const KV_STORE_DB: &str = "kv-store.db";
+pub const GATEWAY_SESSIONS_DB: &str = "gateway-sessions.db";
+
+/// Sqlite databases opened by the services, with the migrations they apply on startup.
+///
+/// The key-value store and the gateway session store create their tables on their own,
+/// so for them only the presence and readability of the database is checked.
+fn databases() -> Vec<(&'static str, Option<Dir<'static>>)> {
+    vec![
+        (COMPONENTS_DB, Some(ComponentService::db_migrations())),
+        (WORKERS_DB, Some(WorkerService::db_migrations())),
+        (KV_STORE_DB, None),
+        (GATEWAY_SESSIONS_DB, None),
+    ]
+}
+
+/// Stamp of the data dir, written on every server start
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct DataDirManifest {
+    pub format_version: u32,
+    pub golem_version: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl DataDirManifest {
+    pub fn path(data_dir: &Path) -> PathBuf {
+        data_dir.join(MANIFEST_FILE)
+    }
+
+    pub fn load(data_dir: &Path) -> anyhow::Result<Option<Self>> {
+        let path = Self::path(data_dir);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| anyhow!("Failed to read data dir manifest: {}", path.display()))?;
+        toml::from_str(&content)
+            .map(Some)
+            .with_context(|| anyhow!("Invalid data dir manifest: {}", path.display()))
+    }
+
+    fn stamp(data_dir: &Path, previous: Option<&DataDirManifest>) -> anyhow::Result<()> {
+        let now = Utc::now();
+        let manifest = DataDirManifest {
+            format_version: DATA_DIR_FORMAT_VERSION,
+            golem_version: golem_cli::version().to_string(),
+            created_at: previous.map(|manifest| manifest.created_at).unwrap_or(now),
+            updated_at: now,
+        };
+        let path = Self::path(data_dir);
+        std::fs::write(&path, toml::to_string_pretty(&manifest)?)
+            .with_context(|| anyhow!("Failed to write data dir manifest: {}", path.display()))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MigrationInfo {
+    pub version: i64,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DatabaseMigrationStatus {
+    pub name: String,
+    pub exists: bool,
+    pub applied: Vec<MigrationInfo>,
+    pub pending: Vec<MigrationInfo>,
+    /// Applied migrations not known by this version, the database was migrated by a newer version
+    pub unknown: Vec<MigrationInfo>,
+    /// Applied migrations which differ from the ones of this version, or which failed
+    pub modified: Vec<MigrationInfo>,
+}
+
+impl DatabaseMigrationStatus {
+    pub fn is_compatible(&self) -> bool {
+        self.unknown.is_empty() && self.modified.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataDirReport {
+    pub data_dir: PathBuf,
+    pub manifest: Option<DataDirManifest>,
+    pub format_version: u32,
+    pub golem_version: String,
+    pub databases: Vec<DatabaseMigrationStatus>,
+}
+
+impl DataDirReport {
+    pub fn is_compatible(&self) -> bool {
+        self.manifest
+            .as_ref()
+            .is_none_or(|manifest| manifest.format_version <= self.format_version)
+            && self.databases.iter().all(|db| db.is_compatible())
+    }
+
+    pub fn has_pending_migrations(&self) -> bool {
+        self.databases
+            .iter()
+            .any(|db| db.exists && !db.pending.is_empty())
+    }
+
+    fn is_empty(&self) -> bool {
+        self.manifest.is_none() && self.databases.iter().all(|db| !db.exists)
+    }
+}
+
+impl TextView for DataDirReport {
+    fn log(&self) {
+        logln(format!(
+            "Data dir: {}",
+            self.data_dir.display().to_string().log_color_highlight()
+        ));
+        match &self.manifest {
+            Some(manifest) => {
+                logln(format!(
+                    "Format version: {} (supported: {})",
+                    manifest.format_version, self.format_version
+                ));
+                logln(format!(
+                    "Last started by golem {} at {}",
+                    manifest.golem_version.log_color_highlight(),
+                    manifest.updated_at.format("%Y-%m-%d %H:%M:%S")
+                ));
+            }
+            None => logln("No data dir manifest found"),
+        }
+        logln(format!(
+            "Current golem version: {}",
+            self.golem_version.log_color_highlight()
+        ));
+
+        for db in &self.databases {
+            logln("");
+            let status = if !db.exists {
+                "not created".log_color_help_group()
+            } else if !db.is_compatible() {
+                "incompatible".log_color_error_highlight()
+            } else if !db.pending.is_empty() {
+                "pending migrations".log_color_warn()
+            } else {
+                "up to date".log_color_ok_highlight()
+            };
+            logln(format!("{}: {}", db.name.log_color_highlight(), status));
+
+            let _indent = LogIndent::new();
+            logln(format!("Applied migrations: {}", db.applied.len()));
+            log_migrations("Pending", &db.pending);
+            log_migrations("Unknown", &db.unknown);
+            log_migrations("Modified or failed", &db.modified);
+        }
+    }
+}
+
+fn log_migrations(label: &str, migrations: &[MigrationInfo]) {
+    if migrations.is_empty() {
+        return;
+    }
+    logln(format!("{} migrations:", label));
+    let _indent = LogIndent::new();
+    for migration in migrations {
+        logln(format!("{} {}", migration.version, migration.description));
+    }
+}
+
+/// Reports the data dir manifest and the migration status of the databases
+pub async fn check(data_dir: &Path) -> anyhow::Result<DataDirReport> {
+    let mut databases = Vec::new();
+    for (name, migrations) in databases() {
+        databases.push(database_status(&data_dir.join(name), name, migrations).await?);
+    }
+
+    Ok(DataDirReport {
+        data_dir: data_dir.to_path_buf(),
+        manifest: DataDirManifest::load(data_dir)?,
+        format_version: DATA_DIR_FORMAT_VERSION,
+        golem_version: golem_cli::version().to_string(),
+        databases,
+    })
+}
+
+/// Checks whether the services can be started on the data dir.
+///
+/// Data dirs of newer versions are refused, and pending migrations of existing databases are only
+/// allowed (to be applied by the services) when requested, as they cannot be reverted.
+pub async fn verify(data_dir: &Path, allow_migration: bool) -> anyhow::Result<DataDirReport> {
+    let report = check(data_dir).await?;
+    if report.is_empty() {
+        return Ok(report);
+    }
+
+    if let Some(manifest) = &report.manifest {
+        if manifest.format_version > DATA_DIR_FORMAT_VERSION {
+            bail!(
+                "The data dir {} was created by a newer golem version ({}, format version {}), \
+                 use a matching golem version, or start with --clean",
+                data_dir.display(),
+                manifest.golem_version,
+                manifest.format_version
+            );
+        }
+    }
+    if let Some(db) = report.databases.iter().find(|db| !db.is_compatible()) {
+        bail!(
+            "The database {} in {} is not compatible with golem {}, see `golem server data check`, \
+             use a matching golem version, or start with --clean",
+            db.name,
+            data_dir.display(),
+            report.golem_version
+        );
+    }
+    if report.has_pending_migrations() && !allow_migration {
+        bail!(
+            "The data dir {} has pending database migrations for golem {}, see `golem server data check`. \
+             Start with --migrate to apply them, consider saving a snapshot first with `golem server snapshot save`",
+            data_dir.display(),
+            report.golem_version
+        );
+    }
+
+    Ok(report)
+}
+
+/// Verifies the data dir before starting the services, and stamps it with the current version
+pub async fn prepare(data_dir: &Path, allow_migration: bool) -> anyhow::Result<()> {
+    tokio::fs::create_dir_all(data_dir).await?;
+    let report = verify(data_dir, allow_migration).await?;
+    DataDirManifest::stamp(data_dir, report.manifest.as_ref())
+}
+
+async fn database_status(
+    path: &Path,
+    name: &str,
+    migrations: Option<Dir<'static>>,
+) -> anyhow::Result<DatabaseMigrationStatus> {
+    let known = match migrations {
+        Some(migrations) => IncludedMigrationsDir::new(migrations)
+            .sqlite_migrations()
+            .resolve()
+            .await
+            .map_err(|err| anyhow!("Failed to resolve migrations of {}: {}", name, err))?
+            .into_iter()
+            .filter(|migration| !migration.migration_type.is_down_migration())
+            .collect::<Vec<_>>(),
+        None => vec![],
+    };
+
+    let applied = if path.exists() {
+        Some(applied_migrations(path).await?)
+    } else {
+        None
+    };
+
+    let info = |version: i64, description: &str| MigrationInfo {
+        version,
+        description: description.to_string(),
+    };
+
+    let mut status = DatabaseMigrationStatus {
+        name: name.to_string(),
+        exists: applied.is_some(),
+        applied: vec![],
+        pending: vec![],
+        unknown: vec![],
+        modified: vec![],
+    };
+    let applied = applied.unwrap_or_default();
+
+    for migration in &known {
+        match applied.get(&migration.version) {
+            Some(applied) => {
+                let migration_info = info(migration.version, &migration.description);
+                if applied.success && applied.checksum == *migration.checksum {
+                    status.applied.push(migration_info);
+                } else {
+                    status.modified.push(migration_info);
+                }
+            }
+            None => status
+                .pending
+                .push(info(migration.version, &migration.description)),
+        }
+    }
+    for (version, applied) in &applied {
+        if !known.iter().any(|migration| migration.version == *version) {
+            status.unknown.push(info(*version, &applied.description));
+        }
+    }
+
+    Ok(status)
+}
+
+struct AppliedMigration {
+    description: String,
+    success: bool,
+    checksum: Vec<u8>,
+}
+
+async fn applied_migrations(path: &Path) -> anyhow::Result<BTreeMap<i64, AppliedMigration>> {
+    let mut connection = SqliteConnectOptions::new()
+        .filename(path)
+        .read_only(true)
+        .connect()
+        .await
+        .with_context(|| anyhow!("Failed to open database: {}", path.display()))?;
+
+    let has_migrations_table: bool = sqlx::query_scalar(
+        "SELECT COUNT(*) > 0 FROM sqlite_master WHERE type = 'table' AND name = '_sqlx_migrations'",
+    )
+    .fetch_one(&mut connection)
+    .await?;
+
+    let applied = if has_migrations_table {
+        sqlx::query_as::<_, (i64, String, bool, Vec<u8>)>(
+            "SELECT version, description, success, checksum FROM _sqlx_migrations ORDER BY version",
+        )
+        .fetch_all(&mut connection)
+        .await
+        .with_context(|| anyhow!("Failed to read migrations of {}", path.display()))?
+        .into_iter()
+        .map(|(version, description, success, checksum)| {
+            (
+                version,
+                AppliedMigration {
+                    description,
+                    success,
+                    checksum,
+                },
+            )
+        })
+        .collect()
+    } else {
+        BTreeMap::new()
+    };
+
+    connection.close().await?;
+
+    Ok(applied)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::data_dir::{
+        check, prepare, verify, DataDirManifest, COMPONENTS_DB, DATA_DIR_FORMAT_VERSION,
+        GATEWAY_SESSIONS_DB, KV_STORE_DB, WORKERS_DB,
+    };
+    use assert2::{assert, let_assert};
+    use sqlx::sqlite::SqliteConnectOptions;
+    use sqlx::{ConnectOptions, Connection};
+    use std::path::Path;
+    use test_r::test;
+
+    fn write_manifest(data_dir: &Path, format_version: u32) {
+        std::fs::write(
+            DataDirManifest::path(data_dir),
+            format!(
+                r#"
+                    format-version = {format_version}
+                    golem-version = "9.9.9"
+                    created-at = "2025-01-02T03:04:05Z"
+                    updated-at = "2025-02-03T04:05:06Z"
+                "#
+            ),
+        )
+        .unwrap();
+    }
+
+    async fn create_empty_database(path: &Path) {
+        SqliteConnectOptions::new()
+            .filename(path)
+            .create_if_missing(true)
+            .connect()
+            .await
+            .unwrap()
+            .close()
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    fn manifest_parsing() {
+        let data_dir = tempfile::tempdir().unwrap();
+        assert!(DataDirManifest::load(data_dir.path()).unwrap().is_none());
+
+        write_manifest(data_dir.path(), 1);
+        let_assert!(Some(manifest) = DataDirManifest::load(data_dir.path()).unwrap());
+        assert!(manifest.format_version == 1);
+        assert!(manifest.golem_version == "9.9.9");
+        assert!(manifest.created_at.to_rfc3339() == "2025-01-02T03:04:05+00:00");
+
+        std::fs::write(
+            DataDirManifest::path(data_dir.path()),
+            "format-version = \"x\"",
+        )
+        .unwrap();
+        assert!(DataDirManifest::load(data_dir.path()).is_err());
+    }
+
+    #[test]
+    async fn check_reports_every_database() {
+        let data_dir = tempfile::tempdir().unwrap();
+        create_empty_database(&data_dir.path().join(KV_STORE_DB)).await;
+
+        let report = check(data_dir.path()).await.unwrap();
+        let names = report
+            .databases
+            .iter()
+            .map(|db| db.name.as_str())
+            .collect::<Vec<_>>();
+        assert!(names == vec![COMPONENTS_DB, WORKERS_DB, KV_STORE_DB, GATEWAY_SESSIONS_DB]);
+
+        let kv_store = &report.databases[2];
+        assert!(kv_store.exists);
+        assert!(kv_store.is_compatible());
+        assert!(kv_store.pending.is_empty());
+        assert!(!report.databases[3].exists);
+    }
+
+    #[test]
+    async fn newer_format_version_is_refused() {
+        let data_dir = tempfile::tempdir().unwrap();
+        write_manifest(data_dir.path(), DATA_DIR_FORMAT_VERSION + 1);
+
+        let_assert!(Err(err) = verify(data_dir.path(), true).await);
+        assert!(err.to_string().contains("newer golem version"));
+        assert!(prepare(data_dir.path(), true).await.is_err());
+    }
+
+    #[test]
+    async fn pending_migrations_require_migrate() {
+        let data_dir = tempfile::tempdir().unwrap();
+        create_empty_database(&data_dir.path().join(COMPONENTS_DB)).await;
+
+        let report = check(data_dir.path()).await.unwrap();
+        assert!(report.has_pending_migrations());
+
+        let_assert!(Err(err) = verify(data_dir.path(), false).await);
+        assert!(err.to_string().contains("--migrate"));
+        assert!(verify(data_dir.path(), true).await.is_ok());
+    }
+
+    #[test]
+    async fn prepare_stamps_the_data_dir() {
+        let root = tempfile::tempdir().unwrap();
+        let data_dir = root.path().join("data");
+
+        prepare(&data_dir, false).await.unwrap();
+        let_assert!(Some(first) = DataDirManifest::load(&data_dir).unwrap());
+        assert!(first.format_version == DATA_DIR_FORMAT_VERSION);
+        assert!(first.golem_version == golem_cli::version());
+
+        prepare(&data_dir, false).await.unwrap();
+        let_assert!(Some(second) = DataDirManifest::load(&data_dir).unwrap());
+        assert!(second.created_at == first.created_at);
+        assert!(second.updated_at >= first.updated_at);
+    }
+}
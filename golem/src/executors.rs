@@ -0,0 +1,301 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::{anyhow, bail};
+use golem_cli::log::{logln, LogColorize};
+use golem_cli::model::text::fmt::TextView;
+use golem_worker_executor_base::services::additional_config::DefaultAdditionalGolemConfig;
+use golem_worker_executor_base::services::golem_config::GolemConfig;
+use poem::http::StatusCode;
+use poem::web::{Data, Json, Path};
+use poem::{get, handler, post, Endpoint, EndpointExt, Route};
+use serde::{Deserialize, Serialize};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::runtime::Handle;
+use tokio::sync::{mpsc, Mutex};
+use tokio::task::{JoinHandle, JoinSet};
+use tracing::{info, Instrument};
+
+/// Path of the local admin API for managing the worker executors
+pub const EXECUTORS_API_PATH: &str = "/v1/local/executors";
+
+struct RunningExecutor {
+    run_details: golem_worker_executor_base::RunDetails,
+    /// Owns the tasks of the executor, aborting it stops the executor
+    supervisor: JoinHandle<()>,
+}
+
+/// Worker executors of the local server, all registered with the same shard manager.
+///
+/// Every executor runs its tasks in its own join set, so single executors can be killed and restarted
+/// for simulating node loss, while unexpected executor failures still stop the whole server.
+pub struct WorkerExecutors {
+    config: GolemConfig,
+    additional_config: DefaultAdditionalGolemConfig,
+    executors: Mutex<Vec<Option<RunningExecutor>>>,
+    failures: mpsc::UnboundedSender<anyhow::Error>,
+}
+
+impl WorkerExecutors {
+    pub async fn start(
+        count: usize,
+        config: GolemConfig,
+        additional_config: DefaultAdditionalGolemConfig,
+    ) -> anyhow::Result<(Arc<Self>, mpsc::UnboundedReceiver<anyhow::Error>)> {
+        let (failures, failures_rx) = mpsc::unbounded_channel();
+        let executors = Arc::new(Self {
+            config,
+            additional_config,
+            executors: Mutex::new(Vec::with_capacity(count)),
+            failures,
+        });
+
+        for index in 0..count.max(1) {
+            let executor = executors.start_executor(index).await?;
+            executors.executors.lock().await.push(Some(executor));
+        }
+
+        Ok((executors, failures_rx))
+    }
+
+    pub async fn kill(&self, index: usize) -> anyhow::Result<ExecutorStatus> {
+        let mut executors = self.executors.lock().await;
+        let slot = executors
+            .get_mut(index)
+            .ok_or_else(|| anyhow!("Worker executor {} does not exist", index))?;
+        match slot.take() {
+            Some(executor) => {
+                executor.supervisor.abort();
+                info!("Killed worker executor {}", index);
+                Ok(ExecutorStatus::stopped(index))
+            }
+            None => bail!("Worker executor {} is not running", index),
+        }
+    }
+
+    pub async fn restart(&self, index: usize) -> anyhow::Result<ExecutorStatus> {
+        let mut executors = self.executors.lock().await;
+        let slot = executors
+            .get_mut(index)
+            .ok_or_else(|| anyhow!("Worker executor {} does not exist", index))?;
+        if let Some(executor) = slot.take() {
+            executor.supervisor.abort();
+            // Waiting for the tasks to be dropped, so the executor is deregistered before starting the new one
+            let _ = executor.supervisor.await;
+        }
+
+        let executor = self.start_executor(index).await?;
+        let status = ExecutorStatus::running(index, &executor.run_details);
+        *slot = Some(executor);
+        info!("Restarted worker executor {}", index);
+
+        Ok(status)
+    }
+
+    pub async fn status(&self) -> Vec<ExecutorStatus> {
+        self.executors
+            .lock()
+            .await
+            .iter()
+            .enumerate()
+            .map(|(index, executor)| match executor {
+                Some(executor) => ExecutorStatus::running(index, &executor.run_details),
+                None => ExecutorStatus::stopped(index),
+            })
+            .collect()
+    }
+
+    async fn start_executor(&self, index: usize) -> anyhow::Result<RunningExecutor> {
+        let mut join_set = JoinSet::new();
+        let prometheus_registry = golem_worker_executor_base::metrics::register_all();
+        let span = tracing::info_span!("worker-executor", executor = index);
+
+        let run_details = golem_worker_executor::run(
+            self.config.clone(),
+            self.additional_config.clone(),
+            prometheus_registry,
+            Handle::current(),
+            &mut join_set,
+        )
+        .instrument(span.clone())
+        .await?;
+
+        let failures = self.failures.clone();
+        let supervisor = tokio::spawn(
+            async move {
+                while let Some(result) = join_set.join_next().await {
+                    let result = result
+                        .map_err(anyhow::Error::from)
+                        .and_then(|result| result);
+                    if let Err(err) = result {
+                        let _ =
+                            failures.send(err.context(format!("Worker executor {} failed", index)));
+                        break;
+                    }
+                }
+            }
+            .instrument(span),
+        );
+
+        Ok(RunningExecutor {
+            run_details,
+            supervisor,
+        })
+    }
+}
+
+/// Admin API of the worker executors, mounted into the router at [EXECUTORS_API_PATH].
+///
+/// The API is unauthenticated, so it only accepts requests from the local machine,
+/// even when the router is bound to other interfaces.
+pub fn executors_api(executors: Arc<WorkerExecutors>) -> impl Endpoint {
+    Route::new()
+        .at("/", get(list_executors))
+        .at("/:index/kill", post(kill_executor))
+        .at("/:index/restart", post(restart_executor))
+        .data(executors)
+        .around(|endpoint, request| async move {
+            if !is_loopback(request.remote_addr().as_socket_addr()) {
+                return Err(poem::Error::from_string(
+                    "The executors API is only available from the local machine",
+                    StatusCode::FORBIDDEN,
+                ));
+            }
+            endpoint.call(request).await
+        })
+}
+
+fn is_loopback(addr: Option<&SocketAddr>) -> bool {
+    // Dual-stack listeners report IPv4 clients as IPv4-mapped IPv6 addresses
+    addr.is_some_and(|addr| addr.ip().to_canonical().is_loopback())
+}
+
+#[handler]
+async fn list_executors(Data(executors): Data<&Arc<WorkerExecutors>>) -> Json<ExecutorStatusList> {
+    Json(ExecutorStatusList(executors.status().await))
+}
+
+#[handler]
+async fn kill_executor(
+    Path(index): Path<usize>,
+    Data(executors): Data<&Arc<WorkerExecutors>>,
+) -> poem::Result<Json<ExecutorStatus>> {
+    executors
+        .kill(index)
+        .await
+        .map(Json)
+        .map_err(|err| poem::Error::from_string(err.to_string(), StatusCode::BAD_REQUEST))
+}
+
+#[handler]
+async fn restart_executor(
+    Path(index): Path<usize>,
+    Data(executors): Data<&Arc<WorkerExecutors>>,
+) -> poem::Result<Json<ExecutorStatus>> {
+    executors
+        .restart(index)
+        .await
+        .map(Json)
+        .map_err(|err| poem::Error::from_string(err.to_string(), StatusCode::INTERNAL_SERVER_ERROR))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecutorStatus {
+    pub index: usize,
+    pub running: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grpc_port: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_port: Option<u16>,
+}
+
+impl ExecutorStatus {
+    fn running(index: usize, run_details: &golem_worker_executor_base::RunDetails) -> Self {
+        Self {
+            index,
+            running: true,
+            grpc_port: Some(run_details.grpc_port),
+            http_port: Some(run_details.http_port),
+        }
+    }
+
+    fn stopped(index: usize) -> Self {
+        Self {
+            index,
+            running: false,
+            grpc_port: None,
+            http_port: None,
+        }
+    }
+}
+
+impl TextView for ExecutorStatus {
+    fn log(&self) {
+        let status = if self.running {
+            "running".log_color_ok_highlight()
+        } else {
+            "stopped".log_color_error_highlight()
+        };
+        match self.grpc_port {
+            Some(grpc_port) => logln(format!(
+                "Worker executor {}: {} (gRPC port {})",
+                self.index.to_string().log_color_highlight(),
+                status,
+                grpc_port
+            )),
+            None => logln(format!(
+                "Worker executor {}: {}",
+                self.index.to_string().log_color_highlight(),
+                status
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecutorStatusList(pub Vec<ExecutorStatus>);
+
+impl TextView for ExecutorStatusList {
+    fn log(&self) {
+        for executor in &self.0 {
+            executor.log();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::executors::is_loopback;
+    use assert2::assert;
+    use std::net::SocketAddr;
+    use test_r::test;
+
+    fn addr(addr: &str) -> SocketAddr {
+        addr.parse().unwrap()
+    }
+
+    #[test]
+    fn only_loopback_peers_are_accepted() {
+        assert!(is_loopback(Some(&addr("127.0.0.1:5000"))));
+        assert!(is_loopback(Some(&addr("[::1]:5000"))));
+        assert!(is_loopback(Some(&addr("[::ffff:127.0.0.1]:5000"))));
+
+        assert!(!is_loopback(Some(&addr("192.168.1.10:5000"))));
+        assert!(!is_loopback(Some(&addr("[::ffff:192.168.1.10]:5000"))));
+        assert!(!is_loopback(Some(&addr("[2001:db8::1]:5000"))));
+        assert!(!is_loopback(None));
+    }
+}
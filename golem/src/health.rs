@@ -0,0 +1,275 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::executors::WorkerExecutors;
+use futures::future::join_all;
+use golem_cli::log::{logln, LogColorize, LogIndent};
+use golem_cli::model::text::fmt::TextView;
+use poem::endpoint::BoxEndpoint;
+use poem::http::{StatusCode, Uri};
+use poem::{Endpoint, EndpointExt, Request, Response};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+const HEALTHCHECK_PATH: &str = "/healthcheck";
+const HEALTHCHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServiceHealth {
+    pub service: String,
+    pub healthy: bool,
+    pub latency_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HealthReport {
+    pub healthy: bool,
+    pub services: Vec<ServiceHealth>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl HealthReport {
+    pub fn unreachable(error: String) -> Self {
+        Self {
+            healthy: false,
+            services: vec![],
+            error: Some(error),
+        }
+    }
+}
+
+impl TextView for HealthReport {
+    fn log(&self) {
+        if self.healthy {
+            logln(format!(
+                "Golem server is {}",
+                "healthy".log_color_ok_highlight()
+            ));
+        } else {
+            logln(format!(
+                "Golem server is {}",
+                "unhealthy".log_color_error_highlight()
+            ));
+        }
+
+        let _indent = LogIndent::new();
+        if let Some(error) = &self.error {
+            logln(format!("Server is not reachable: {}", error));
+        }
+        let padding = self
+            .services
+            .iter()
+            .map(|service| service.service.len())
+            .max()
+            .unwrap_or(0)
+            + 1;
+        for service in &self.services {
+            let status = if service.healthy {
+                "ok".log_color_ok_highlight()
+            } else {
+                "failed".log_color_error_highlight()
+            };
+            let mut line = format!(
+                "{:<padding$} {} ({} ms)",
+                format!("{}:", service.service),
+                status,
+                service.latency_ms
+            );
+            if let Some(error) = &service.error {
+                line.push_str(&format!(" - {}", error));
+            }
+            logln(line);
+        }
+    }
+}
+
+enum HealthCheckTarget {
+    /// Service with its own HTTP server, checked through its healthcheck route
+    Http { port: u16 },
+    /// Service mounted into the router, checked in-process
+    Endpoint(BoxEndpoint<'static, Response>),
+}
+
+struct ServiceHealthCheck {
+    service: &'static str,
+    target: HealthCheckTarget,
+}
+
+/// Aggregated health check of all the services started by the single executable server
+#[derive(Clone)]
+pub struct HealthCheck {
+    checks: Arc<Vec<ServiceHealthCheck>>,
+    /// Checked separately, as executors can be killed and restarted on new ports
+    worker_executors: Arc<WorkerExecutors>,
+    client: reqwest::Client,
+}
+
+impl HealthCheck {
+    pub fn new<C, W>(
+        shard_manager: &golem_shard_manager::RunDetails,
+        component_compilation_service: &golem_component_compilation_service::RunDetails,
+        worker_executors: Arc<WorkerExecutors>,
+        component_service_api: C,
+        worker_service_api: W,
+    ) -> Self
+    where
+        C: Endpoint + 'static,
+        W: Endpoint + 'static,
+    {
+        Self {
+            checks: Arc::new(vec![
+                ServiceHealthCheck {
+                    service: "shard-manager",
+                    target: HealthCheckTarget::Http {
+                        port: shard_manager.http_port,
+                    },
+                },
+                ServiceHealthCheck {
+                    service: "component-compilation-service",
+                    target: HealthCheckTarget::Http {
+                        port: component_compilation_service.http_port,
+                    },
+                },
+                ServiceHealthCheck {
+                    service: "component-service",
+                    target: HealthCheckTarget::Endpoint(
+                        component_service_api.map_to_response().boxed(),
+                    ),
+                },
+                ServiceHealthCheck {
+                    service: "worker-service",
+                    target: HealthCheckTarget::Endpoint(
+                        worker_service_api.map_to_response().boxed(),
+                    ),
+                },
+            ]),
+            worker_executors,
+            client: reqwest::Client::builder()
+                .timeout(HEALTHCHECK_TIMEOUT)
+                .build()
+                .expect("Failed to create HTTP client for health checks"),
+        }
+    }
+
+    pub async fn check(&self) -> HealthReport {
+        let mut services =
+            join_all(self.checks.iter().map(|check| self.check_service(check))).await;
+        services.extend(self.check_worker_executors().await);
+
+        HealthReport {
+            healthy: services.iter().all(|service| service.healthy),
+            services,
+            error: None,
+        }
+    }
+
+    async fn check_worker_executors(&self) -> Vec<ServiceHealth> {
+        let executors = self.worker_executors.status().await;
+        let single = executors.len() == 1;
+
+        join_all(executors.into_iter().map(|executor| async move {
+            let service = if single {
+                "worker-executor".to_string()
+            } else {
+                format!("worker-executor-{}", executor.index)
+            };
+            match executor.http_port {
+                Some(port) => {
+                    let start = Instant::now();
+                    let result = self.check_http(port).await;
+                    ServiceHealth {
+                        service,
+                        healthy: result.is_ok(),
+                        latency_ms: start.elapsed().as_millis() as u64,
+                        error: result.err(),
+                    }
+                }
+                None => ServiceHealth {
+                    service,
+                    healthy: false,
+                    latency_ms: 0,
+                    error: Some("stopped".to_string()),
+                },
+            }
+        }))
+        .await
+    }
+
+    async fn check_http(&self, port: u16) -> Result<(), String> {
+        self.client
+            .get(format!("http://127.0.0.1:{}{}", port, HEALTHCHECK_PATH))
+            .send()
+            .await
+            .map_err(|err| err.to_string())
+            .and_then(|response| status_result(response.status().as_u16()))
+    }
+
+    async fn check_service(&self, check: &ServiceHealthCheck) -> ServiceHealth {
+        let start = Instant::now();
+
+        let result = match &check.target {
+            HealthCheckTarget::Http { port } => self.check_http(*port).await,
+            HealthCheckTarget::Endpoint(endpoint) => {
+                let request = Request::builder()
+                    .uri(Uri::from_static(HEALTHCHECK_PATH))
+                    .finish();
+                match tokio::time::timeout(HEALTHCHECK_TIMEOUT, endpoint.get_response(request))
+                    .await
+                {
+                    Ok(response) => status_result(response.status().as_u16()),
+                    Err(_) => Err("timed out".to_string()),
+                }
+            }
+        };
+
+        ServiceHealth {
+            service: check.service.to_string(),
+            healthy: result.is_ok(),
+            latency_ms: start.elapsed().as_millis() as u64,
+            error: result.err(),
+        }
+    }
+
+    pub fn endpoint(self) -> impl Endpoint<Output = Response> {
+        poem::endpoint::make(move |_request| {
+            let health_check = self.clone();
+            async move {
+                let report = health_check.check().await;
+                let status = if report.healthy {
+                    StatusCode::OK
+                } else {
+                    StatusCode::SERVICE_UNAVAILABLE
+                };
+                Response::builder()
+                    .status(status)
+                    .content_type("application/json")
+                    .body(serde_json::to_string(&report).unwrap_or_default())
+            }
+        })
+    }
+}
+
+fn status_result(status: u16) -> Result<(), String> {
+    if (200..300).contains(&status) {
+        Ok(())
+    } else {
+        Err(format!("healthcheck returned HTTP {}", status))
+    }
+}
@@ -0,0 +1,503 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::config::RequestJournalConfig;
+use crate::daemon::{read_lines, rotate_file, rotated_file};
+use anyhow::{anyhow, bail, Context};
+use chrono::{DateTime, Utc};
+use golem_cli::log::{logln, LogColorize};
+use golem_cli::model::text::fmt::TextView;
+use poem::{Endpoint, IntoResponse, Middleware, Request, Response};
+use serde::{Deserialize, Serialize};
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use tracing::warn;
+
+const JOURNAL_FILE: &str = "requests.jsonl";
+const MAX_ERROR_LENGTH: usize = 1024;
+/// Requests which are polled by tooling, and would only add noise to the journal
+const IGNORED_PATHS: &[&str] = &["/healthcheck", "/metrics"];
+
+pub fn journal_file(data_dir: &Path) -> PathBuf {
+    data_dir.join("logs").join(JOURNAL_FILE)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestJournalEntry {
+    pub timestamp: DateTime<Utc>,
+    pub service: String,
+    pub method: String,
+    pub path: String,
+    pub status: u16,
+    pub latency_ms: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub component_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub worker_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl TextView for RequestJournalEntry {
+    fn log(&self) {
+        let status = if self.status >= 400 {
+            self.status.to_string().log_color_error_highlight()
+        } else {
+            self.status.to_string().log_color_ok_highlight()
+        };
+        let mut line = format!(
+            "{} {:<21} {:<6} {} {} {} ms",
+            self.timestamp.format("%Y-%m-%d %H:%M:%S%.3f"),
+            self.service,
+            self.method,
+            self.path.log_color_highlight(),
+            status,
+            self.latency_ms
+        );
+        if let Some(component_id) = &self.component_id {
+            line.push_str(&format!(" component={}", component_id));
+        }
+        if let Some(worker_name) = &self.worker_name {
+            line.push_str(&format!(" worker={}", worker_name));
+        }
+        if let Some(error) = &self.error {
+            line.push_str(&format!(" error={}", error.log_color_warn()));
+        }
+        logln(line);
+    }
+}
+
+/// Size rotated JSON lines file of the requests served by the local server
+pub struct RequestJournal {
+    path: PathBuf,
+    max_file_size: u64,
+    max_files: usize,
+    file: Mutex<(File, u64)>,
+}
+
+impl RequestJournal {
+    pub fn open(data_dir: &Path, config: &RequestJournalConfig) -> anyhow::Result<Self> {
+        let path = journal_file(data_dir);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let file = open_append(&path)?;
+        let size = file.metadata()?.len();
+
+        Ok(Self {
+            path,
+            max_file_size: config.max_file_size(),
+            max_files: config.max_files(),
+            file: Mutex::new((file, size)),
+        })
+    }
+
+    pub fn record(&self, entry: &RequestJournalEntry) {
+        if let Err(err) = self.write(entry) {
+            warn!("Failed to write request journal: {}", err);
+        }
+    }
+
+    fn write(&self, entry: &RequestJournalEntry) -> anyhow::Result<()> {
+        let mut line = serde_json::to_string(entry)?;
+        line.push('\n');
+
+        let mut file = self
+            .file
+            .lock()
+            .map_err(|_| anyhow!("Request journal lock poisoned"))?;
+        if file.1 > 0 && file.1 + line.len() as u64 > self.max_file_size {
+            rotate_file(&self.path, self.max_files)?;
+            *file = (open_append(&self.path)?, 0);
+        }
+        file.0.write_all(line.as_bytes())?;
+        file.1 += line.len() as u64;
+
+        Ok(())
+    }
+}
+
+fn open_append(path: &Path) -> anyhow::Result<File> {
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| anyhow!("Failed to open request journal: {}", path.display()))
+}
+
+/// Middleware recording the requests of an endpoint into the journal, when the journal is enabled
+pub struct RequestJournalMiddleware {
+    journal: Option<Arc<RequestJournal>>,
+    service: Option<&'static str>,
+}
+
+impl RequestJournalMiddleware {
+    /// Without a fixed service name, the service is derived from the request path
+    pub fn new(journal: Option<Arc<RequestJournal>>, service: Option<&'static str>) -> Self {
+        Self { journal, service }
+    }
+}
+
+impl<E: Endpoint> Middleware<E> for RequestJournalMiddleware {
+    type Output = RequestJournalEndpoint<E>;
+
+    fn transform(&self, ep: E) -> Self::Output {
+        RequestJournalEndpoint {
+            inner: ep,
+            journal: self.journal.clone(),
+            service: self.service,
+        }
+    }
+}
+
+pub struct RequestJournalEndpoint<E> {
+    inner: E,
+    journal: Option<Arc<RequestJournal>>,
+    service: Option<&'static str>,
+}
+
+impl<E: Endpoint> Endpoint for RequestJournalEndpoint<E> {
+    type Output = Response;
+
+    async fn call(&self, req: Request) -> poem::Result<Self::Output> {
+        let Some(journal) = &self.journal else {
+            return self.inner.call(req).await.map(IntoResponse::into_response);
+        };
+        let path = req.uri().path().to_string();
+        if IGNORED_PATHS.contains(&path.as_str()) {
+            return self.inner.call(req).await.map(IntoResponse::into_response);
+        }
+
+        let start = Instant::now();
+        let timestamp = Utc::now();
+        let method = req.method().to_string();
+
+        let mut response = self.inner.get_response(req).await;
+
+        let status = response.status();
+        let error = if status.is_client_error() || status.is_server_error() {
+            // Error bodies are small, so they are buffered for the journal and then restored
+            let body = response.take_body().into_bytes().await.unwrap_or_default();
+            let error = String::from_utf8_lossy(&body)
+                .chars()
+                .take(MAX_ERROR_LENGTH)
+                .collect::<String>();
+            response.set_body(body);
+            Some(error).filter(|error| !error.is_empty())
+        } else {
+            None
+        };
+
+        let (component_id, worker_name) = path_ids(&path);
+        journal.record(&RequestJournalEntry {
+            timestamp,
+            service: self
+                .service
+                .unwrap_or_else(|| service_by_path(&path))
+                .to_string(),
+            method,
+            path,
+            status: status.as_u16(),
+            latency_ms: start.elapsed().as_millis() as u64,
+            component_id,
+            worker_name,
+            error,
+        });
+
+        Ok(response)
+    }
+}
+
+/// Service handling the request, based on the routes of the router
+fn service_by_path(path: &str) -> &'static str {
+    let segments = path.split('/').collect::<Vec<_>>();
+    match segments.as_slice() {
+        ["", "v1", "api", ..] => "worker-service",
+        ["", "v1", "components", _, "workers", ..]
+        | ["", "v1", "components", _, "invoke", ..]
+        | ["", "v1", "components", _, "invoke-and-await", ..] => "worker-service",
+        ["", "v1", "components", ..]
+        | ["", "v1", "plugins", ..]
+        | ["", "v1", "app-plugins", ..]
+        | ["", "v1", "library-plugins", ..] => "component-service",
+        ["", "v1", "local", "executors", ..] => "worker-executor",
+        _ => "router",
+    }
+}
+
+fn path_ids(path: &str) -> (Option<String>, Option<String>) {
+    let segments = path.split('/').collect::<Vec<_>>();
+    let segment_after = |name: &str| {
+        segments
+            .iter()
+            .position(|segment| *segment == name)
+            .and_then(|index| segments.get(index + 1))
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| segment.to_string())
+    };
+    (segment_after("components"), segment_after("workers"))
+}
+
+/// Filter of journal entries in `field op value` form, e.g. `status>=400`, `worker=w1` or `path~invoke`
+#[derive(Debug, Clone)]
+pub struct RequestFilter {
+    field: RequestFilterField,
+    op: RequestFilterOp,
+    value: String,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum RequestFilterField {
+    Service,
+    Method,
+    Path,
+    Status,
+    Latency,
+    Component,
+    Worker,
+    Error,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum RequestFilterOp {
+    Eq,
+    NotEq,
+    Contains,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+}
+
+impl FromStr for RequestFilter {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Longer operators first, so `>=` is not parsed as `>`
+        let ops = [
+            (">=", RequestFilterOp::Ge),
+            ("<=", RequestFilterOp::Le),
+            ("!=", RequestFilterOp::NotEq),
+            ("=", RequestFilterOp::Eq),
+            ("~", RequestFilterOp::Contains),
+            (">", RequestFilterOp::Gt),
+            ("<", RequestFilterOp::Lt),
+        ];
+        let (index, op_str, op) = ops
+            .iter()
+            .filter_map(|(op_str, op)| s.find(op_str).map(|index| (index, *op_str, *op)))
+            .min_by_key(|(index, op_str, _)| (*index, usize::MAX - op_str.len()))
+            .ok_or_else(|| anyhow!("Missing operator in request filter: {}", s))?;
+
+        let field = match s[..index].trim().to_lowercase().as_str() {
+            "service" => RequestFilterField::Service,
+            "method" => RequestFilterField::Method,
+            "path" => RequestFilterField::Path,
+            "status" => RequestFilterField::Status,
+            "latency" => RequestFilterField::Latency,
+            "component" => RequestFilterField::Component,
+            "worker" => RequestFilterField::Worker,
+            "error" => RequestFilterField::Error,
+            other => bail!(
+                "Unknown request filter field: {}, available fields: service, method, path, status, latency, component, worker, error",
+                other
+            ),
+        };
+        let value = s[index + op_str.len()..].trim().to_string();
+
+        let numeric = matches!(
+            field,
+            RequestFilterField::Status | RequestFilterField::Latency
+        );
+        let ordering = matches!(
+            op,
+            RequestFilterOp::Gt | RequestFilterOp::Ge | RequestFilterOp::Lt | RequestFilterOp::Le
+        );
+        if numeric && value.parse::<u64>().is_err() {
+            bail!("Numeric value expected in request filter: {}", s);
+        }
+        if ordering && !numeric {
+            bail!(
+                "Only status and latency can be compared in request filter: {}",
+                s
+            );
+        }
+
+        Ok(Self { field, op, value })
+    }
+}
+
+impl RequestFilter {
+    pub fn matches(&self, entry: &RequestJournalEntry) -> bool {
+        let value = match self.field {
+            RequestFilterField::Service => Some(entry.service.clone()),
+            RequestFilterField::Method => Some(entry.method.clone()),
+            RequestFilterField::Path => Some(entry.path.clone()),
+            RequestFilterField::Status => Some(entry.status.to_string()),
+            RequestFilterField::Latency => Some(entry.latency_ms.to_string()),
+            RequestFilterField::Component => entry.component_id.clone(),
+            RequestFilterField::Worker => entry.worker_name.clone(),
+            RequestFilterField::Error => entry.error.clone(),
+        };
+        let Some(value) = value else {
+            return matches!(self.op, RequestFilterOp::NotEq);
+        };
+
+        let compare = |f: fn(u64, u64) -> bool| match (value.parse::<u64>(), self.value.parse()) {
+            (Ok(value), Ok(expected)) => f(value, expected),
+            _ => false,
+        };
+        match self.op {
+            RequestFilterOp::Eq => value.eq_ignore_ascii_case(&self.value),
+            RequestFilterOp::NotEq => !value.eq_ignore_ascii_case(&self.value),
+            RequestFilterOp::Contains => value.to_lowercase().contains(&self.value.to_lowercase()),
+            RequestFilterOp::Gt => compare(|a, b| a > b),
+            RequestFilterOp::Ge => compare(|a, b| a >= b),
+            RequestFilterOp::Lt => compare(|a, b| a < b),
+            RequestFilterOp::Le => compare(|a, b| a <= b),
+        }
+    }
+}
+
+/// Reads the journal entries matching all filters, starting with the oldest rotated file
+pub async fn read_journal<F>(
+    data_dir: &Path,
+    max_files: usize,
+    filters: &[RequestFilter],
+    follow: bool,
+    mut handler: F,
+) -> anyhow::Result<()>
+where
+    F: FnMut(RequestJournalEntry),
+{
+    let path = journal_file(data_dir);
+    if !path.exists() {
+        bail!(
+            "No request journal found at {}, enable it with --request-journal",
+            path.display()
+        );
+    }
+
+    let mut on_line = |line: &str| {
+        // Lines which cannot be parsed (e.g. partially written ones) are skipped
+        if let Ok(entry) = serde_json::from_str::<RequestJournalEntry>(line) {
+            if filters.iter().all(|filter| filter.matches(&entry)) {
+                handler(entry);
+            }
+        }
+    };
+
+    for index in (1..=max_files).rev() {
+        let rotated = rotated_file(&path, index);
+        if rotated.exists() {
+            read_lines(&rotated, false, &mut on_line).await?;
+        }
+    }
+    read_lines(&path, follow, &mut on_line).await
+}
+
+#[cfg(test)]
+mod test {
+    use crate::journal::{
+        path_ids, service_by_path, RequestFilter, RequestFilterField, RequestFilterOp,
+        RequestJournalEntry,
+    };
+    use assert2::{assert, let_assert};
+    use chrono::Utc;
+    use test_r::test;
+
+    fn entry() -> RequestJournalEntry {
+        RequestJournalEntry {
+            timestamp: Utc::now(),
+            service: "worker-service".to_string(),
+            method: "POST".to_string(),
+            path: "/v1/components/c1/workers/w1/invoke".to_string(),
+            status: 404,
+            latency_ms: 120,
+            component_id: Some("c1".to_string()),
+            worker_name: Some("w1".to_string()),
+            error: None,
+        }
+    }
+
+    fn filter(filter: &str) -> RequestFilter {
+        filter.parse().unwrap()
+    }
+
+    #[test]
+    fn request_filter_parsing() {
+        let_assert!(Ok(parsed) = "status>=400".parse::<RequestFilter>());
+        assert!(matches!(parsed.field, RequestFilterField::Status));
+        assert!(matches!(parsed.op, RequestFilterOp::Ge));
+        assert!(parsed.value == "400");
+
+        let_assert!(Ok(parsed) = " Worker != w1 ".parse::<RequestFilter>());
+        assert!(matches!(parsed.field, RequestFilterField::Worker));
+        assert!(matches!(parsed.op, RequestFilterOp::NotEq));
+        assert!(parsed.value == "w1");
+
+        let_assert!(Ok(parsed) = "path~a=b".parse::<RequestFilter>());
+        assert!(matches!(parsed.field, RequestFilterField::Path));
+        assert!(matches!(parsed.op, RequestFilterOp::Contains));
+        assert!(parsed.value == "a=b");
+
+        assert!("status".parse::<RequestFilter>().is_err());
+        assert!("unknown=1".parse::<RequestFilter>().is_err());
+        assert!("status=ok".parse::<RequestFilter>().is_err());
+        assert!("path>10".parse::<RequestFilter>().is_err());
+    }
+
+    #[test]
+    fn request_filter_matching() {
+        let entry = entry();
+
+        assert!(filter("status>=400").matches(&entry));
+        assert!(!filter("status<400").matches(&entry));
+        assert!(filter("latency>100").matches(&entry));
+        assert!(filter("method=post").matches(&entry));
+        assert!(filter("path~INVOKE").matches(&entry));
+        assert!(filter("worker=w1").matches(&entry));
+        assert!(!filter("worker!=w1").matches(&entry));
+        // Missing values only match negated filters
+        assert!(!filter("error~failed").matches(&entry));
+        assert!(filter("error!=failed").matches(&entry));
+    }
+
+    #[test]
+    fn service_by_request_path() {
+        assert!(service_by_path("/v1/api/definitions") == "worker-service");
+        assert!(service_by_path("/v1/components/c1/workers/w1") == "worker-service");
+        assert!(service_by_path("/v1/components/c1/invoke-and-await") == "worker-service");
+        assert!(service_by_path("/v1/components") == "component-service");
+        assert!(service_by_path("/v1/components/c1/versions/2") == "component-service");
+        assert!(service_by_path("/v1/plugins") == "component-service");
+        assert!(service_by_path("/v1/local/executors/0/kill") == "worker-executor");
+        assert!(service_by_path("/healthcheck") == "router");
+    }
+
+    #[test]
+    fn ids_by_request_path() {
+        assert!(
+            path_ids("/v1/components/c1/workers/w1/invoke")
+                == (Some("c1".to_string()), Some("w1".to_string()))
+        );
+        assert!(path_ids("/v1/components/c1/workers") == (Some("c1".to_string()), None));
+        assert!(path_ids("/v1/components/") == (None, None));
+    }
+}
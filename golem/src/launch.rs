@@ -12,8 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::config::{OtlpConfig, ServerConfig, WorkerExecutorServerConfig};
+use crate::data_dir::{COMPONENTS_DB, GATEWAY_SESSIONS_DB, KV_STORE_DB, WORKERS_DB};
+use crate::executors::WorkerExecutors;
+use crate::journal::RequestJournal;
+use crate::listener::{connect_host, needs_custom_request_frontend, TlsMaterial};
 use crate::migration::IncludedMigrationsDir;
-use crate::router::start_router;
+use crate::router::{start_custom_request_frontend, start_router};
+use crate::telemetry;
 use crate::StartedComponents;
 use anyhow::Context;
 use golem_common::config::DbConfig;
@@ -49,7 +55,7 @@ use opentelemetry::global;
 use opentelemetry_sdk::metrics::MeterProviderBuilder;
 use prometheus::Registry;
 use std::path::PathBuf;
-use tokio::runtime::Handle;
+use std::sync::Arc;
 use tokio::task::JoinSet;
 use tracing::Instrument;
 
@@ -58,6 +64,35 @@ pub struct LaunchArgs {
     pub router_port: u16,
     pub custom_request_port: u16,
     pub data_dir: PathBuf,
+    pub blob_storage_dir: PathBuf,
+    pub worker_executor: WorkerExecutorServerConfig,
+    pub tls: Option<TlsMaterial>,
+    pub request_journal: Option<Arc<RequestJournal>>,
+    pub otlp: OtlpConfig,
+}
+
+impl LaunchArgs {
+    pub fn new(config: &ServerConfig) -> anyhow::Result<Self> {
+        let data_dir = config.data_dir()?;
+        Ok(Self {
+            router_addr: config.router_addr.clone(),
+            router_port: config.router_port,
+            custom_request_port: config.custom_request_port,
+            blob_storage_dir: config.blob_storage_dir(&data_dir),
+            worker_executor: config.worker_executor.clone(),
+            tls: TlsMaterial::load(&config.tls, &data_dir)?,
+            request_journal: if config.request_journal.enabled {
+                Some(Arc::new(RequestJournal::open(
+                    &data_dir,
+                    &config.request_journal,
+                )?))
+            } else {
+                None
+            },
+            otlp: config.otlp.clone(),
+            data_dir,
+        })
+    }
 }
 
 pub async fn launch_golem_services(args: &LaunchArgs) -> anyhow::Result<()> {
@@ -69,11 +104,13 @@ pub async fn launch_golem_services(args: &LaunchArgs) -> anyhow::Result<()> {
         .with_registry(Registry::default())
         .build()?;
 
-    global::set_meter_provider(
-        MeterProviderBuilder::default()
-            .with_reader(exporter)
-            .build(),
-    );
+    let mut meter_provider = MeterProviderBuilder::default().with_reader(exporter);
+    if let Some(otlp_reader) = telemetry::otlp_metric_reader(&args.otlp)? {
+        meter_provider = meter_provider
+            .with_reader(otlp_reader)
+            .with_resource(telemetry::resource(&args.otlp));
+    }
+    global::set_meter_provider(meter_provider.build());
 
     let mut join_set: JoinSet<anyhow::Result<()>> = JoinSet::new();
 
@@ -86,14 +123,29 @@ pub async fn launch_golem_services(args: &LaunchArgs) -> anyhow::Result<()> {
             )
         })?;
 
-    let started_components = start_components(args, &mut join_set).await?;
-
-    start_router(
+    // The worker service can only serve plain HTTP on IPv4, so for TLS, IPv6 and the request journal
+    // it listens on an internal port, and the custom requests are forwarded to it by the router.
+    // The internal port is chosen by the worker service when binding, so it cannot be taken in between.
+    let custom_request_frontend = needs_custom_request_frontend(
         &args.router_addr,
-        args.router_port,
-        started_components,
-        &mut join_set,
-    )?;
+        args.tls.as_ref(),
+        args.request_journal.is_some(),
+    );
+    let custom_request_backend_port = if custom_request_frontend {
+        0
+    } else {
+        args.custom_request_port
+    };
+
+    let started_components =
+        start_components(args, custom_request_backend_port, &mut join_set).await?;
+    let bound_custom_request_port = started_components.worker_service.custom_request_port;
+
+    start_router(args, started_components, &mut join_set)?;
+
+    if custom_request_frontend {
+        start_custom_request_frontend(args, bound_custom_request_port, &mut join_set)?;
+    }
 
     while let Some(res) = join_set.join_next().await {
         res??;
@@ -104,6 +156,7 @@ pub async fn launch_golem_services(args: &LaunchArgs) -> anyhow::Result<()> {
 
 async fn start_components(
     args: &LaunchArgs,
+    custom_request_port: u16,
     join_set: &mut JoinSet<anyhow::Result<()>>,
 ) -> Result<StartedComponents, anyhow::Error> {
     let shard_manager = run_shard_manager(shard_manager_config(args), join_set).await?;
@@ -116,20 +169,38 @@ async fn start_components(
         join_set,
     )
     .await?;
-    let worker_executor = {
+    let worker_executors = {
         let (config, additional_config) =
             worker_executor_config(args, &shard_manager, &component_service);
-        run_worker_executor(config, additional_config, join_set).await?
+        let (worker_executors, mut failures) = WorkerExecutors::start(
+            args.worker_executor.instances.unwrap_or(1),
+            config,
+            additional_config,
+        )
+        .await?;
+        join_set.spawn(async move {
+            match failures.recv().await {
+                Some(err) => Err(err),
+                None => Ok(()),
+            }
+        });
+        worker_executors
     };
     let worker_service = run_worker_service(
-        worker_service_config(args, &shard_manager, &component_service),
+        worker_service_config(
+            args,
+            custom_request_port,
+            &shard_manager,
+            &component_service,
+        ),
         join_set,
     )
     .await?;
 
     Ok(StartedComponents {
+        component_compilation_service,
         shard_manager,
-        worker_executor,
+        worker_executors,
         component_service,
         worker_service,
         prometheus_registry: prometheus::default_registry().clone(),
@@ -138,7 +209,7 @@ async fn start_components(
 
 fn blob_storage_config(args: &LaunchArgs) -> BlobStorageConfig {
     BlobStorageConfig::LocalFileSystem(LocalFileSystemBlobStorageConfig {
-        root: args.data_dir.join("blobs"),
+        root: args.blob_storage_dir.clone(),
     })
 }
 
@@ -185,7 +256,7 @@ fn component_service_config(
         db: DbConfig::Sqlite(DbSqliteConfig {
             database: args
                 .data_dir
-                .join("components.db")
+                .join(COMPONENTS_DB)
                 .to_string_lossy()
                 .to_string(),
             max_connections: 32,
@@ -193,7 +264,7 @@ fn component_service_config(
         blob_storage: blob_storage_config(args),
         compilation: golem_component_service_base::config::ComponentCompilationConfig::Enabled(
             ComponentCompilationEnabledConfig {
-                host: args.router_addr.clone(),
+                host: connect_host(&args.router_addr),
                 port: component_compilation_service.grpc_port,
                 retries: Default::default(),
                 connect_timeout: Default::default(),
@@ -214,7 +285,7 @@ fn worker_executor_config(
         key_value_storage: KeyValueStorageConfig::Sqlite(DbSqliteConfig {
             database: args
                 .data_dir
-                .join("kv-store.db")
+                .join(KV_STORE_DB)
                 .to_string_lossy()
                 .to_string(),
             max_connections: 32,
@@ -225,25 +296,41 @@ fn worker_executor_config(
             CompiledComponentServiceEnabledConfig {},
         ),
         shard_manager_service: ShardManagerServiceConfig::Grpc(ShardManagerServiceGrpcConfig {
-            host: args.router_addr.clone(),
+            host: connect_host(&args.router_addr),
             port: shard_manager_run_details.grpc_port,
             ..ShardManagerServiceGrpcConfig::default()
         }),
         plugin_service: PluginServiceConfig::Grpc(PluginServiceGrpcConfig {
-            host: args.router_addr.clone(),
+            host: connect_host(&args.router_addr),
             port: component_service_run_details.grpc_port,
             ..Default::default()
         }),
         ..Default::default()
     };
 
+    if let Some(max_active_workers) = args.worker_executor.max_active_workers {
+        config.limits.max_active_workers = max_active_workers;
+    }
+    if let Some(system_memory_override) = args.worker_executor.system_memory_override {
+        config.memory.system_memory_override = Some(system_memory_override);
+    }
+    if let Some(worker_memory_ratio) = args.worker_executor.worker_memory_ratio {
+        config.memory.worker_memory_ratio = worker_memory_ratio;
+    }
+    if let Some(retries) = &args.worker_executor.retries {
+        config.retry = retries.clone();
+    }
+    if let Some(component_cache_capacity) = args.worker_executor.component_cache_capacity {
+        config.component_cache.max_capacity = component_cache_capacity;
+    }
+
     config.add_port_to_tracing_file_name_if_enabled();
 
     let additional_config = DefaultAdditionalGolemConfig {
         component_service:
             golem_worker_executor_base::services::additional_config::ComponentServiceConfig::Grpc(
                 ComponentServiceGrpcConfig {
-                    host: args.router_addr.clone(),
+                    host: connect_host(&args.router_addr),
                     port: component_service_run_details.grpc_port,
                     ..ComponentServiceGrpcConfig::default()
                 },
@@ -256,19 +343,16 @@ fn worker_executor_config(
 
 fn worker_service_config(
     args: &LaunchArgs,
+    custom_request_port: u16,
     shard_manager_run_details: &golem_shard_manager::RunDetails,
     component_service_run_details: &golem_component_service::TrafficReadyEndpoints,
 ) -> WorkerServiceBaseConfig {
     WorkerServiceBaseConfig {
         port: 0,
         worker_grpc_port: 0,
-        custom_request_port: args.custom_request_port,
+        custom_request_port,
         db: DbConfig::Sqlite(DbSqliteConfig {
-            database: args
-                .data_dir
-                .join("workers.db")
-                .to_string_lossy()
-                .to_string(),
+            database: args.data_dir.join(WORKERS_DB).to_string_lossy().to_string(),
             max_connections: 32,
         }),
         gateway_session_storage:
@@ -276,7 +360,7 @@ fn worker_service_config(
                 DbSqliteConfig {
                     database: args
                         .data_dir
-                        .join("gateway-sessions.db")
+                        .join(GATEWAY_SESSIONS_DB)
                         .to_string_lossy()
                         .to_string(),
                     max_connections: 32,
@@ -284,12 +368,12 @@ fn worker_service_config(
             ),
         blob_storage: blob_storage_config(args),
         component_service: golem_worker_service_base::app_config::ComponentServiceConfig {
-            host: args.router_addr.clone(),
+            host: connect_host(&args.router_addr),
             port: component_service_run_details.grpc_port,
             ..golem_worker_service_base::app_config::ComponentServiceConfig::default()
         },
         routing_table: RoutingTableConfig {
-            host: args.router_addr.clone(),
+            host: connect_host(&args.router_addr),
             port: shard_manager_run_details.grpc_port,
             ..RoutingTableConfig::default()
         },
@@ -334,25 +418,6 @@ async fn run_component_service(
         .await
 }
 
-async fn run_worker_executor(
-    config: GolemConfig,
-    additional_config: DefaultAdditionalGolemConfig,
-    join_set: &mut JoinSet<anyhow::Result<()>>,
-) -> Result<golem_worker_executor_base::RunDetails, anyhow::Error> {
-    let prometheus_registry = golem_worker_executor_base::metrics::register_all();
-
-    let span = tracing::info_span!("worker-executor");
-    golem_worker_executor::run(
-        config,
-        additional_config,
-        prometheus_registry,
-        Handle::current(),
-        join_set,
-    )
-    .instrument(span)
-    .await
-}
-
 async fn run_worker_service(
     config: WorkerServiceBaseConfig,
     join_set: &mut JoinSet<anyhow::Result<()>>,
@@ -15,17 +15,28 @@
 use prometheus::Registry;
 
 pub mod command_handler;
+pub mod config;
+mod daemon;
+mod data_dir;
+mod executors;
+mod health;
+mod journal;
 pub mod launch;
+mod listener;
 mod migration;
 mod router;
+mod seed;
+mod snapshot;
+mod telemetry;
 
 #[cfg(test)]
 test_r::enable!();
 
 pub struct StartedComponents {
+    pub component_compilation_service: golem_component_compilation_service::RunDetails,
     pub component_service: golem_component_service::TrafficReadyEndpoints,
     pub shard_manager: golem_shard_manager::RunDetails,
-    pub worker_executor: golem_worker_executor_base::RunDetails,
+    pub worker_executors: std::sync::Arc<executors::WorkerExecutors>,
     pub worker_service: golem_worker_service::TrafficReadyEndpoints,
     pub prometheus_registry: Registry,
 }
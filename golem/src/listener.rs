@@ -0,0 +1,284 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::config::TlsServerConfig;
+use anyhow::{anyhow, Context};
+use chrono::{DateTime, Duration, Utc};
+use golem_cli::fs;
+use golem_cli::model::certificate::{
+    check_certificate_pem, generate_self_signed_certificate_for_names,
+};
+use poem::http::header::{CONNECTION, HOST, TRANSFER_ENCODING};
+use poem::http::{HeaderValue, StatusCode};
+use poem::listener::{AcceptorExt, BoxAcceptor, RustlsCertificate, RustlsConfig, TcpAcceptor};
+use poem::{Endpoint, Request, Response};
+use socket2::{Domain, Protocol, Socket, Type};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, ToSocketAddrs};
+use std::path::{Path, PathBuf};
+use tracing::info;
+
+const DEV_CERTIFICATE_VALIDITY_DAYS: u32 = 365;
+/// The development certificate is regenerated when it expires within this many days
+const DEV_CERTIFICATE_RENEWAL_DAYS: i64 = 30;
+const DEV_CERTIFICATE_NAMES: &[&str] = &["localhost", "127.0.0.1", "::1"];
+
+#[derive(Clone)]
+pub struct TlsMaterial {
+    pub certificate_pem: String,
+    pub private_key_pem: String,
+}
+
+impl TlsMaterial {
+    /// Loads the configured certificate, or the self-signed development certificate stored in the data dir,
+    /// which is generated on first use and regenerated when it is close to expiry
+    pub fn load(config: &TlsServerConfig, data_dir: &Path) -> anyhow::Result<Option<Self>> {
+        if !config.is_enabled() {
+            return Ok(None);
+        }
+
+        match (&config.cert, &config.key) {
+            (Some(cert), Some(key)) => Ok(Some(Self {
+                certificate_pem: std::fs::read_to_string(cert).with_context(|| {
+                    anyhow!("Failed to read TLS certificate: {}", cert.display())
+                })?,
+                private_key_pem: std::fs::read_to_string(key).with_context(|| {
+                    anyhow!("Failed to read TLS private key: {}", key.display())
+                })?,
+            })),
+            (Some(_), None) | (None, Some(_)) => Err(anyhow!(
+                "Both the TLS certificate and the private key are required"
+            )),
+            (None, None) => Self::dev_certificate(data_dir).map(Some),
+        }
+    }
+
+    fn dev_certificate(data_dir: &Path) -> anyhow::Result<Self> {
+        let (cert_path, key_path) = dev_certificate_paths(data_dir);
+        let tls_dir = data_dir.join("tls");
+
+        if cert_path.exists() && key_path.exists() {
+            let certificate_pem = std::fs::read_to_string(&cert_path)?;
+            if !dev_certificate_needs_renewal(&certificate_pem, Utc::now()) {
+                return Ok(Self {
+                    certificate_pem,
+                    private_key_pem: std::fs::read_to_string(&key_path)?,
+                });
+            }
+            info!(
+                "Self-signed development certificate is expired or close to expiry, regenerating it: {}",
+                cert_path.display()
+            );
+        }
+
+        let names = DEV_CERTIFICATE_NAMES
+            .iter()
+            .map(|name| name.to_string())
+            .collect::<Vec<_>>();
+        let certificate =
+            generate_self_signed_certificate_for_names(&names, DEV_CERTIFICATE_VALIDITY_DAYS)?;
+
+        std::fs::create_dir_all(&tls_dir)?;
+        std::fs::write(&cert_path, &certificate.certificate_pem)?;
+        fs::write_str_owner_only(&key_path, &certificate.private_key_pem)?;
+        info!(
+            "Generated self-signed development certificate: {}",
+            cert_path.display()
+        );
+
+        Ok(Self {
+            certificate_pem: certificate.certificate_pem,
+            private_key_pem: certificate.private_key_pem,
+        })
+    }
+}
+
+fn dev_certificate_needs_renewal(certificate_pem: &str, now: DateTime<Utc>) -> bool {
+    let check = check_certificate_pem(String::new(), certificate_pem, None, now, 0);
+    check.has_errors()
+        || check
+            .not_after
+            .is_none_or(|not_after| not_after < now + Duration::days(DEV_CERTIFICATE_RENEWAL_DAYS))
+}
+
+/// Paths of the self-signed development certificate and its private key in the data dir
+pub fn dev_certificate_paths(data_dir: &Path) -> (PathBuf, PathBuf) {
+    let tls_dir = data_dir.join("tls");
+    (tls_dir.join("dev-cert.pem"), tls_dir.join("dev-key.pem"))
+}
+
+/// Binds a listener for the given address, serving HTTPS when TLS is configured.
+///
+/// HTTP/1.1 and HTTP/2 are both accepted, the unspecified IPv6 address (`::`) binds to both IPv4 and IPv6.
+pub fn bind(addr: &str, port: u16, tls: Option<&TlsMaterial>) -> anyhow::Result<BoxAcceptor> {
+    let socket_addr = resolve(addr, port)?;
+    let socket = Socket::new(
+        Domain::for_address(socket_addr),
+        Type::STREAM,
+        Some(Protocol::TCP),
+    )?;
+    if socket_addr.is_ipv6() {
+        socket.set_only_v6(!socket_addr.ip().is_unspecified())?;
+    }
+    socket.set_reuse_address(true)?;
+    socket.set_nonblocking(true)?;
+    socket
+        .bind(&socket_addr.into())
+        .with_context(|| anyhow!("Failed to bind {}", socket_addr))?;
+    socket.listen(1024)?;
+
+    let acceptor = TcpAcceptor::from_std(socket.into())?;
+
+    match tls {
+        Some(tls) => Ok(acceptor
+            .rustls(
+                RustlsConfig::new().fallback(
+                    RustlsCertificate::new()
+                        .cert(tls.certificate_pem.clone())
+                        .key(tls.private_key_pem.clone()),
+                ),
+            )
+            .boxed()),
+        None => Ok(acceptor.boxed()),
+    }
+}
+
+/// Host to be used for connecting to the internal services bound on the given address
+pub fn connect_host(addr: &str) -> String {
+    match addr.trim_matches(['[', ']']).parse::<IpAddr>() {
+        Ok(ip) if ip.is_unspecified() => Ipv4Addr::LOCALHOST.to_string(),
+        Ok(IpAddr::V6(ip)) => format!("[{}]", ip),
+        _ => addr.to_string(),
+    }
+}
+
+/// Whether the custom request port has to be served by the router, as the worker service
+/// can only bind plain HTTP on IPv4, and the requests would not be journaled otherwise
+pub fn needs_custom_request_frontend(
+    addr: &str,
+    tls: Option<&TlsMaterial>,
+    request_journal: bool,
+) -> bool {
+    tls.is_some()
+        || request_journal
+        || matches!(
+            addr.trim_matches(['[', ']']).parse::<IpAddr>(),
+            Ok(IpAddr::V6(_))
+        )
+}
+
+/// Forwards requests to a plain HTTP listener on the loopback interface
+pub fn proxy_endpoint(target_port: u16, tls: bool) -> impl Endpoint<Output = Response> {
+    let forwarded_proto = if tls { "https" } else { "http" };
+    let client = reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .build()
+        .expect("Failed to create HTTP client for the custom request proxy");
+
+    poem::endpoint::make(move |request: Request| {
+        let client = client.clone();
+        async move {
+            match forward(&client, target_port, forwarded_proto, request).await {
+                Ok(response) => response,
+                Err(err) => Response::builder()
+                    .status(StatusCode::BAD_GATEWAY)
+                    .body(err.to_string()),
+            }
+        }
+    })
+}
+
+async fn forward(
+    client: &reqwest::Client,
+    target_port: u16,
+    forwarded_proto: &'static str,
+    request: Request,
+) -> anyhow::Result<Response> {
+    let path_and_query = request
+        .uri()
+        .path_and_query()
+        .map(|path_and_query| path_and_query.as_str())
+        .unwrap_or("/");
+    let url = format!("http://127.0.0.1:{}{}", target_port, path_and_query);
+    let method = request.method().clone();
+
+    let mut headers = request.headers().clone();
+    headers.remove(CONNECTION);
+    headers.remove(TRANSFER_ENCODING);
+    // HTTP/2 requests carry the host in the authority, which is used by the API gateway for routing
+    if !headers.contains_key(HOST) {
+        if let Some(authority) = request.uri().authority() {
+            headers.insert(HOST, HeaderValue::from_str(authority.as_str())?);
+        }
+    }
+    headers.insert(
+        "x-forwarded-proto",
+        HeaderValue::from_static(forwarded_proto),
+    );
+
+    let body = request.into_body().into_bytes().await?;
+    let response = client
+        .request(method, url)
+        .headers(headers)
+        .body(body)
+        .send()
+        .await?;
+
+    let mut builder = Response::builder().status(response.status());
+    for (name, value) in response.headers() {
+        if name != CONNECTION && name != TRANSFER_ENCODING {
+            builder = builder.header(name, value);
+        }
+    }
+    Ok(builder.body(response.bytes().await?))
+}
+
+fn resolve(addr: &str, port: u16) -> anyhow::Result<SocketAddr> {
+    let host = addr.trim_matches(['[', ']']);
+    if let Ok(ip) = host.parse::<IpAddr>() {
+        return Ok(SocketAddr::new(ip, port));
+    }
+    (host, port)
+        .to_socket_addrs()
+        .with_context(|| anyhow!("Failed at parsing the listener host address {}", addr))?
+        .next()
+        .ok_or_else(|| anyhow!("Failed at resolving the listener host address {}", addr))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::listener::dev_certificate_needs_renewal;
+    use assert2::assert;
+    use chrono::Utc;
+    use golem_cli::model::certificate::generate_self_signed_certificate_for_names;
+    use test_r::test;
+
+    fn certificate_pem(validity_days: u32) -> String {
+        generate_self_signed_certificate_for_names(&["localhost".to_string()], validity_days)
+            .unwrap()
+            .certificate_pem
+    }
+
+    #[test]
+    fn dev_certificate_renewal() {
+        let now = Utc::now();
+
+        assert!(!dev_certificate_needs_renewal(&certificate_pem(365), now));
+        assert!(dev_certificate_needs_renewal(&certificate_pem(10), now));
+        assert!(dev_certificate_needs_renewal(
+            &certificate_pem(365),
+            now + chrono::Duration::days(400)
+        ));
+        assert!(dev_certificate_needs_renewal("not a certificate", now));
+    }
+}
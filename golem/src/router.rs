@@ -12,43 +12,44 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::executors::{executors_api, EXECUTORS_API_PATH};
+use crate::health::HealthCheck;
+use crate::journal::RequestJournalMiddleware;
+use crate::launch::LaunchArgs;
+use crate::listener::{bind, proxy_endpoint};
+use crate::telemetry::TraceContextMiddleware;
 use crate::StartedComponents;
-use anyhow::Context;
 use poem::middleware::{OpenTelemetryMetrics, Tracing};
 use poem::EndpointExt;
 use poem::{Route, Server};
-use std::net::Ipv4Addr;
 use tokio::task::JoinSet;
 use tracing::info;
 use tracing::Instrument;
 
 pub fn start_router(
-    listener_addr: &str,
-    listener_port: u16,
+    args: &LaunchArgs,
     started_components: StartedComponents,
     join_set: &mut JoinSet<Result<(), anyhow::Error>>,
 ) -> Result<(), anyhow::Error> {
-    use std::net::SocketAddrV4;
     use std::sync::Arc;
 
     use poem::endpoint::PrometheusExporter;
-    use poem::listener::TcpListener;
 
     info!("Starting single-executable http api");
 
-    let ipv4_addr: Ipv4Addr = listener_addr.parse().context(format!(
-        "Failed at parsing the listener host address {}",
-        listener_addr
-    ))?;
-
-    let listener_socket_addr = SocketAddrV4::new(ipv4_addr, listener_port);
-
-    let listener = TcpListener::bind(listener_socket_addr);
+    let acceptor = bind(&args.router_addr, args.router_port, args.tls.as_ref())?;
 
     let metrics = PrometheusExporter::new(started_components.prometheus_registry.clone());
 
     let worker_service_api = Arc::new(started_components.worker_service.api_endpoint);
     let component_service_api = Arc::new(started_components.component_service.endpoint);
+    let health_check = HealthCheck::new(
+        &started_components.shard_manager,
+        &started_components.component_compilation_service,
+        started_components.worker_executors.clone(),
+        component_service_api.clone(),
+        worker_service_api.clone(),
+    );
 
     let app = Route::new()
         .at("/v1/api/definitions", worker_service_api.clone())
@@ -115,15 +116,62 @@ pub fn start_router(
         .at("/v1/plugins", component_service_api.clone())
         .at("/v1/plugins/*", component_service_api.clone())
         .at("/metrics", metrics)
-        .at("/healthcheck", component_service_api)
+        .at("/healthcheck", health_check.endpoint())
+        .nest(
+            EXECUTORS_API_PATH,
+            executors_api(started_components.worker_executors.clone()),
+        )
+        .with(RequestJournalMiddleware::new(
+            args.request_journal.clone(),
+            None,
+        ))
         .with(OpenTelemetryMetrics::new())
-        .with(Tracing);
+        .with(Tracing)
+        .with(TraceContextMiddleware);
+
+    join_set.spawn(
+        async move {
+            Server::new_with_acceptor(acceptor)
+                .run(app)
+                .await
+                .map_err(|e| e.into())
+        }
+        .in_current_span(),
+    );
+
+    Ok(())
+}
+
+/// Serves the custom requests of the API gateway over TLS or IPv6, or when they are journaled,
+/// forwarding them to the worker service
+pub fn start_custom_request_frontend(
+    args: &LaunchArgs,
+    backend_port: u16,
+    join_set: &mut JoinSet<Result<(), anyhow::Error>>,
+) -> Result<(), anyhow::Error> {
+    info!("Starting custom request frontend");
 
-    // TODO: have proper healtchchecks, pass them through the different services and expose here -- similar to metrics
-    // for now just use the one from component service.
+    let acceptor = bind(
+        &args.router_addr,
+        args.custom_request_port,
+        args.tls.as_ref(),
+    )?;
+    let app = proxy_endpoint(backend_port, args.tls.is_some())
+        .with(RequestJournalMiddleware::new(
+            args.request_journal.clone(),
+            Some("custom-request"),
+        ))
+        .with(Tracing)
+        .with(TraceContextMiddleware);
 
     join_set.spawn(
-        async move { Server::new(listener).run(app).await.map_err(|e| e.into()) }.in_current_span(),
+        async move {
+            Server::new_with_acceptor(acceptor)
+                .run(app)
+                .await
+                .map_err(|e| e.into())
+        }
+        .in_current_span(),
     );
 
     Ok(())
@@ -0,0 +1,282 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::{anyhow, bail, Context};
+use golem_cli::config::{Config, OssProfile, Profile, ProfileConfig, ProfileName};
+use golem_cli::log::{log_action, LogColorize, LogIndent};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tempfile::TempDir;
+use url::Url;
+
+const SEED_PROFILE_NAME: &str = "server-seed";
+
+/// Initial state of the local server, applied with the CLI after all services are ready
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct Seed {
+    /// Application manifest to build and deploy
+    #[serde(default)]
+    pub app: Option<PathBuf>,
+    /// Components of the application to deploy, all of them are deployed when empty
+    #[serde(default)]
+    pub components: Vec<String>,
+    #[serde(default)]
+    pub workers: Vec<SeedWorker>,
+    /// OpenAPI documents to import as API definitions
+    #[serde(default)]
+    pub api_definitions: Vec<PathBuf>,
+    #[serde(default)]
+    pub api_deployments: Vec<SeedApiDeployment>,
+    /// Invocations executed after everything else is deployed
+    #[serde(default)]
+    pub warmup: Vec<SeedInvocation>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SeedWorker {
+    /// Worker name in <COMPONENT>/<WORKER> form
+    pub name: String,
+    #[serde(default)]
+    pub args: Vec<String>,
+    #[serde(default)]
+    pub env: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SeedApiDeployment {
+    /// API definitions in <ID>/<VERSION> form
+    pub definitions: Vec<String>,
+    pub host: Option<String>,
+    pub subdomain: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SeedInvocation {
+    /// Worker name in <COMPONENT>/<WORKER> form
+    pub worker: String,
+    pub function: String,
+    /// Function arguments in WAVE format
+    #[serde(default)]
+    pub args: Vec<String>,
+}
+
+impl Seed {
+    pub fn from_args(app: Option<&Path>, seed: Option<&Path>) -> anyhow::Result<Option<Self>> {
+        match (app, seed) {
+            (Some(_), Some(_)) => bail!("Only one of --app and --seed can be used"),
+            (Some(app), None) => Ok(Some(Seed {
+                app: Some(existing_path(app)?),
+                ..Seed::default()
+            })),
+            (None, Some(seed)) => Self::load(seed).map(Some),
+            (None, None) => Ok(None),
+        }
+    }
+
+    /// Loads a seed file, paths in it are relative to the seed file
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let path = existing_path(path)?;
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| anyhow!("Failed to read seed file: {}", path.display()))?;
+        let mut seed: Seed = serde_yaml::from_str(&content)
+            .with_context(|| anyhow!("Failed to parse seed file: {}", path.display()))?;
+
+        let base_dir = path.parent().unwrap_or(Path::new("."));
+        if let Some(app) = &seed.app {
+            seed.app = Some(existing_path(&base_dir.join(app))?);
+        }
+        seed.api_definitions = seed
+            .api_definitions
+            .iter()
+            .map(|definition| existing_path(&base_dir.join(definition)))
+            .collect::<anyhow::Result<_>>()?;
+
+        Ok(seed)
+    }
+
+    /// Applies the seed to the server listening on the given router host and port
+    pub async fn apply(
+        &self,
+        router_host: &str,
+        router_port: u16,
+        tls: bool,
+    ) -> anyhow::Result<()> {
+        log_action("Seeding", "golem server");
+        let _indent = LogIndent::new();
+
+        let cli = SeedCli::new(router_host, router_port, tls, self.app.clone())?;
+
+        if self.app.is_some() {
+            let mut args = args(["app", "deploy"]);
+            args.extend(self.components.iter().map(OsString::from));
+            cli.run("deploying application", args).await?;
+        }
+
+        for worker in &self.workers {
+            let mut args = args(["worker", "new", &worker.name]);
+            args.extend(worker.args.iter().map(OsString::from));
+            for (key, value) in &worker.env {
+                args.push("--env".into());
+                args.push(format!("{}={}", key, value).into());
+            }
+            cli.run(&format!("creating worker {}", worker.name), args)
+                .await?;
+        }
+
+        for definition in &self.api_definitions {
+            let mut args = args(["api", "definition", "import"]);
+            args.push(definition.clone().into_os_string());
+            cli.run(
+                &format!("importing API definition {}", definition.display()),
+                args,
+            )
+            .await?;
+        }
+
+        for deployment in &self.api_deployments {
+            let mut args = args(["api", "deployment", "deploy"]);
+            args.extend(deployment.definitions.iter().map(OsString::from));
+            if let Some(host) = &deployment.host {
+                args.push("--host".into());
+                args.push(host.into());
+            }
+            if let Some(subdomain) = &deployment.subdomain {
+                args.push("--subdomain".into());
+                args.push(subdomain.into());
+            }
+            cli.run(
+                &format!(
+                    "deploying API definitions {}",
+                    deployment.definitions.join(", ")
+                ),
+                args,
+            )
+            .await?;
+        }
+
+        for invocation in &self.warmup {
+            let mut args = args(["worker", "invoke", &invocation.worker, &invocation.function]);
+            args.extend(invocation.args.iter().map(OsString::from));
+            cli.run(
+                &format!(
+                    "invoking {} on worker {}",
+                    invocation.function, invocation.worker
+                ),
+                args,
+            )
+            .await?;
+        }
+
+        log_action(
+            "Seeded",
+            "golem server".log_color_ok_highlight().to_string(),
+        );
+
+        Ok(())
+    }
+}
+
+/// Runs CLI commands of the current executable against the local server, using a temporary profile
+/// pointing to the actual router address and port
+struct SeedCli {
+    executable: PathBuf,
+    config_dir: TempDir,
+    app: Option<PathBuf>,
+}
+
+impl SeedCli {
+    fn new(
+        router_host: &str,
+        router_port: u16,
+        tls: bool,
+        app: Option<PathBuf>,
+    ) -> anyhow::Result<Self> {
+        let config_dir = tempfile::Builder::new()
+            .prefix("golem-server-seed")
+            .tempdir()
+            .context("Failed to create temporary config dir for seeding")?;
+
+        Config::set_profile(
+            ProfileName::from(SEED_PROFILE_NAME),
+            Profile::Golem(OssProfile {
+                url: Url::parse(&format!(
+                    "{}://{}:{}",
+                    if tls { "https" } else { "http" },
+                    router_host,
+                    router_port
+                ))?,
+                worker_url: None,
+                allow_insecure: tls,
+                config: ProfileConfig::default(),
+            }),
+            config_dir.path(),
+        )?;
+
+        Ok(Self {
+            executable: std::env::current_exe()
+                .context("Failed to get the path of the current executable")?,
+            config_dir,
+            app,
+        })
+    }
+
+    async fn run(&self, description: &str, args: Vec<OsString>) -> anyhow::Result<()> {
+        log_action("Seeding", description);
+
+        let mut command = Command::new(&self.executable);
+        command
+            .arg("--config-dir")
+            .arg(self.config_dir.path())
+            .args(["--profile", SEED_PROFILE_NAME, "--yes"]);
+        match &self.app {
+            Some(app) => {
+                command.arg("--app-manifest-path").arg(app);
+                if let Some(app_dir) = app.parent() {
+                    command.current_dir(app_dir);
+                }
+            }
+            None => {
+                command.arg("--disable-app-manifest-discovery");
+            }
+        }
+
+        command.args(args);
+        let status = tokio::task::spawn_blocking(move || command.status())
+            .await?
+            .with_context(|| anyhow!("Failed to execute seed step: {}", description))?;
+        if !status.success() {
+            bail!("Seed step failed: {} ({})", description, status);
+        }
+
+        Ok(())
+    }
+}
+
+fn args<const N: usize>(args: [&str; N]) -> Vec<OsString> {
+    args.into_iter().map(OsString::from).collect()
+}
+
+fn existing_path(path: &Path) -> anyhow::Result<PathBuf> {
+    std::path::absolute(path)
+        .ok()
+        .filter(|path| path.exists())
+        .ok_or_else(|| anyhow!("File not found: {}", path.display()))
+}
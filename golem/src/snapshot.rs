@@ -0,0 +1,568 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::{anyhow, bail, Context};
+use async_zip::tokio::read::fs::ZipFileReader;
+use async_zip::tokio::write::ZipFileWriter;
+use async_zip::{Compression, ZipEntryBuilder};
+use chrono::{DateTime, Utc};
+use futures::AsyncWriteExt;
+use golem_cli::log::{log_action, logln, LogColorize, LogIndent};
+use golem_cli::model::text::fmt::{format_binary_size, TextView};
+use serde::{Deserialize, Serialize};
+use std::path::{Component, Path, PathBuf};
+use tokio::io::AsyncReadExt;
+use walkdir::WalkDir;
+
+const SNAPSHOTS_DIR: &str = "snapshots";
+const SNAPSHOT_EXTENSION: &str = "zip";
+const COPY_BUFFER_SIZE: usize = 64 * 1024;
+
+/// Archive entry prefix of the data dir content
+const DATA_PREFIX: &str = "data";
+/// Archive entry prefix of the blob storage, when it is configured outside the data dir
+const BLOB_STORAGE_PREFIX: &str = "blob-storage";
+
+/// Entries of the data dir which are not part of the server state
+const EXCLUDED_DATA_ENTRIES: &[&str] = &[SNAPSHOTS_DIR, "logs", "server.pid", "server.lock"];
+
+/// Location of the server state captured by snapshots
+pub struct SnapshotLayout {
+    pub data_dir: PathBuf,
+    pub blob_storage_dir: PathBuf,
+}
+
+impl SnapshotLayout {
+    pub fn snapshots_dir(&self) -> PathBuf {
+        self.data_dir.join(SNAPSHOTS_DIR)
+    }
+
+    /// Snapshots are stored by name in the data dir, but explicit archive paths can be used for sharing them
+    pub fn snapshot_path(&self, name: &str) -> PathBuf {
+        let path = Path::new(name);
+        if path
+            .extension()
+            .is_some_and(|ext| ext == SNAPSHOT_EXTENSION)
+            || path.components().count() > 1
+        {
+            path.to_path_buf()
+        } else {
+            self.snapshots_dir()
+                .join(format!("{}.{}", name, SNAPSHOT_EXTENSION))
+        }
+    }
+
+    fn external_blob_storage_dir(&self) -> Option<&Path> {
+        (!self.blob_storage_dir.starts_with(&self.data_dir)).then_some(&self.blob_storage_dir)
+    }
+}
+
+pub async fn save(layout: &SnapshotLayout, name: &str, force: bool) -> anyhow::Result<PathBuf> {
+    let snapshot_path = layout.snapshot_path(name);
+    if snapshot_path.exists() && !force {
+        bail!(
+            "Snapshot already exists: {}, use --force to overwrite it",
+            snapshot_path.display()
+        );
+    }
+    if !layout.data_dir.exists() {
+        bail!("Data dir does not exist: {}", layout.data_dir.display());
+    }
+    if let Some(parent) = snapshot_path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+
+    log_action(
+        "Saving",
+        format!(
+            "snapshot to {}",
+            snapshot_path.display().to_string().log_color_highlight()
+        ),
+    );
+    let _indent = LogIndent::new();
+
+    // Writing to a temporary file first, so failures do not leave a broken snapshot behind
+    let temp_path = snapshot_path.with_extension(format!("{}.tmp", SNAPSHOT_EXTENSION));
+    let file = tokio::fs::File::create(&temp_path)
+        .await
+        .with_context(|| anyhow!("Failed to create snapshot: {}", temp_path.display()))?;
+    let mut writer = ZipFileWriter::with_tokio(file);
+
+    let mut sources = vec![(DATA_PREFIX, layout.data_dir.as_path())];
+    if let Some(blob_storage_dir) = layout.external_blob_storage_dir() {
+        if blob_storage_dir.exists() {
+            sources.push((BLOB_STORAGE_PREFIX, blob_storage_dir));
+        }
+    }
+
+    for (prefix, dir) in sources {
+        for path in files_to_archive(dir, prefix == DATA_PREFIX)? {
+            let relative_path = path.strip_prefix(dir)?;
+            let entry_name = entry_name(prefix, relative_path)?;
+            let mut file = tokio::fs::File::open(&path)
+                .await
+                .with_context(|| anyhow!("Failed to open {}", path.display()))?;
+
+            let mut entry_writer = writer
+                .write_entry_stream(ZipEntryBuilder::new(
+                    entry_name.clone().into(),
+                    Compression::Deflate,
+                ))
+                .await
+                .with_context(|| anyhow!("Failed to write snapshot entry {}", entry_name))?;
+            let mut buffer = vec![0u8; COPY_BUFFER_SIZE];
+            loop {
+                let read = file
+                    .read(&mut buffer)
+                    .await
+                    .with_context(|| anyhow!("Failed to read {}", path.display()))?;
+                if read == 0 {
+                    break;
+                }
+                entry_writer
+                    .write_all(&buffer[..read])
+                    .await
+                    .with_context(|| anyhow!("Failed to write snapshot entry {}", entry_name))?;
+            }
+            entry_writer
+                .close()
+                .await
+                .with_context(|| anyhow!("Failed to write snapshot entry {}", entry_name))?;
+            log_action("Added", entry_name.log_color_highlight().to_string());
+        }
+    }
+
+    writer
+        .close()
+        .await
+        .with_context(|| anyhow!("Failed to close snapshot: {}", temp_path.display()))?;
+    tokio::fs::rename(&temp_path, &snapshot_path).await?;
+
+    Ok(snapshot_path)
+}
+
+pub async fn restore(layout: &SnapshotLayout, name: &str) -> anyhow::Result<PathBuf> {
+    let snapshot_path = layout.snapshot_path(name);
+    if !snapshot_path.exists() {
+        bail!("Snapshot not found: {}", snapshot_path.display());
+    }
+
+    log_action(
+        "Restoring",
+        format!(
+            "snapshot from {}",
+            snapshot_path.display().to_string().log_color_highlight()
+        ),
+    );
+    let _indent = LogIndent::new();
+
+    let reader = ZipFileReader::new(&snapshot_path)
+        .await
+        .with_context(|| anyhow!("Failed to open snapshot: {}", snapshot_path.display()))?;
+
+    // The snapshot is extracted next to the current state first, so a broken archive leaves it untouched
+    let staging = staging_layout(layout)?;
+
+    // Validating all the entries before extracting anything
+    let mut entries = Vec::new();
+    for (index, entry) in reader.file().entries().iter().enumerate() {
+        if entry.dir()? {
+            continue;
+        }
+        let entry_name = entry.filename().as_str()?.to_string();
+        entries.push((index, target_path(&staging, &entry_name)?, entry_name));
+    }
+
+    let staging_dirs = staging_dirs(layout, &staging);
+    for dir in &staging_dirs {
+        remove_dir_if_exists(dir).await?;
+    }
+    if let Err(err) = extract(&reader, &entries, &staging).await {
+        for dir in &staging_dirs {
+            let _ = remove_dir_if_exists(dir).await;
+        }
+        return Err(err);
+    }
+    drop(reader);
+
+    let blob_storage_backup = match layout.external_blob_storage_dir() {
+        Some(blob_storage_dir) => {
+            match swap_in(blob_storage_dir, &staging.blob_storage_dir).await {
+                Ok(blob_storage_backup) => blob_storage_backup,
+                Err(err) => {
+                    let _ = remove_dir_if_exists(&staging.data_dir).await;
+                    return Err(err);
+                }
+            }
+        }
+        None => None,
+    };
+    let data_backup = match swap_in(&layout.data_dir, &staging.data_dir).await {
+        Ok(data_backup) => data_backup,
+        Err(err) => {
+            if let Some(blob_storage_dir) = layout.external_blob_storage_dir() {
+                swap_back(blob_storage_dir, blob_storage_backup.as_deref()).await?;
+            }
+            return Err(err);
+        }
+    };
+
+    if let Some(data_backup) = &data_backup {
+        // Snapshots, logs and the server files are not part of the snapshot, so they are kept
+        for excluded in EXCLUDED_DATA_ENTRIES {
+            let from = data_backup.join(excluded);
+            if from.exists() {
+                tokio::fs::rename(&from, layout.data_dir.join(excluded))
+                    .await
+                    .with_context(|| {
+                        anyhow!(
+                            "Failed to move {} to the restored data dir, the previous data dir is kept at {}",
+                            from.display(),
+                            data_backup.display()
+                        )
+                    })?;
+            }
+        }
+    }
+    for backup in data_backup.iter().chain(blob_storage_backup.iter()) {
+        remove_dir_if_exists(backup).await?;
+    }
+
+    Ok(snapshot_path)
+}
+
+async fn extract(
+    reader: &ZipFileReader,
+    entries: &[(usize, PathBuf, String)],
+    staging: &SnapshotLayout,
+) -> anyhow::Result<()> {
+    tokio::fs::create_dir_all(&staging.data_dir)
+        .await
+        .with_context(|| anyhow!("Failed to create {}", staging.data_dir.display()))?;
+
+    for (index, target_path, entry_name) in entries {
+        let mut content = Vec::new();
+        reader
+            .reader_with_entry(*index)
+            .await?
+            .read_to_end_checked(&mut content)
+            .await
+            .with_context(|| anyhow!("Failed to read snapshot entry {}", entry_name))?;
+
+        if let Some(parent) = target_path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        tokio::fs::write(&target_path, content)
+            .await
+            .with_context(|| anyhow!("Failed to write {}", target_path.display()))?;
+        log_action("Restored", entry_name.log_color_highlight().to_string());
+    }
+
+    Ok(())
+}
+
+/// Layout of the directories the snapshot is extracted into before replacing the current state
+fn staging_layout(layout: &SnapshotLayout) -> anyhow::Result<SnapshotLayout> {
+    let data_dir = sibling_dir(&layout.data_dir, "restore")?;
+    let blob_storage_dir = match layout.external_blob_storage_dir() {
+        Some(blob_storage_dir) => sibling_dir(blob_storage_dir, "restore")?,
+        None => data_dir.join(layout.blob_storage_dir.strip_prefix(&layout.data_dir)?),
+    };
+    Ok(SnapshotLayout {
+        data_dir,
+        blob_storage_dir,
+    })
+}
+
+fn staging_dirs(layout: &SnapshotLayout, staging: &SnapshotLayout) -> Vec<PathBuf> {
+    let mut dirs = vec![staging.data_dir.clone()];
+    if layout.external_blob_storage_dir().is_some() {
+        dirs.push(staging.blob_storage_dir.clone());
+    }
+    dirs
+}
+
+/// Hidden directory next to the given one, so renaming between them does not cross filesystems
+fn sibling_dir(dir: &Path, suffix: &str) -> anyhow::Result<PathBuf> {
+    let name = dir
+        .file_name()
+        .ok_or_else(|| anyhow!("Invalid directory: {}", dir.display()))?
+        .to_string_lossy();
+    Ok(dir.with_file_name(format!(".{}.{}", name, suffix)))
+}
+
+/// Replaces the directory with the staged one, returning where its previous content was moved
+async fn swap_in(dir: &Path, staging_dir: &Path) -> anyhow::Result<Option<PathBuf>> {
+    let backup_dir = if dir.exists() {
+        let backup_dir = sibling_dir(dir, "old")?;
+        remove_dir_if_exists(&backup_dir).await?;
+        tokio::fs::rename(dir, &backup_dir)
+            .await
+            .with_context(|| anyhow!("Failed to move {}", dir.display()))?;
+        Some(backup_dir)
+    } else {
+        None
+    };
+
+    if let Err(err) = tokio::fs::rename(staging_dir, dir).await {
+        swap_back(dir, backup_dir.as_deref()).await?;
+        let _ = remove_dir_if_exists(staging_dir).await;
+        return Err(anyhow!(err).context(format!("Failed to replace {}", dir.display())));
+    }
+
+    Ok(backup_dir)
+}
+
+/// Moves back the previous content of a directory replaced by [swap_in]
+async fn swap_back(dir: &Path, backup_dir: Option<&Path>) -> anyhow::Result<()> {
+    remove_dir_if_exists(dir).await?;
+    if let Some(backup_dir) = backup_dir {
+        tokio::fs::rename(backup_dir, dir).await.with_context(|| {
+            anyhow!(
+                "Failed to move back {} to {}",
+                backup_dir.display(),
+                dir.display()
+            )
+        })?;
+    }
+    Ok(())
+}
+
+async fn remove_dir_if_exists(dir: &Path) -> anyhow::Result<()> {
+    if dir.exists() {
+        tokio::fs::remove_dir_all(dir)
+            .await
+            .with_context(|| anyhow!("Failed to remove {}", dir.display()))?;
+    }
+    Ok(())
+}
+
+pub async fn list(layout: &SnapshotLayout) -> anyhow::Result<SnapshotListView> {
+    let snapshots_dir = layout.snapshots_dir();
+    let mut snapshots = Vec::new();
+
+    if snapshots_dir.exists() {
+        let mut read_dir = tokio::fs::read_dir(&snapshots_dir).await?;
+        while let Some(entry) = read_dir.next_entry().await? {
+            let path = entry.path();
+            if path.extension().is_none_or(|ext| ext != SNAPSHOT_EXTENSION) {
+                continue;
+            }
+            let metadata = entry.metadata().await?;
+            snapshots.push(SnapshotInfo {
+                name: path
+                    .file_stem()
+                    .map(|stem| stem.to_string_lossy().to_string())
+                    .unwrap_or_default(),
+                path,
+                size: metadata.len(),
+                created_at: metadata.modified().ok().map(DateTime::<Utc>::from),
+            });
+        }
+    }
+    snapshots.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(SnapshotListView(snapshots))
+}
+
+fn files_to_archive(dir: &Path, skip_excluded: bool) -> anyhow::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let walker = WalkDir::new(dir)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|entry| {
+            !(skip_excluded
+                && entry.depth() == 1
+                && EXCLUDED_DATA_ENTRIES
+                    .iter()
+                    .any(|excluded| entry.file_name() == *excluded))
+        });
+    for entry in walker {
+        let entry = entry.with_context(|| anyhow!("Failed to read {}", dir.display()))?;
+        if entry.file_type().is_file() {
+            files.push(entry.into_path());
+        }
+    }
+    Ok(files)
+}
+
+fn entry_name(prefix: &str, relative_path: &Path) -> anyhow::Result<String> {
+    let mut name = prefix.to_string();
+    for component in relative_path.components() {
+        let component = component.as_os_str().to_str().ok_or_else(|| {
+            anyhow!(
+                "Non-unicode path cannot be archived: {}",
+                relative_path.display()
+            )
+        })?;
+        name.push('/');
+        name.push_str(component);
+    }
+    Ok(name)
+}
+
+/// Maps a snapshot entry to its restore location, rejecting entries escaping the target directories
+fn target_path(layout: &SnapshotLayout, entry_name: &str) -> anyhow::Result<PathBuf> {
+    let (prefix, relative_path) = entry_name
+        .split_once('/')
+        .ok_or_else(|| anyhow!("Invalid snapshot entry: {}", entry_name))?;
+    let relative_path = Path::new(relative_path);
+    if !relative_path
+        .components()
+        .all(|component| matches!(component, Component::Normal(_)))
+    {
+        bail!("Invalid snapshot entry: {}", entry_name);
+    }
+
+    match prefix {
+        DATA_PREFIX => Ok(layout.data_dir.join(relative_path)),
+        BLOB_STORAGE_PREFIX => Ok(layout.blob_storage_dir.join(relative_path)),
+        _ => bail!("Invalid snapshot entry: {}", entry_name),
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotInfo {
+    pub name: String,
+    pub path: PathBuf,
+    pub size: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotListView(pub Vec<SnapshotInfo>);
+
+impl TextView for SnapshotListView {
+    fn log(&self) {
+        if self.0.is_empty() {
+            logln("No snapshots found");
+            return;
+        }
+
+        let padding = self
+            .0
+            .iter()
+            .map(|snapshot| snapshot.name.len())
+            .max()
+            .unwrap_or(0);
+        for snapshot in &self.0 {
+            let created_at = snapshot
+                .created_at
+                .map(|created_at| created_at.format("%Y-%m-%d %H:%M:%S").to_string())
+                .unwrap_or_default();
+            logln(format!(
+                "{} {:>10} {}",
+                format!("{:<padding$}", snapshot.name).log_color_highlight(),
+                format_binary_size(&snapshot.size),
+                created_at
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::snapshot::{restore, save, target_path, SnapshotLayout};
+    use assert2::assert;
+    use std::path::Path;
+    use test_r::test;
+
+    fn layout(root: &Path) -> SnapshotLayout {
+        SnapshotLayout {
+            data_dir: root.join("data"),
+            blob_storage_dir: root.join("blobs"),
+        }
+    }
+
+    fn write(path: &Path, content: &str) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+
+    fn read(path: &Path) -> String {
+        std::fs::read_to_string(path).unwrap()
+    }
+
+    #[test]
+    async fn save_and_restore_round_trip() {
+        let root = tempfile::tempdir().unwrap();
+        let layout = layout(root.path());
+        write(&layout.data_dir.join("components.db"), "components-v1");
+        write(&layout.data_dir.join("nested/state"), "state-v1");
+        write(&layout.data_dir.join("logs/server.log"), "log-v1");
+        write(&layout.blob_storage_dir.join("a/blob"), "blob-v1");
+
+        let snapshot_path = save(&layout, "first", false).await.unwrap();
+        assert!(snapshot_path == layout.snapshots_dir().join("first.zip"));
+        assert!(save(&layout, "first", false).await.is_err());
+
+        write(&layout.data_dir.join("components.db"), "components-v2");
+        write(&layout.data_dir.join("added"), "added");
+        write(&layout.data_dir.join("logs/server.log"), "log-v2");
+        write(&layout.blob_storage_dir.join("a/blob"), "blob-v2");
+        write(&layout.blob_storage_dir.join("b/blob"), "blob-added");
+
+        restore(&layout, "first").await.unwrap();
+
+        assert!(read(&layout.data_dir.join("components.db")) == "components-v1");
+        assert!(read(&layout.data_dir.join("nested/state")) == "state-v1");
+        assert!(!layout.data_dir.join("added").exists());
+        assert!(read(&layout.blob_storage_dir.join("a/blob")) == "blob-v1");
+        assert!(!layout.blob_storage_dir.join("b").exists());
+        // Not part of the snapshot, kept as they are
+        assert!(read(&layout.data_dir.join("logs/server.log")) == "log-v2");
+        assert!(snapshot_path.exists());
+        // No staging or backup directories are left behind
+        let mut entries = std::fs::read_dir(root.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+            .collect::<Vec<_>>();
+        entries.sort();
+        assert!(entries == vec!["blobs".to_string(), "data".to_string()]);
+    }
+
+    #[test]
+    async fn restore_of_invalid_snapshot_keeps_current_state() {
+        let root = tempfile::tempdir().unwrap();
+        let layout = layout(root.path());
+        write(&layout.data_dir.join("components.db"), "components");
+        write(&layout.snapshots_dir().join("broken.zip"), "not a zip");
+
+        assert!(restore(&layout, "broken").await.is_err());
+        assert!(read(&layout.data_dir.join("components.db")) == "components");
+    }
+
+    #[test]
+    fn target_path_rejects_escaping_entries() {
+        let layout = layout(Path::new("/golem"));
+
+        assert!(
+            target_path(&layout, "data/nested/state").unwrap()
+                == Path::new("/golem/data/nested/state")
+        );
+        assert!(
+            target_path(&layout, "blob-storage/a/blob").unwrap()
+                == Path::new("/golem/blobs/a/blob")
+        );
+
+        assert!(target_path(&layout, "data/../escape").is_err());
+        assert!(target_path(&layout, "data/nested/../../escape").is_err());
+        assert!(target_path(&layout, "data//etc/passwd").is_err());
+        assert!(target_path(&layout, "blob-storage/./blob").is_err());
+        assert!(target_path(&layout, "other/file").is_err());
+        assert!(target_path(&layout, "no-prefix").is_err());
+    }
+}
@@ -0,0 +1,135 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::config::{OtlpConfig, OtlpProtocol};
+use golem_cli::TracingLayer;
+use opentelemetry::global;
+use opentelemetry::propagation::Extractor;
+use opentelemetry::trace::{FutureExt, TracerProvider};
+use opentelemetry_otlp::{MetricExporter, SpanExporter, WithExportConfig};
+use opentelemetry_sdk::metrics::PeriodicReader;
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use opentelemetry_sdk::trace::SdkTracerProvider;
+use opentelemetry_sdk::Resource;
+use poem::http::HeaderMap;
+use poem::{Endpoint, Middleware, Request};
+use std::sync::OnceLock;
+use tracing_subscriber::Layer;
+
+static TRACER_PROVIDER: OnceLock<SdkTracerProvider> = OnceLock::new();
+
+/// Tracing layer exporting the spans of all services via OTLP, when enabled.
+///
+/// Also installs the W3C trace context propagator, so the traces of incoming requests are continued.
+pub fn otlp_tracing_layer(config: &OtlpConfig) -> anyhow::Result<Option<TracingLayer>> {
+    if !config.enabled {
+        return Ok(None);
+    }
+
+    let exporter = match config.protocol {
+        OtlpProtocol::Grpc => SpanExporter::builder()
+            .with_tonic()
+            .with_endpoint(config.endpoint())
+            .build()?,
+        OtlpProtocol::Http => SpanExporter::builder()
+            .with_http()
+            .with_endpoint(format!("{}/v1/traces", config.endpoint()))
+            .build()?,
+    };
+    let provider = SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_resource(resource(config))
+        .build();
+    let tracer = provider.tracer("golem");
+
+    global::set_text_map_propagator(TraceContextPropagator::new());
+    global::set_tracer_provider(provider.clone());
+    let _ = TRACER_PROVIDER.set(provider);
+
+    Ok(Some(
+        tracing_opentelemetry::layer().with_tracer(tracer).boxed(),
+    ))
+}
+
+/// Metric reader periodically exporting the metrics of all services via OTLP, when enabled
+pub fn otlp_metric_reader(config: &OtlpConfig) -> anyhow::Result<Option<PeriodicReader>> {
+    if !config.enabled {
+        return Ok(None);
+    }
+
+    let exporter = match config.protocol {
+        OtlpProtocol::Grpc => MetricExporter::builder()
+            .with_tonic()
+            .with_endpoint(config.endpoint())
+            .build()?,
+        OtlpProtocol::Http => MetricExporter::builder()
+            .with_http()
+            .with_endpoint(format!("{}/v1/metrics", config.endpoint()))
+            .build()?,
+    };
+
+    Ok(Some(PeriodicReader::builder(exporter).build()))
+}
+
+pub fn resource(config: &OtlpConfig) -> Resource {
+    Resource::builder()
+        .with_service_name(config.service_name())
+        .build()
+}
+
+/// Flushes the pending spans, should be called before exiting
+pub fn shutdown() {
+    if let Some(provider) = TRACER_PROVIDER.get() {
+        let _ = provider.shutdown();
+    }
+}
+
+/// Middleware continuing the trace of the `traceparent` header of incoming requests,
+/// so the spans of a CLI command can be followed across the services
+pub struct TraceContextMiddleware;
+
+impl<E: Endpoint> Middleware<E> for TraceContextMiddleware {
+    type Output = TraceContextEndpoint<E>;
+
+    fn transform(&self, ep: E) -> Self::Output {
+        TraceContextEndpoint { inner: ep }
+    }
+}
+
+pub struct TraceContextEndpoint<E> {
+    inner: E,
+}
+
+impl<E: Endpoint> Endpoint for TraceContextEndpoint<E> {
+    type Output = E::Output;
+
+    async fn call(&self, req: Request) -> poem::Result<Self::Output> {
+        let parent = global::get_text_map_propagator(|propagator| {
+            propagator.extract(&HeaderExtractor(req.headers()))
+        });
+        self.inner.call(req).with_context(parent).await
+    }
+}
+
+struct HeaderExtractor<'a>(&'a HeaderMap);
+
+impl Extractor for HeaderExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|value| value.to_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(|key| key.as_str()).collect()
+    }
+}